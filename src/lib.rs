@@ -76,11 +76,50 @@ pub mod prelude;
 #[cfg(test)]
 mod tests;
 
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum CliError {
     AmbiguousCommand,
     ValueEvaluation,
+    /// A value failed to evaluate, carrying the reason it was rejected.
+    ValueEvaluationMessage(String),
     FlagEvaluation(String),
+    /// A flag failed to evaluate, carrying the flag's custom error message.
+    FlagEvaluationMessage {
+        flag: String,
+        message: String,
+    },
+    InvalidChoice(String),
+    /// A value passed to a flag failed to evaluate, carrying the flag's custom
+    /// error message if set.
+    InvalidValue {
+        flag: String,
+        value: String,
+        message: Option<String>,
+    },
+    /// Evaluated values were rejected by a validator, carrying its message.
+    Validation(String),
+    MissingValue(String),
+    MissingSubcommand {
+        available: Vec<String>,
+    },
+    MissingRequiredFlag(Vec<String>),
+    TooManyOccurrences {
+        flag: String,
+        max: usize,
+    },
+    MutuallyExclusive(Vec<String>),
+    MissingDependency {
+        present: Vec<String>,
+        missing: Vec<String>,
+    },
+    WrongArgCount {
+        expected: String,
+        got: usize,
+    },
+    Io(String),
 }
 
 impl std::fmt::Display for CliError {
@@ -88,13 +127,163 @@ impl std::fmt::Display for CliError {
         match self {
             Self::AmbiguousCommand => write!(f, "ambiguous command"),
             Self::ValueEvaluation => write!(f, "value missmatch"),
+            Self::ValueEvaluationMessage(reason) => write!(f, "{}", reason),
             Self::FlagEvaluation(name) => write!(f, "unable to evaluate flag: {}", name),
+            Self::FlagEvaluationMessage { message, .. } => write!(f, "{}", message),
+            Self::InvalidChoice(choice) => write!(f, "invalid choice: {}", choice),
+            Self::InvalidValue {
+                message: Some(message),
+                ..
+            } => write!(f, "{}", message),
+            Self::InvalidValue { flag, value, .. } => {
+                write!(f, "invalid value for flag {}: {}", flag, value)
+            }
+            Self::Validation(message) => write!(f, "{}", message),
+            Self::MissingValue(name) => write!(f, "missing value for flag: {}", name),
+            Self::MissingSubcommand { available } => write!(
+                f,
+                "a subcommand is required, available: {}",
+                available.join(", ")
+            ),
+            Self::MissingRequiredFlag(names) => write!(
+                f,
+                "at least one of the following flags is required: {}",
+                names
+                    .iter()
+                    .map(|name| format!("--{}", name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::TooManyOccurrences { flag, max } => {
+                write!(f, "flag {} may be passed at most {} times", flag, max)
+            }
+            Self::MutuallyExclusive(names) => write!(
+                f,
+                "flags cannot be used together: {}",
+                names
+                    .iter()
+                    .map(|name| format!("--{}", name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::MissingDependency { present, missing } => write!(
+                f,
+                "flags {} must be used together with: {}",
+                present
+                    .iter()
+                    .map(|name| format!("--{}", name))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                missing
+                    .iter()
+                    .map(|name| format!("--{}", name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::WrongArgCount { expected, got } => {
+                write!(f, "expected {} positional arguments, got {}", expected, got)
+            }
+            Self::Io(msg) => write!(f, "io error: {}", msg),
+        }
+    }
+}
+
+/// ErrorKind classifies a CliError by the kind of problem it represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The command was invoked incorrectly, such as a missing required flag
+    /// or subcommand, and usage should be shown.
+    Usage,
+    /// A provided value failed to evaluate.
+    Value,
+    /// An error was encountered accessing the environment, such as a file.
+    Io,
+}
+
+impl CliError {
+    /// Returns the error with every flag name it carries rewritten by `f`.
+    fn map_flag_names(self, f: impl Fn(&str) -> String) -> Self {
+        let map_all = |names: Vec<String>| names.iter().map(|name| f(name)).collect();
+
+        match self {
+            Self::FlagEvaluation(name) => Self::FlagEvaluation(f(&name)),
+            Self::FlagEvaluationMessage { flag, message } => Self::FlagEvaluationMessage {
+                flag: f(&flag),
+                message,
+            },
+            Self::InvalidValue {
+                flag,
+                value,
+                message,
+            } => Self::InvalidValue {
+                flag: f(&flag),
+                value,
+                message,
+            },
+            Self::MissingValue(name) => Self::MissingValue(f(&name)),
+            Self::MissingRequiredFlag(names) => Self::MissingRequiredFlag(map_all(names)),
+            Self::TooManyOccurrences { flag, max } => Self::TooManyOccurrences {
+                flag: f(&flag),
+                max,
+            },
+            Self::MutuallyExclusive(names) => Self::MutuallyExclusive(map_all(names)),
+            Self::MissingDependency { present, missing } => Self::MissingDependency {
+                present: map_all(present),
+                missing: map_all(missing),
+            },
+            e => e,
+        }
+    }
+
+    /// Returns the kind of problem an error represents, allowing a caller to
+    /// decide whether to present usage alongside the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(ErrorKind::Usage, CliError::AmbiguousCommand.kind());
+    /// assert_eq!(ErrorKind::Value, CliError::ValueEvaluation.kind());
+    ///
+    /// let port = Flag::expect_u16("port", "p", "A port.");
+    /// assert_eq!(
+    ///     Err(ErrorKind::Value),
+    ///     port.evaluate(&["test", "-p", "abc"][..]).map_err(|e| e.kind())
+    /// );
+    /// assert_eq!(
+    ///     Err(ErrorKind::Usage),
+    ///     port.evaluate(&["test"][..]).map_err(|e| e.kind())
+    /// );
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::AmbiguousCommand
+            | Self::FlagEvaluation(_)
+            | Self::FlagEvaluationMessage { .. }
+            | Self::MissingValue(_)
+            | Self::MissingSubcommand { .. }
+            | Self::MissingRequiredFlag(_)
+            | Self::TooManyOccurrences { .. }
+            | Self::MutuallyExclusive(_)
+            | Self::MissingDependency { .. }
+            | Self::WrongArgCount { .. } => ErrorKind::Usage,
+            Self::ValueEvaluation
+            | Self::ValueEvaluationMessage(_)
+            | Self::InvalidChoice(_)
+            | Self::InvalidValue { .. }
+            | Self::Validation(_) => ErrorKind::Value,
+            Self::Io(_) => ErrorKind::Io,
         }
     }
 }
 
 /// CmdGroup functions as a grouping of multiple dispatchable commands under a
-/// single command grouping.
+/// single command grouping. Both evaluating a group and rendering its help
+/// require its commands to implement `SubcommandNames`, which `Cmd`, `OneOf`
+/// and `CmdGroup` do. A custom command type that previously only implemented
+/// `ShortHelpable<Output = String>` must implement `SubcommandNames` too.
 ///
 /// # Example
 ///
@@ -139,6 +328,11 @@ pub struct CmdGroup<C> {
     author: &'static str,
     version: &'static str,
     commands: C,
+    subcommand_required: bool,
+    /// Additional program names the group is invoked under.
+    binary_aliases: Vec<&'static str>,
+    /// Dispatches the subcommand named by the program name.
+    multicall: bool,
 }
 
 impl CmdGroup<()> {
@@ -160,6 +354,9 @@ impl CmdGroup<()> {
             author: "",
             version: "",
             commands: (),
+            subcommand_required: false,
+            binary_aliases: Vec::new(),
+            multicall: false,
         }
     }
 
@@ -182,11 +379,56 @@ impl CmdGroup<()> {
             author: self.author,
             version: self.version,
             commands: new_cmd,
+            subcommand_required: self.subcommand_required,
+            binary_aliases: self.binary_aliases,
+            multicall: self.multicall,
         }
     }
 }
 
 impl<C> CmdGroup<C> {
+    /// Returns a copy of the CmdGroup's structure, its metadata, options and
+    /// the structure of each of its commands, without their handlers. Like
+    /// `Cmd::clone_structure`, this allows a definition to be reused, for
+    /// example to generate help after the original has been dispatched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let group = CmdGroup::new("tool")
+    ///     .description("a tool")
+    ///     .with_command(
+    ///         Cmd::new("greet")
+    ///             .description("greet someone")
+    ///             .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///             .with_handler(|name| name),
+    ///     );
+    ///
+    /// let structure = group.clone_structure();
+    /// let flags = group.evaluate(&["tool", "greet", "-n", "foo"][..]).unwrap();
+    /// assert_eq!("foo".to_string(), group.dispatch(flags));
+    ///
+    /// assert!(structure.help().contains("greet"));
+    /// ```
+    pub fn clone_structure(&self) -> CmdGroup<C::Output>
+    where
+        C: StructureCloneable,
+    {
+        CmdGroup {
+            name: self.name,
+            description: self.description,
+            author: self.author,
+            version: self.version,
+            commands: self.commands.clone_structure(),
+            subcommand_required: self.subcommand_required,
+            binary_aliases: self.binary_aliases.clone(),
+            multicall: self.multicall,
+        }
+    }
+
     /// Returns CmdGroup with the name field set to the provided value.
     ///
     /// # Examples
@@ -217,6 +459,24 @@ impl<C> CmdGroup<C> {
         self
     }
 
+    /// Returns CmdGroup with the description field set to the provided
+    /// value. Functionally this is an alias for `description`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     "a test command group",
+    ///     CmdGroup::new("test").about("a test command group").get_description()
+    /// );
+    /// ```
+    pub fn about(self, description: &'static str) -> Self {
+        self.description(description)
+    }
+
     /// Returns CmdGroup with the author field set to the provided value.
     ///
     /// # Examples
@@ -246,6 +506,186 @@ impl<C> CmdGroup<C> {
         self.version = version;
         self
     }
+
+    /// Returns CmdGroup requiring that a subcommand is provided. When the
+    /// argument following the group doesn't name one of its subcommands,
+    /// evaluation fails with a `CliError::MissingSubcommand` listing the
+    /// available subcommands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let group = CmdGroup::new("test_group")
+    ///     .subcommand_required()
+    ///     .with_command(Cmd::new("test_one").with_handler(|_| {}))
+    ///     .with_command(Cmd::new("test_two").with_handler(|_| {}));
+    ///
+    /// assert_eq!(
+    ///     Err(CliError::MissingSubcommand {
+    ///         available: vec!["test_one".to_string(), "test_two".to_string()]
+    ///     }),
+    ///     group.evaluate(&["test_group"][..])
+    /// );
+    /// ```
+    pub fn subcommand_required(mut self) -> Self {
+        self.subcommand_required = true;
+        self
+    }
+
+    /// Returns CmdGroup accepting the provided program names in addition to
+    /// its own name, such as when the binary is symlinked under a different
+    /// name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let group = CmdGroup::new("test_group")
+    ///     .binary_aliases(&["tg"])
+    ///     .with_command(Cmd::new("test_one").with_handler(|_| {}));
+    ///
+    /// assert!(group.evaluate(&["/usr/bin/tg", "test_one"][..]).is_ok());
+    /// ```
+    pub fn binary_aliases(mut self, aliases: &[&'static str]) -> Self {
+        self.binary_aliases.extend_from_slice(aliases);
+        self
+    }
+
+    /// Returns CmdGroup in busybox-style multi-call mode, where a program
+    /// name matching one of its subcommands, such as a binary symlinked as
+    /// `cat`, dispatches that subcommand with no explicit subcommand
+    /// argument. Invoking the group under its own name behaves as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let group = CmdGroup::new("toolbox")
+    ///     .multicall()
+    ///     .with_command(
+    ///         Cmd::new("cat")
+    ///             .with_flag(Flag::store_true("number", "n", "Number lines.").optional())
+    ///             .with_positional("file", 0, StringValue)
+    ///             .with_handler(|(_, file)| file),
+    ///     )
+    ///     .with_command(Cmd::new("ls").with_handler(|_| {}));
+    ///
+    /// assert!(group.evaluate(&["/bin/cat", "a.txt"][..]).is_ok());
+    /// assert!(group.evaluate(&["toolbox", "cat", "a.txt"][..]).is_ok());
+    /// assert_eq!(
+    ///     Err(CliError::AmbiguousCommand),
+    ///     group.evaluate(&["cp", "a.txt"][..])
+    /// );
+    /// ```
+    pub fn multicall(mut self) -> Self {
+        self.multicall = true;
+        self
+    }
+
+    /// Returns the name of the command group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!("test", CmdGroup::new("test").get_name());
+    /// ```
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the description of the command group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     "a test command group",
+    ///     CmdGroup::new("test").description("a test command group").get_description()
+    /// );
+    /// ```
+    pub fn get_description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Returns the author of the command group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     "John Doe <jdoe@example.com>",
+    ///     CmdGroup::new("test").author("John Doe <jdoe@example.com>").get_author()
+    /// );
+    /// ```
+    pub fn get_author(&self) -> &'static str {
+        self.author
+    }
+
+    /// Returns the version of the command group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!("1.0.0", CmdGroup::new("test").version("1.0.0").get_version());
+    /// ```
+    pub fn get_version(&self) -> &'static str {
+        self.version
+    }
+}
+
+impl<C> CmdGroup<C>
+where
+    C: CommandTree,
+{
+    /// Returns a model of the group's full command tree, walking any nested
+    /// groups, for consumption by documentation and completion generators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let model = CmdGroup::new("test_group")
+    ///     .description("a test group")
+    ///     .with_command(
+    ///         Cmd::new("test")
+    ///             .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///             .with_handler(|_| {}),
+    ///     )
+    ///     .tree_model();
+    ///
+    /// assert_eq!("test_group", model.name);
+    /// assert_eq!("test", model.subcommands[0].name);
+    /// assert_eq!("name", model.subcommands[0].flags[0].name);
+    /// ```
+    pub fn tree_model(&self) -> CommandNode {
+        CommandNode {
+            name: self.name,
+            description: self.description,
+            flags: Vec::new(),
+            subcommands: self.commands.command_nodes(),
+        }
+    }
 }
 
 impl<C> CmdGroup<C>
@@ -271,13 +711,16 @@ where
             author: self.author,
             version: self.version,
             commands: OneOf::new(self.commands, new_cmd),
+            subcommand_required: self.subcommand_required,
+            binary_aliases: self.binary_aliases,
+            multicall: self.multicall,
         }
     }
 }
 
 impl<'a, C, B> Evaluatable<'a, &'a [&'a str], B> for CmdGroup<C>
 where
-    C: Evaluatable<'a, &'a [&'a str], B>,
+    C: Evaluatable<'a, &'a [&'a str], B> + SubcommandNames,
     B: std::fmt::Debug,
 {
     fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
@@ -286,14 +729,39 @@ where
             .map(|&bin| std::path::Path::new(bin).file_name());
 
         match filename {
-            Some(Some(name)) if name == self.name => self
-                .commands
-                .evaluate(&input[1..])
-                .map(|v| v.from_offset(1)),
+            Some(Some(name))
+                if name == self.name || self.binary_aliases.iter().any(|&alias| name == alias) =>
+            {
+                let available = self.commands.subcommand_names();
+                let has_subcommand = input
+                    .get(1)
+                    .is_some_and(|subcommand| available.contains(subcommand));
+
+                if self.subcommand_required && !has_subcommand {
+                    Err(CliError::MissingSubcommand {
+                        available: available.iter().map(|name| name.to_string()).collect(),
+                    })
+                } else {
+                    self.commands
+                        .evaluate(&input[1..])
+                        .map(|v| v.from_offset(1))
+                }
+                // Add group to range
+                .map(|v| Value::new(Span::from_range(0..1).join(v.span), v.value))
+            }
+            // the binary itself is named for the subcommand.
+            Some(Some(name))
+                if self.multicall
+                    && self
+                        .commands
+                        .subcommand_names()
+                        .iter()
+                        .any(|&subcommand| name == subcommand) =>
+            {
+                self.commands.evaluate(input)
+            }
             _ => Err(CliError::AmbiguousCommand),
         }
-        // Add group to range
-        .map(|v| Value::new(Span::from_range(0..1).join(v.span), v.value))
     }
 }
 
@@ -315,15 +783,32 @@ where
     }
 }
 
+impl<'a, C, A, B, R> DispatchableWithRawArgs<A, B, R> for CmdGroup<C>
+where
+    C: Evaluatable<'a, A, B> + DispatchableWithRawArgs<A, B, R>,
+{
+    fn dispatch_with_raw_args(self, raw_args: &[&str], flag_values: Value<B>) -> R {
+        self.commands.dispatch_with_raw_args(raw_args, flag_values)
+    }
+}
+
+impl<'a, C, A, B, R> DispatchableOnce<A, B, R> for CmdGroup<C>
+where
+    C: Evaluatable<'a, A, B> + DispatchableOnce<A, B, R>,
+{
+    fn dispatch_once(self, flag_values: Value<B>) -> R {
+        self.commands.dispatch_once(flag_values)
+    }
+}
+
 impl<A, C, B, R> DispatchableWithHelpString<A, B, R> for CmdGroup<C>
 where
-    Self: Helpable<Output = String>,
     C: DispatchableWithHelpString<A, B, R>,
 {
+    /// Dispatches to the matched subcommand, which supplies its own
+    /// helpstring to its handler, rather than the group's.
     fn dispatch_with_helpstring(self, flag_values: Value<B>) -> R {
-        let help_string = self.help();
-        self.commands
-            .dispatch_with_supplied_helpstring(help_string, flag_values)
+        self.commands.dispatch_with_helpstring(flag_values)
     }
 
     fn dispatch_with_supplied_helpstring(self, help_string: String, flag_values: Value<B>) -> R {
@@ -334,13 +819,13 @@ where
 
 impl<A, C, B, R> DispatchableWithHelpStringAndArgs<A, B, R> for CmdGroup<C>
 where
-    Self: Helpable<Output = String>,
     C: DispatchableWithHelpStringAndArgs<A, B, R>,
 {
+    /// Dispatches to the matched subcommand, which supplies its own
+    /// helpstring to its handler, rather than the group's.
     fn dispatch_with_helpstring_and_args(self, args: StringArgs, flag_values: Value<B>) -> R {
-        let help_string = self.help();
         self.commands
-            .dispatch_with_supplied_helpstring_and_args(help_string, args, flag_values)
+            .dispatch_with_helpstring_and_args(args, flag_values)
     }
 
     fn dispatch_with_supplied_helpstring_and_args(
@@ -356,17 +841,37 @@ where
 
 impl<C> Helpable for CmdGroup<C>
 where
-    C: ShortHelpable<Output = String>,
+    C: SubcommandNames,
 {
     type Output = String;
 
     fn help(&self) -> Self::Output {
-        format!(
-            "Usage: {} [OPTIONS]\n{}\nSubcommands:\n{}",
-            self.name,
-            self.description,
-            self.commands.short_help()
-        )
+        let mut buf = Vec::new();
+        // writes to a Vec are infallible and only ever contain valid utf-8.
+        let _ = self.render_to(&mut buf);
+        String::from_utf8(buf).unwrap_or_default()
+    }
+
+    /// Renders the group's help, listing its subcommands as a table with
+    /// descriptions aligned to the longest subcommand name.
+    fn render_to<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        write!(
+            w,
+            "Usage: {} [OPTIONS]\n{}\nSubcommands:",
+            self.name, self.description,
+        )?;
+
+        let summaries = self.commands.subcommand_summaries();
+        let width = summaries
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or_default();
+
+        for (name, description) in summaries {
+            write!(w, "\n{:<width$} {}", name, description, width = width)?;
+        }
+        Ok(())
     }
 }
 
@@ -437,6 +942,56 @@ impl<C1, C2> OneOf<C1, C2> {
     pub fn new(left: C1, right: C2) -> Self {
         Self { left, right }
     }
+
+    /// Dispatches an evaluated `Either` value to one of two ad-hoc handlers,
+    /// bypassing the handlers defined on the enclosed commands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmds = || OneOf::new(
+    ///     Cmd::new("test_one").with_flag(Flag::expect_string("name", "n", "A name.")),
+    ///     Cmd::new("test_two").with_flag(Flag::expect_u8("count", "c", "A count.")),
+    /// );
+    ///
+    /// let left = cmds().evaluate(&["test_one", "-n", "foo"][..]).unwrap();
+    /// assert_eq!(
+    ///     "name: foo".to_string(),
+    ///     cmds().dispatch_either(
+    ///         left,
+    ///         |name| format!("name: {}", name),
+    ///         |count| format!("count: {}", count),
+    ///     )
+    /// );
+    ///
+    /// let right = cmds().evaluate(&["test_two", "-c", "5"][..]).unwrap();
+    /// assert_eq!(
+    ///     "count: 5".to_string(),
+    ///     cmds().dispatch_either(
+    ///         right,
+    ///         |name| format!("name: {}", name),
+    ///         |count| format!("count: {}", count),
+    ///     )
+    /// );
+    /// ```
+    pub fn dispatch_either<B, C, R, LF, RF>(
+        self,
+        flag_values: Value<Either<B, C>>,
+        left_fn: LF,
+        right_fn: RF,
+    ) -> R
+    where
+        LF: FnOnce(B) -> R,
+        RF: FnOnce(C) -> R,
+    {
+        match flag_values.unwrap() {
+            Either::Left(b) => left_fn(b),
+            Either::Right(c) => right_fn(c),
+        }
+    }
 }
 
 impl<'a, C1, C2, B, C> Evaluatable<'a, &'a [&'a str], Either<B, C>> for OneOf<C1, C2>
@@ -485,41 +1040,72 @@ where
     }
 }
 
-impl<A, C1, C2, B, C, R> DispatchableWithHelpString<A, Either<B, C>, R> for OneOf<C1, C2>
+impl<'a, C1, C2, A, B, C, R> DispatchableWithRawArgs<A, Either<B, C>, R> for OneOf<C1, C2>
 where
-    Self: Helpable<Output = String>,
-    C1: DispatchableWithHelpString<A, B, R>,
-    C2: DispatchableWithHelpString<A, C, R>,
+    C1: Evaluatable<'a, A, B> + DispatchableWithRawArgs<A, B, R>,
+    C2: Evaluatable<'a, A, C> + DispatchableWithRawArgs<A, C, R>,
 {
-    fn dispatch_with_helpstring(self, flag_values: Value<Either<B, C>>) -> R {
-        let help_string = self.help();
+    fn dispatch_with_raw_args(self, raw_args: &[&str], flag_values: Value<Either<B, C>>) -> R {
         let span = flag_values.span;
         let values = flag_values.value;
 
         match values {
             Either::Left(b) => self
                 .left
-                .dispatch_with_supplied_helpstring(help_string, Value::new(span, b)),
+                .dispatch_with_raw_args(raw_args, Value::new(span, b)),
             Either::Right(c) => self
                 .right
-                .dispatch_with_supplied_helpstring(help_string, Value::new(span, c)),
+                .dispatch_with_raw_args(raw_args, Value::new(span, c)),
         }
     }
+}
 
-    fn dispatch_with_supplied_helpstring(
-        self,
-        help_string: String,
-        flag_values: Value<Either<B, C>>,
-    ) -> R {
+impl<'a, C1, C2, A, B, C, R> DispatchableOnce<A, Either<B, C>, R> for OneOf<C1, C2>
+where
+    C1: Evaluatable<'a, A, B> + DispatchableOnce<A, B, R>,
+    C2: Evaluatable<'a, A, C> + DispatchableOnce<A, C, R>,
+{
+    fn dispatch_once(self, flag_values: Value<Either<B, C>>) -> R {
         let span = flag_values.span;
         let values = flag_values.value;
 
         match values {
-            Either::Left(b) => self
-                .left
-                .dispatch_with_supplied_helpstring(help_string, Value::new(span, b)),
-            Either::Right(c) => self
-                .right
+            Either::Left(b) => self.left.dispatch_once(Value::new(span, b)),
+            Either::Right(c) => self.right.dispatch_once(Value::new(span, c)),
+        }
+    }
+}
+
+impl<A, C1, C2, B, C, R> DispatchableWithHelpString<A, Either<B, C>, R> for OneOf<C1, C2>
+where
+    C1: DispatchableWithHelpString<A, B, R>,
+    C2: DispatchableWithHelpString<A, C, R>,
+{
+    /// Dispatches to the matched branch, which generates its own helpstring.
+    fn dispatch_with_helpstring(self, flag_values: Value<Either<B, C>>) -> R {
+        let span = flag_values.span;
+        let values = flag_values.value;
+
+        match values {
+            Either::Left(b) => self.left.dispatch_with_helpstring(Value::new(span, b)),
+            Either::Right(c) => self.right.dispatch_with_helpstring(Value::new(span, c)),
+        }
+    }
+
+    fn dispatch_with_supplied_helpstring(
+        self,
+        help_string: String,
+        flag_values: Value<Either<B, C>>,
+    ) -> R {
+        let span = flag_values.span;
+        let values = flag_values.value;
+
+        match values {
+            Either::Left(b) => self
+                .left
+                .dispatch_with_supplied_helpstring(help_string, Value::new(span, b)),
+            Either::Right(c) => self
+                .right
                 .dispatch_with_supplied_helpstring(help_string, Value::new(span, c)),
         }
     }
@@ -527,30 +1113,25 @@ where
 
 impl<A, C1, C2, B, C, R> DispatchableWithHelpStringAndArgs<A, Either<B, C>, R> for OneOf<C1, C2>
 where
-    Self: Helpable<Output = String>,
     C1: DispatchableWithHelpStringAndArgs<A, B, R>,
     C2: DispatchableWithHelpStringAndArgs<A, C, R>,
 {
+    /// Dispatches to the matched branch, which generates its own helpstring.
     fn dispatch_with_helpstring_and_args(
         self,
         args: StringArgs,
         flag_values: Value<Either<B, C>>,
     ) -> R {
-        let help_string = self.help();
         let span = flag_values.span;
         let values = flag_values.value;
 
         match values {
-            Either::Left(b) => self.left.dispatch_with_supplied_helpstring_and_args(
-                help_string,
-                args,
-                Value::new(span, b),
-            ),
-            Either::Right(c) => self.right.dispatch_with_supplied_helpstring_and_args(
-                help_string,
-                args,
-                Value::new(span, c),
-            ),
+            Either::Left(b) => self
+                .left
+                .dispatch_with_helpstring_and_args(args, Value::new(span, b)),
+            Either::Right(c) => self
+                .right
+                .dispatch_with_helpstring_and_args(args, Value::new(span, c)),
         }
     }
 
@@ -593,6 +1174,227 @@ where
 /// A marker trait to denote cmd-like objects from terminal objects.
 pub trait IsCmd {}
 
+/// SubcommandNames provides the name and description of each command that a
+/// cmd-like object can evaluate to, in declaration order. A `CmdGroup` uses
+/// these to recognize and report its subcommands, so custom cmd-like types
+/// passed to `CmdGroup::with_command` must implement it.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// struct Version;
+///
+/// impl<'a> Evaluatable<'a, &'a [&'a str], ()> for Version {
+///     fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, ()> {
+///         match input.first() {
+///             Some(&"version") => Ok(Value::new(Span::from_range(0..1), ())),
+///             _ => Err(CliError::AmbiguousCommand),
+///         }
+///     }
+/// }
+///
+/// impl SubcommandNames for Version {
+///     fn subcommand_summaries(&self) -> Vec<(&'static str, &'static str)> {
+///         vec![("version", "print the version")]
+///     }
+/// }
+///
+/// let group = CmdGroup::new("tool")
+///     .subcommand_required()
+///     .with_command(Version);
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(0..2), ())),
+///     group.evaluate(&["tool", "version"][..])
+/// );
+/// assert_eq!(
+///     Err(CliError::MissingSubcommand {
+///         available: vec!["version".to_string()]
+///     }),
+///     group.evaluate(&["tool"][..])
+/// );
+/// ```
+pub trait SubcommandNames {
+    /// Returns the name and description of each command.
+    fn subcommand_summaries(&self) -> Vec<(&'static str, &'static str)>;
+
+    /// Returns the name of each command.
+    fn subcommand_names(&self) -> Vec<&'static str> {
+        self.subcommand_summaries()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+impl<C> SubcommandNames for CmdGroup<C> {
+    fn subcommand_summaries(&self) -> Vec<(&'static str, &'static str)> {
+        vec![(self.name, self.description)]
+    }
+}
+
+impl<C1, C2> SubcommandNames for OneOf<C1, C2>
+where
+    C1: SubcommandNames,
+    C2: SubcommandNames,
+{
+    fn subcommand_summaries(&self) -> Vec<(&'static str, &'static str)> {
+        let mut summaries = self.left.subcommand_summaries();
+        summaries.extend(self.right.subcommand_summaries());
+        summaries
+    }
+}
+
+impl<F, H> SubcommandNames for Cmd<F, H> {
+    fn subcommand_summaries(&self) -> Vec<(&'static str, &'static str)> {
+        vec![(self.name, self.description)]
+    }
+}
+
+/// StructureCloneable provides a copy of a cmd-like object's structure, its
+/// metadata, flags and subcommands, without any handlers, as returned by
+/// `Cmd::clone_structure` and `CmdGroup::clone_structure`.
+pub trait StructureCloneable {
+    type Output;
+
+    fn clone_structure(&self) -> Self::Output;
+}
+
+impl<T, H> StructureCloneable for Cmd<T, H>
+where
+    T: Clone,
+{
+    type Output = Cmd<T, ()>;
+
+    fn clone_structure(&self) -> Self::Output {
+        Cmd::clone_structure(self)
+    }
+}
+
+impl<C1, C2> StructureCloneable for OneOf<C1, C2>
+where
+    C1: StructureCloneable,
+    C2: StructureCloneable,
+{
+    type Output = OneOf<C1::Output, C2::Output>;
+
+    fn clone_structure(&self) -> Self::Output {
+        OneOf::new(self.left.clone_structure(), self.right.clone_structure())
+    }
+}
+
+impl<C> StructureCloneable for CmdGroup<C>
+where
+    C: StructureCloneable,
+{
+    type Output = CmdGroup<C::Output>;
+
+    fn clone_structure(&self) -> Self::Output {
+        CmdGroup::clone_structure(self)
+    }
+}
+
+/// CommandNode models a command and its subcommands for documentation and
+/// completion generators, as returned by `CmdGroup::tree_model`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandNode {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub flags: Vec<FlagMetadata>,
+    pub subcommands: Vec<CommandNode>,
+}
+
+/// FlagMetadata describes a single flag of a CommandNode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlagMetadata {
+    /// The long form of the flag, including any prefix.
+    pub name: String,
+    pub short_code: &'static str,
+    pub description: &'static str,
+    pub modifiers: Vec<String>,
+    pub env_var: Option<&'static str>,
+    /// Whether the flag consumes a value, rather than being set by its
+    /// presence alone.
+    pub takes_value: bool,
+}
+
+impl From<&FlagHelpContext> for FlagMetadata {
+    fn from(ctx: &FlagHelpContext) -> Self {
+        Self {
+            name: ctx.long_name(),
+            short_code: ctx.short_code,
+            description: ctx.description,
+            modifiers: ctx.modifiers.clone(),
+            env_var: ctx.env_var,
+            takes_value: ctx.takes_value,
+        }
+    }
+}
+
+/// CommandTree provides a CommandNode for each command that a cmd-like
+/// object can evaluate to, in declaration order.
+pub trait CommandTree {
+    fn command_nodes(&self) -> Vec<CommandNode>;
+}
+
+impl<C> CommandTree for CmdGroup<C>
+where
+    C: CommandTree,
+{
+    fn command_nodes(&self) -> Vec<CommandNode> {
+        vec![self.tree_model()]
+    }
+}
+
+impl<C1, C2> CommandTree for OneOf<C1, C2>
+where
+    C1: CommandTree,
+    C2: CommandTree,
+{
+    fn command_nodes(&self) -> Vec<CommandNode> {
+        let mut nodes = self.left.command_nodes();
+        nodes.extend(self.right.command_nodes());
+        nodes
+    }
+}
+
+// Cmd has no flags
+impl<H> CommandTree for Cmd<(), H> {
+    fn command_nodes(&self) -> Vec<CommandNode> {
+        vec![CommandNode {
+            name: self.name,
+            description: self.description,
+            flags: Vec::new(),
+            subcommands: Vec::new(),
+        }]
+    }
+}
+
+impl<F, H> CommandTree for Cmd<F, H>
+where
+    F: ShortHelpable<Output = FlagHelpCollector>,
+{
+    fn command_nodes(&self) -> Vec<CommandNode> {
+        let flags = self
+            .flags
+            .short_help()
+            .contexts()
+            .into_iter()
+            .map(FlagMetadata::from)
+            .collect();
+
+        vec![CommandNode {
+            name: self.name,
+            description: self.description,
+            flags,
+            subcommands: Vec::new(),
+        }]
+    }
+}
+
 /// Cmd represents an executable Cmd for the purpose of collating both flags
 /// and a corresponding handler.
 ///
@@ -624,18 +1426,246 @@ pub trait IsCmd {}
 ///         .evaluate(&["test", "-l", "info"][..])
 /// )
 /// ```
+///
+/// Input not beginning with the command's name, including an empty input,
+/// fails to evaluate with a `CliError::AmbiguousCommand`.
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///     Err(CliError::AmbiguousCommand),
+///     Cmd::new("test")
+///         .with_flag(Flag::store_true("verbose", "v", "Enable verbose output.").optional())
+///         .with_handler(|_| {})
+///         .evaluate(&[][..])
+/// );
+/// ```
 #[derive(Debug)]
 pub struct Cmd<F, H> {
     name: &'static str,
     description: &'static str,
     author: &'static str,
     version: &'static str,
+    /// Text rendered following the flags in compact help.
+    after_help: &'static str,
+    /// Text rendered following the flags in full help.
+    after_long_help: &'static str,
     flags: F,
     handler: H,
+    run_options: RunOptions,
+    help_style: HelpStyle,
+    /// The index and name of each positional argument, for help generation.
+    positionals: Vec<(usize, &'static str)>,
+    trailing_var_arg: bool,
+    /// The permitted number of unused positional arguments, if bounded.
+    positional_count: Option<std::ops::RangeInclusive<usize>>,
+    /// Treats unmatched dash-prefixed arguments as positional values.
+    keep_unknown_flags: bool,
 }
 
 impl<F, H> IsCmd for Cmd<F, H> {}
 
+/// Expands to the calling crate's `CARGO_PKG_VERSION`, for use with
+/// `Cmd::version`. As `env!` is resolved where the macro is expanded, the
+/// version is that of the binary rather than of scrap.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let cmd = Cmd::new("test").version(cmd_version!());
+/// assert!(!cmd.get_version().is_empty());
+/// ```
+#[macro_export]
+macro_rules! cmd_version {
+    () => {
+        env!("CARGO_PKG_VERSION")
+    };
+}
+
+/// Expands to the calling crate's `CARGO_PKG_AUTHORS`, for use with
+/// `Cmd::author`.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let cmd = Cmd::new("test").author(cmd_author!());
+/// assert_eq!(env!("CARGO_PKG_AUTHORS"), cmd.get_author());
+/// ```
+#[macro_export]
+macro_rules! cmd_author {
+    () => {
+        env!("CARGO_PKG_AUTHORS")
+    };
+}
+
+/// Expands to the calling crate's `CARGO_PKG_DESCRIPTION`, for use with
+/// `Cmd::description`.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let cmd = Cmd::new("test").description(cmd_description!());
+/// assert_eq!(env!("CARGO_PKG_DESCRIPTION"), cmd.get_description());
+/// ```
+#[macro_export]
+macro_rules! cmd_description {
+    () => {
+        env!("CARGO_PKG_DESCRIPTION")
+    };
+}
+
+/// Builds a `Span` from a list of matched positions, for constructing
+/// expected values when testing evaluators, including non-contiguous spans.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(Span::new(vec![0, 1, 3]), span![0, 1, 3]);
+/// assert_eq!(Span::empty(), span![]);
+/// ```
+#[macro_export]
+macro_rules! span {
+    ($($position:expr),* $(,)?) => {
+        $crate::Span::new(vec![$($position),*])
+    };
+}
+
+/// HelpStream identifies the standard stream that generated help is written
+/// to by the `run` helpers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpStream {
+    Stdout,
+    Stderr,
+}
+
+impl HelpStream {
+    /// Returns true if the stream is a terminal and color hasn't been
+    /// disabled via a non-empty `NO_COLOR` environment variable.
+    fn supports_color(self) -> bool {
+        use std::io::IsTerminal;
+
+        let is_terminal = match self {
+            HelpStream::Stdout => std::io::stdout().is_terminal(),
+            HelpStream::Stderr => std::io::stderr().is_terminal(),
+        };
+
+        is_terminal && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+    }
+}
+
+/// RunOptions captures the output behavior of a Cmd's `run` helpers.
+#[derive(Debug, Clone, Copy)]
+struct RunOptions {
+    /// The stream help is written to when explicitly requested.
+    requested_help: HelpStream,
+    /// The stream help is written to following an evaluation error.
+    error_help: HelpStream,
+    /// Suppresses all output following an evaluation error.
+    quiet_errors: bool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            requested_help: HelpStream::Stdout,
+            error_help: HelpStream::Stderr,
+            quiet_errors: false,
+        }
+    }
+}
+
+/// HelpStyle captures presentation options applied to a Cmd's generated help
+/// without affecting how its flags are evaluated.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let help = Cmd::new("test")
+///     .description("a test cmd")
+///     .help_style(HelpStyle::new().sort_flags(true))
+///     .with_flag(Flag::store_true("verbose", "v", "enable verbose output."))
+///     .with_flag(Flag::store_true("all", "a", "include everything."))
+///     .with_handler(|_| {})
+///     .help();
+///
+/// assert!(help.find("--all").unwrap() < help.find("--verbose").unwrap());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HelpStyle {
+    sort_flags: bool,
+    /// Renders help in the layout of Python's argparse.
+    argparse: bool,
+    /// Whether help is colored, or `None` to detect it from the terminal
+    /// where supported.
+    color: Option<bool>,
+}
+
+impl HelpStyle {
+    /// Instantiates a new HelpStyle with all options disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns HelpStyle with flags sorted by their long name when rendered,
+    /// rather than in declaration order.
+    pub fn sort_flags(mut self, sort_flags: bool) -> Self {
+        self.sort_flags = sort_flags;
+        self
+    }
+
+    /// Returns a HelpStyle preset rendering help in the layout of Python's
+    /// argparse, easing migration for users familiar with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let help = Cmd::new("test")
+    ///     .description("a test cmd")
+    ///     .help_style(HelpStyle::argparse())
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_handler(|_| {})
+    ///     .help();
+    ///
+    /// assert_eq!(
+    ///     "usage: test [-n NAME]\n\na test cmd\n\noptions:\n  -n NAME, --name NAME  A name.",
+    ///     help
+    /// );
+    /// ```
+    pub fn argparse() -> Self {
+        Self {
+            argparse: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns HelpStyle with color forced on or off, rather than detected
+    /// from the terminal by `Cmd::help_auto` and the `run` helpers.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
 impl Cmd<(), Box<dyn Fn()>> {
     /// Instantiates a new instance of `Cmd` with the name field set. All other
     /// fields will default to initial values (primarily empty strings).
@@ -654,8 +1684,16 @@ impl Cmd<(), Box<dyn Fn()>> {
             description: "",
             author: "",
             version: "",
+            after_help: "",
+            after_long_help: "",
             flags: (),
             handler: Box::new(|| ()),
+            run_options: RunOptions::default(),
+            help_style: HelpStyle::default(),
+            positionals: Vec::new(),
+            trailing_var_arg: false,
+            positional_count: None,
+            keep_unknown_flags: false,
         }
     }
 }
@@ -685,10 +1723,38 @@ impl<H> Cmd<(), H> {
             description: self.description,
             author: self.author,
             version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
             flags: new_flag,
             handler: self.handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
         }
     }
+
+    /// Returns a new instance of `Cmd` with its flags built from the passed
+    /// `FlagSet`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let shared = FlagSet::new(|| Flag::expect_string("config", "c", "A config path."));
+    ///
+    /// Cmd::new("test").with_flag_set(&shared);
+    /// ```
+    pub fn with_flag_set<C, NF>(self, flag_set: &FlagSet<C>) -> Cmd<NF, H>
+    where
+        C: Fn() -> NF,
+    {
+        self.with_flag(flag_set.build())
+    }
 }
 
 impl<T, H> Cmd<T, H> {
@@ -722,7 +1788,8 @@ impl<T, H> Cmd<T, H> {
         self
     }
 
-    /// Returns Cmd with the author string set to the provided value.
+    /// Returns Cmd with the description string set to the provided value.
+    /// Functionally this is an alias for `description`.
     ///
     /// # Examples
     ///
@@ -730,7 +1797,24 @@ impl<T, H> Cmd<T, H> {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// Cmd::new("test").author("John Doe <jdoe@example.com");
+    /// assert_eq!(
+    ///     "A test command.",
+    ///     Cmd::new("test").about("A test command.").get_description()
+    /// );
+    /// ```
+    pub fn about(self, description: &'static str) -> Self {
+        self.description(description)
+    }
+
+    /// Returns Cmd with the author string set to the provided value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test").author("John Doe <jdoe@example.com");
     /// ```
     pub fn author(mut self, author: &'static str) -> Self {
         self.author = author;
@@ -752,8 +1836,10 @@ impl<T, H> Cmd<T, H> {
         self
     }
 
-    /// Returns Cmd with the handler set to the provided function in the format
-    /// of `Fn(evaluator return) -> R`.
+    /// Returns Cmd with the after help string set to the provided value.
+    /// After help is rendered, verbatim, following the flags. As no compact
+    /// help exists yet, it is rendered by `help` unless an `after_long_help`
+    /// is also set.
     ///
     /// # Examples
     ///
@@ -761,25 +1847,302 @@ impl<T, H> Cmd<T, H> {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// Cmd::new("test").with_handler(|_| ());
+    /// let help = Cmd::new("test")
+    ///     .after_help("See the manual for more.")
+    ///     .with_handler(|_| {})
+    ///     .help();
+    ///
+    /// assert!(help.ends_with("\n\nSee the manual for more."));
     /// ```
-    pub fn with_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
+    pub fn after_help(mut self, after_help: &'static str) -> Self {
+        self.after_help = after_help;
+        self
+    }
+
+    /// Returns Cmd with the after long help string set to the provided value.
+    /// Long after help is rendered, verbatim, following the flags in the full
+    /// help, preserving any newlines, taking precedence over `after_help`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let help = Cmd::new("test")
+    ///     .after_help("See the manual.")
+    ///     .after_long_help("Examples:\n    test -n foo\n\nSee the manual.")
+    ///     .with_handler(|_| {})
+    ///     .help();
+    ///
+    /// assert!(help.ends_with("\n\nExamples:\n    test -n foo\n\nSee the manual."));
+    /// ```
+    pub fn after_long_help(mut self, after_long_help: &'static str) -> Self {
+        self.after_long_help = after_long_help;
+        self
+    }
+
+    /// Returns the after help rendered by the full help, preferring the long
+    /// form when set.
+    fn rendered_after_help(&self) -> &'static str {
+        if self.after_long_help.is_empty() {
+            self.after_help
+        } else {
+            self.after_long_help
+        }
+    }
+
+    /// Returns the name of the command.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!("test", Cmd::new("test").get_name());
+    /// ```
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the description of the command.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     "A test command.",
+    ///     Cmd::new("test").description("A test command.").get_description()
+    /// );
+    /// ```
+    pub fn get_description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Returns the author of the command.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     "John Doe <jdoe@example.com>",
+    ///     Cmd::new("test").author("John Doe <jdoe@example.com>").get_author()
+    /// );
+    /// ```
+    pub fn get_author(&self) -> &'static str {
+        self.author
+    }
+
+    /// Returns the version of the command.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!("1.0.0", Cmd::new("test").version("1.0.0").get_version());
+    /// ```
+    pub fn get_version(&self) -> &'static str {
+        self.version
+    }
+
+    /// Returns Cmd with flag evaluation stopped at the first positional
+    /// argument, leaving it and every argument following it, flags included,
+    /// unevaluated to be collected as trailing arguments. This suits commands
+    /// like `env` or `time` that pass the remainder of their input to another
+    /// command. A token consumed as the value of a preceding flag isn't
+    /// considered positional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let input = ["time", "-n", "foo", "ls", "-n"];
+    /// let flags = Cmd::new("time")
+    ///     .trailing_var_arg()
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_handler(|name| name)
+    ///     .evaluate(&input[..])
+    ///     .unwrap();
+    ///
+    /// assert_eq!("foo".to_string(), flags.value);
+    /// assert_eq!(
+    ///     vec!["ls".to_string(), "-n".to_string()],
+    ///     return_unused_args(&input[..], &flags.span)
+    ///         .into_iter()
+    ///         .map(|arg| arg.unwrap())
+    ///         .collect::<Vec<String>>()
+    /// );
+    /// ```
+    pub fn trailing_var_arg(mut self) -> Self {
+        self.trailing_var_arg = true;
+        self
+    }
+
+    /// Returns Cmd bounding the number of positional arguments left unused
+    /// by its flags, i.e. those that don't look like a flag, failing
+    /// evaluation with a `CliError::WrongArgCount` when outside the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = Cmd::new("cp")
+    ///     .positionals(2..=2)
+    ///     .with_flag(Flag::store_true("force", "f", "Overwrite files.").optional())
+    ///     .with_handler(|_| {});
+    ///
+    /// assert!(cmd.evaluate(&["cp", "-f", "a", "b"][..]).is_ok());
+    /// assert_eq!(
+    ///     Err(CliError::WrongArgCount { expected: "2..=2".to_string(), got: 1 }),
+    ///     cmd.evaluate(&["cp", "a"][..])
+    /// );
+    /// ```
+    pub fn positionals(mut self, count: std::ops::RangeInclusive<usize>) -> Self {
+        self.positional_count = Some(count);
+        self
+    }
+
+    /// Returns Cmd treating any dash-prefixed argument that doesn't match one
+    /// of its flags as a positional value, for passthrough tools that forward
+    /// unknown flags to another program. Unknown flags are then counted by
+    /// `positionals`, start the trailing arguments of a `trailing_var_arg`
+    /// command and are reported among the `unused_positionals` of
+    /// `evaluate_report`, each in its original position.
+    ///
+    /// Because an unknown flag carries no definition, the argument following
+    /// it is kept alongside it purely by position. This heuristic can't tell
+    /// a value of the unknown flag from an unrelated positional argument, nor
+    /// detect an unknown flag that takes more than one value, so pairing them
+    /// is left to the handler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let input = ["hello", "--unknown", "x", "-n", "foo", "y"];
+    /// let report = Cmd::new("hello")
+    ///     .keep_unknown_flags()
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_handler(|name| name)
+    ///     .evaluate_report(&input[..])
+    ///     .unwrap();
+    ///
+    /// assert!(report.unused_flags.is_empty());
+    /// assert_eq!(
+    ///     vec![
+    ///         (1, "--unknown".to_string()),
+    ///         (2, "x".to_string()),
+    ///         (5, "y".to_string())
+    ///     ],
+    ///     report.unused_positionals
+    /// );
+    /// ```
+    pub fn keep_unknown_flags(mut self) -> Self {
+        self.keep_unknown_flags = true;
+        self
+    }
+
+    /// Returns true if an argument unconsumed by the Cmd's flags is a
+    /// positional value, i.e. it doesn't look like a flag or unknown flags
+    /// are kept as values.
+    fn is_positional(&self, arg: &str) -> bool {
+        self.keep_unknown_flags || arg == "-" || !arg.starts_with('-')
+    }
+
+    /// Consumes the Cmd, returning its flags namespaced by the provided prefix,
+    /// for merging into a parent command with `with_flag`. This eases
+    /// migrating a subcommand to a group of namespaced flags, with each flag
+    /// matched and documented by its prefixed name. The Cmd's metadata and
+    /// handler are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let db = Cmd::new("db")
+    ///     .with_flag(Flag::expect_string("host", "H", "A database host."))
+    ///     .with_flag(Flag::expect_u16("port", "P", "A database port."));
+    ///
+    /// let cmd = Cmd::new("test")
+    ///     .with_flag(Flag::store_true("debug", "d", "Enable debug output."))
+    ///     .with_flag(db.flatten_group("db"))
+    ///     .with_handler(|_| {});
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(
+    ///         Span::from_range(0..6),
+    ///         (true, ("localhost".to_string(), 5432))
+    ///     )),
+    ///     cmd.evaluate(&["test", "-d", "--db-host", "localhost", "--db-port", "5432"][..])
+    /// );
+    /// assert!(cmd.help().contains("--db-port"));
+    /// ```
+    pub fn flatten_group(self, prefix: &'static str) -> Prefixed<T> {
+        Prefixed::new(prefix, self.flags)
+    }
+
+    /// Returns a copy of the Cmd's structure, its metadata, flags and
+    /// options, without its handler. As handlers are arbitrary closures that
+    /// can't generally be cloned, this allows a definition to be reused, for
+    /// example to generate help after the original has been dispatched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = Cmd::new("test")
+    ///     .description("a test cmd")
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_handler(|name| name);
+    ///
+    /// let structure = cmd.clone_structure();
+    /// let flags = cmd.evaluate(&["test", "-n", "foo"][..]).unwrap();
+    /// assert_eq!("foo".to_string(), cmd.dispatch(flags));
+    ///
+    /// assert!(structure.help().contains("--name"));
+    /// ```
+    pub fn clone_structure(&self) -> Cmd<T, ()>
     where
-        T: Evaluatable<'a, A, B>,
-        NH: Fn(B) -> R,
+        T: Clone,
     {
         Cmd {
             name: self.name,
             description: self.description,
             author: self.author,
             version: self.version,
-            flags: self.flags,
-            handler,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags: self.flags.clone(),
+            handler: (),
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals.clone(),
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count.clone(),
+            keep_unknown_flags: self.keep_unknown_flags,
         }
     }
 
-    /// Returns Cmd with the handler set to the provided function in the format
-    /// of `Fn(StringArgs, evaluator return) -> R`.
+    /// Returns Cmd with the provided HelpStyle applied to its generated help.
     ///
     /// # Examples
     ///
@@ -787,25 +2150,108 @@ impl<T, H> Cmd<T, H> {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// Cmd::new("test").with_args_handler(|_args, ()| ());
+    /// Cmd::new("test").help_style(HelpStyle::new().sort_flags(true));
     /// ```
-    pub fn with_args_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
-    where
-        T: Evaluatable<'a, A, B>,
-        NH: Fn(StringArgs, B) -> R,
-    {
+    pub fn help_style(mut self, style: HelpStyle) -> Self {
+        self.help_style = style;
+        self
+    }
+
+    /// Returns Cmd with explicitly requested help, via `--help` or `-h`,
+    /// written to the provided stream by the `run` helpers. Defaults to
+    /// stdout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test").requested_help_to(HelpStream::Stderr);
+    /// ```
+    pub fn requested_help_to(mut self, stream: HelpStream) -> Self {
+        self.run_options.requested_help = stream;
+        self
+    }
+
+    /// Returns Cmd with help generated from an evaluation error written to
+    /// the provided stream by the `run` helpers. Defaults to stderr.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test").error_help_to(HelpStream::Stdout);
+    /// ```
+    pub fn error_help_to(mut self, stream: HelpStream) -> Self {
+        self.run_options.error_help = stream;
+        self
+    }
+
+    /// Returns Cmd with the `run` helpers writing nothing following an
+    /// evaluation error, leaving the returned `CliError` to be presented by
+    /// the caller. Explicitly requested help is still written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test").quiet_errors();
+    /// ```
+    pub fn quiet_errors(mut self) -> Self {
+        self.run_options.quiet_errors = true;
+        self
+    }
+
+    /// Returns Cmd with its flags set to the provided, pre-built, evaluator.
+    /// Unlike `with_flag` this replaces any existing flags rather than joining
+    /// onto them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let flags = Join::new(
+    ///     Flag::expect_string("name", "n", "A name."),
+    ///     Flag::store_true("debug", "d", "Run command in debug mode.")
+    ///         .optional()
+    ///         .with_default(false),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(0..3), ("foo".to_string(), false))),
+    ///     Cmd::new("test")
+    ///         .with_flags(flags)
+    ///         .evaluate(&["test", "-n", "foo"][..])
+    /// );
+    /// ```
+    pub fn with_flags<NF>(self, flags: NF) -> Cmd<NF, H> {
         Cmd {
             name: self.name,
             description: self.description,
             author: self.author,
             version: self.version,
-            flags: self.flags,
-            handler,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags,
+            handler: self.handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
         }
     }
 
     /// Returns Cmd with the handler set to the provided function in the format
-    /// of `Fn(helpstring, evaluator return) -> R`.
+    /// of `Fn(evaluator return) -> R`.
     ///
     /// # Examples
     ///
@@ -813,25 +2259,61 @@ impl<T, H> Cmd<T, H> {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// Cmd::new("test").with_helpstring_handler(|_helpstring, ()| ());
+    /// Cmd::new("test").with_handler(|_| ());
     /// ```
-    pub fn with_helpstring_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
+    ///
+    /// The handler's signature is checked at compile-time against the
+    /// evaluated type of the command's flags. A command with two flags
+    /// receives both values as a tuple.
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test")
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_flag(Flag::store_true("debug", "d", "Run command in debug mode."))
+    ///     .with_handler(|(name, debug): (String, bool)| format!("{} {}", name, debug));
+    /// ```
+    ///
+    /// While a handler that only accepts one of those values fails to compile.
+    ///
+    /// ```compile_fail
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test")
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_flag(Flag::store_true("debug", "d", "Run command in debug mode."))
+    ///     .with_handler(|name: String| name);
+    /// ```
+    pub fn with_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
     where
         T: Evaluatable<'a, A, B>,
-        NH: Fn(String, B) -> R,
+        NH: Fn(B) -> R,
     {
         Cmd {
             name: self.name,
             description: self.description,
             author: self.author,
             version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
             flags: self.flags,
             handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
         }
     }
 
-    /// Returns Cmd with the handler set to the provided function in the format
-    /// of `Fn(helpstring, StringArgs, evaluator return) -> R`.
+    /// Returns Cmd with the handler set to the provided single-use function
+    /// in the format of `FnOnce(evaluator return) -> R`, allowing the handler
+    /// to consume captured state. Such a handler is dispatched with
+    /// `dispatch_once`.
     ///
     /// # Examples
     ///
@@ -839,29 +2321,46 @@ impl<T, H> Cmd<T, H> {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// Cmd::new("test").with_helpstring_and_args_handler(|_helpstring, _args, ()| ());
+    /// let log = vec!["started".to_string()];
+    /// let cmd = Cmd::new("test")
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_once_handler(move |name| {
+    ///         let mut log = log;
+    ///         log.push(name);
+    ///         log
+    ///     });
+    ///
+    /// let flag_values = cmd.evaluate(&["test", "-n", "foo"][..]).unwrap();
+    /// assert_eq!(
+    ///     vec!["started".to_string(), "foo".to_string()],
+    ///     cmd.dispatch_once(flag_values)
+    /// );
     /// ```
-    pub fn with_helpstring_and_args_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
+    pub fn with_once_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
     where
         T: Evaluatable<'a, A, B>,
-        NH: Fn(String, StringArgs, B) -> R,
+        NH: FnOnce(B) -> R,
     {
         Cmd {
             name: self.name,
             description: self.description,
             author: self.author,
             version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
             flags: self.flags,
             handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
         }
     }
-}
 
-impl<T, H> Cmd<T, H>
-where
-    T: IsFlag,
-{
-    /// Appends a flag to a given command.
+    /// Returns Cmd with the handler set to the provided function in the format
+    /// of `Fn(StringArgs, evaluator return) -> R`.
     ///
     /// # Examples
     ///
@@ -869,7 +2368,159 @@ where
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// Cmd::new("test")
+    /// Cmd::new("test").with_args_handler(|_args, ()| ());
+    /// ```
+    pub fn with_args_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
+    where
+        T: Evaluatable<'a, A, B>,
+        NH: Fn(StringArgs, B) -> R,
+    {
+        Cmd {
+            name: self.name,
+            description: self.description,
+            author: self.author,
+            version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags: self.flags,
+            handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
+        }
+    }
+
+    /// Returns Cmd with the handler set to the provided function in the format
+    /// of `Fn(evaluator return, raw args) -> R`. Unlike `with_args_handler`,
+    /// which receives only the unused arguments, the handler receives the
+    /// complete original arguments, including those matched by flags, for
+    /// uses like logging the exact invocation or re-executing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let input = ["test", "-n", "foo"];
+    /// let cmd = Cmd::new("test")
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_raw_handler(|_name, raw_args: &[&str]| raw_args.join(" "));
+    ///
+    /// let flag_values = cmd.evaluate(&input[..]).unwrap();
+    /// assert_eq!(
+    ///     "test -n foo".to_string(),
+    ///     cmd.dispatch_with_raw_args(&input[..], flag_values)
+    /// );
+    /// ```
+    pub fn with_raw_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
+    where
+        T: Evaluatable<'a, A, B>,
+        NH: Fn(B, &[&str]) -> R,
+    {
+        Cmd {
+            name: self.name,
+            description: self.description,
+            author: self.author,
+            version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags: self.flags,
+            handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
+        }
+    }
+
+    /// Returns Cmd with the handler set to the provided function in the format
+    /// of `Fn(helpstring, evaluator return) -> R`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test").with_helpstring_handler(|_helpstring, ()| ());
+    /// ```
+    pub fn with_helpstring_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
+    where
+        T: Evaluatable<'a, A, B>,
+        NH: Fn(String, B) -> R,
+    {
+        Cmd {
+            name: self.name,
+            description: self.description,
+            author: self.author,
+            version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags: self.flags,
+            handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
+        }
+    }
+
+    /// Returns Cmd with the handler set to the provided function in the format
+    /// of `Fn(helpstring, StringArgs, evaluator return) -> R`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test").with_helpstring_and_args_handler(|_helpstring, _args, ()| ());
+    /// ```
+    pub fn with_helpstring_and_args_handler<'a, A, B, NH, R>(self, handler: NH) -> Cmd<T, NH>
+    where
+        T: Evaluatable<'a, A, B>,
+        NH: Fn(String, StringArgs, B) -> R,
+    {
+        Cmd {
+            name: self.name,
+            description: self.description,
+            author: self.author,
+            version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags: self.flags,
+            handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
+        }
+    }
+}
+
+impl<T, H> Cmd<T, H>
+where
+    T: IsFlag,
+{
+    /// Appends a flag to a given command.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test")
     ///     .with_flag(
     ///         Flag::store_false("no-wait", "n", "don't wait for a response.")
     ///     );
@@ -880,8 +2531,235 @@ where
             description: self.description,
             author: self.author,
             version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
             flags: Join::new(self.flags, new_flag),
             handler: self.handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
+        }
+    }
+
+    /// Appends the flags built from the passed `FlagSet` to a given command.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let shared = FlagSet::new(|| Flag::expect_string("config", "c", "A config path."));
+    ///
+    /// Cmd::new("test")
+    ///     .with_flag(
+    ///         Flag::store_false("no-wait", "n", "don't wait for a response.")
+    ///     )
+    ///     .with_flag_set(&shared);
+    /// ```
+    pub fn with_flag_set<C, NF>(self, flag_set: &FlagSet<C>) -> Cmd<Join<T, NF>, H>
+    where
+        C: Fn() -> NF,
+    {
+        self.with_flag(flag_set.build())
+    }
+
+    /// Appends a positional argument, evaluated at the given index of the
+    /// arguments following the command name that aren't consumed by its
+    /// flags, to a given command. The
+    /// positional is documented by name in both the usage synopsis and an
+    /// "Arguments:" section of the command's help.
+    ///
+    /// As positionals are joined to the command's flags, `with_positional`
+    /// is only available once at least one flag has been appended with
+    /// `with_flag` or `with_flag_set`. A command without flags can instead
+    /// collect its arguments with `return_unused_args`.
+    ///
+    /// ```compile_fail
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Cmd::new("test").with_positional("file", 0, StringValue);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = Cmd::new("test")
+    ///     .with_flag(
+    ///         Flag::store_true("verbose", "v", "enable verbose output.")
+    ///             .optional()
+    ///             .with_default(false)
+    ///     )
+    ///     .with_positional("file", 0, StringValue)
+    ///     .with_handler(|(verbose, file)| (verbose, file));
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(0..2), (false, "a.txt".to_string()))),
+    ///     cmd.evaluate(&["test", "a.txt"][..])
+    /// );
+    /// ```
+    pub fn with_positional<V>(
+        mut self,
+        name: &'static str,
+        index: usize,
+        value: V,
+    ) -> Cmd<WithPositional<T, Positional<V>>, H> {
+        let preceding = self.positionals.iter().map(|&(idx, _)| idx).collect();
+        self.positionals.push((index, name));
+
+        Cmd {
+            name: self.name,
+            description: self.description,
+            author: self.author,
+            version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags: WithPositional {
+                evaluator: self.flags,
+                positional: Positional::new(name, index, value),
+                preceding,
+            },
+            handler: self.handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
+        }
+    }
+
+    /// Returns Cmd with a validator run against the fully evaluated flag
+    /// values, allowing validation across several flags. A validation
+    /// failure is returned as a `CliError::Validation` carrying the
+    /// validator's message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = Cmd::new("test")
+    ///     .with_flag(Flag::expect_u8("min", "m", "A minimum."))
+    ///     .with_flag(Flag::expect_u8("max", "x", "A maximum."))
+    ///     .with_validator(|(min, max): &(u8, u8)| {
+    ///         if min <= max {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("--max must be greater than or equal to --min".to_string())
+    ///         }
+    ///     })
+    ///     .with_handler(|(min, max)| max - min);
+    ///
+    /// assert_eq!(
+    ///     Err(CliError::Validation(
+    ///         "--max must be greater than or equal to --min".to_string()
+    ///     )),
+    ///     cmd.evaluate(&["test", "--min", "5", "--max", "3"][..])
+    /// );
+    /// ```
+    pub fn with_validator<V>(self, validator: V) -> Cmd<Validated<T, V>, H> {
+        Cmd {
+            name: self.name,
+            description: self.description,
+            author: self.author,
+            version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags: Validated::new(self.flags, validator),
+            handler: self.handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
+        }
+    }
+
+    /// Returns Cmd with the named flags declared mutually exclusive, such
+    /// that at most one of them may be passed. Passing more fails evaluation
+    /// with a `CliError::MutuallyExclusive` listing the offending flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = Cmd::new("test")
+    ///     .with_flag(Flag::store_true("json", "j", "Output json.").optional())
+    ///     .with_flag(Flag::store_true("yaml", "y", "Output yaml.").optional())
+    ///     .conflicts(&["json", "yaml"])
+    ///     .with_handler(|_| {});
+    ///
+    /// assert_eq!(
+    ///     Err(CliError::MutuallyExclusive(vec!["json".to_string(), "yaml".to_string()])),
+    ///     cmd.evaluate(&["test", "-j", "--yaml"][..])
+    /// );
+    /// ```
+    pub fn conflicts<const N: usize>(self, names: &[&'static str; N]) -> Cmd<Conflicts<T, N>, H> {
+        Cmd {
+            name: self.name,
+            description: self.description,
+            author: self.author,
+            version: self.version,
+            after_help: self.after_help,
+            after_long_help: self.after_long_help,
+            flags: Conflicts::new(*names, self.flags),
+            handler: self.handler,
+            run_options: self.run_options,
+            help_style: self.help_style,
+            positionals: self.positionals,
+            trailing_var_arg: self.trailing_var_arg,
+            positional_count: self.positional_count,
+            keep_unknown_flags: self.keep_unknown_flags,
+        }
+    }
+}
+
+impl<F, H> Cmd<F, H> {
+    /// Evaluates the flags over the arguments preceding the first positional
+    /// one, i.e. the first argument that is neither consumed by a flag nor
+    /// flag-like. Evaluation starts from the complete input so that values
+    /// of flags passed later are still recognized, and is repeated over the
+    /// arguments preceding each newly found positional until none remain.
+    fn evaluate_until_positional<'a, B>(&self, args: &'a [&'a str]) -> EvaluateResult<'a, B>
+    where
+        F: Evaluatable<'a, &'a [&'a str], B>,
+    {
+        let positional_like = |idx: &usize| self.is_positional(args[*idx]);
+        let mut end = args.len();
+        let mut first_err = None;
+
+        loop {
+            let evaluated = self.flags.evaluate(&args[..end]);
+            let positional = match &evaluated {
+                Ok(v) => (0..end)
+                    .filter(|idx| !v.span.0.contains(idx))
+                    .find(positional_like),
+                // the error may stem from the trailing arguments, so retry
+                // without the last argument that may start them.
+                Err(_) => (0..end).rev().find(positional_like),
+            };
+
+            match (positional, evaluated) {
+                (Some(idx), Err(e)) => {
+                    first_err.get_or_insert(e);
+                    end = idx;
+                }
+                (Some(idx), Ok(_)) => end = idx,
+                (None, Ok(v)) => return Ok(v),
+                (None, Err(e)) => return Err(first_err.unwrap_or(e)),
+            }
         }
     }
 }
@@ -898,13 +2776,38 @@ where
 
         match filename {
             Some(Some(name)) if name == self.name => {
+                let args = &input[1..];
+                let evaluated = if self.trailing_var_arg {
+                    self.evaluate_until_positional(args)
+                } else {
+                    self.flags.evaluate(args)
+                };
+
                 // capture offset for binary.
-                self.flags.evaluate(&input[1..]).map(|v| v.from_offset(1))
+                evaluated.map(|v| v.from_offset(1))
             }
             _ => Err(CliError::AmbiguousCommand),
         }
         // include binary in span range
         .map(|v| Value::new(Span::from_range(0..1).join(v.span), v.value))
+        .and_then(|v| match &self.positional_count {
+            Some(count) => {
+                let got = return_unused_args_with_positions(input, &v.span)
+                    .iter()
+                    .filter(|(_, arg)| self.is_positional(arg))
+                    .count();
+
+                if count.contains(&got) {
+                    Ok(v)
+                } else {
+                    Err(CliError::WrongArgCount {
+                        expected: format!("{:?}", count),
+                        got,
+                    })
+                }
+            }
+            None => Ok(v),
+        })
     }
 }
 
@@ -921,10 +2824,15 @@ impl<H> Helpable for Cmd<(), H> {
     type Output = String;
 
     fn help(&self) -> Self::Output {
-        format!(
+        let help = format!(
             "Usage: {} [OPTIONS]\n{}\nFlags:\n",
             self.name, self.description,
-        )
+        );
+
+        match self.rendered_after_help() {
+            "" => help,
+            after_help => format!("{}\n{}", help, after_help),
+        }
     }
 }
 
@@ -935,61 +2843,541 @@ where
     type Output = String;
 
     fn help(&self) -> Self::Output {
-        format!(
-            "Usage: {} [OPTIONS]\n{}\nFlags:\n{}",
-            self.name,
-            self.description,
-            self.flags.short_help()
-        )
+        let mut buf = Vec::new();
+        // writes to a Vec are infallible and only ever contain valid utf-8.
+        let _ = self.render_to(&mut buf);
+        String::from_utf8(buf).unwrap_or_default()
     }
-}
 
-impl<'a, T, H, A, B, R> Dispatchable<A, B, R> for Cmd<T, H>
-where
-    T: Evaluatable<'a, A, B>,
-    H: Fn(B) -> R,
-{
-    fn dispatch(self, flag_values: Value<B>) -> R {
-        let inner = flag_values.unwrap();
-        (self.handler)(inner)
+    fn render_to<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        self.render_styled(w, self.help_style.color.unwrap_or(false))
     }
 }
 
-impl<'a, T, H, A, B, R> DispatchableWithArgs<A, B, R> for Cmd<T, H>
+impl<F, H> Cmd<F, H>
 where
-    T: Evaluatable<'a, A, B>,
-    H: Fn(StringArgs, B) -> R,
+    F: ShortHelpable<Output = FlagHelpCollector>,
 {
-    fn dispatch_with_args(self, args: StringArgs, flag_values: Value<B>) -> R {
-        let inner = flag_values.unwrap();
-        (self.handler)(args, inner)
-    }
-}
+    /// Renders help to the writer, with section headers emphasized by ANSI
+    /// escape sequences when color is enabled.
+    fn render_styled<W: std::io::Write>(&self, w: &mut W, color: bool) -> std::io::Result<()> {
+        if self.help_style.argparse {
+            return self.render_argparse(w);
+        }
 
-impl<'a, A, T, H, B, R> DispatchableWithHelpString<A, B, R> for Cmd<T, H>
-where
-    Self: Helpable<Output = String>,
-    T: Evaluatable<'a, A, B>,
-    H: Fn(String, B) -> R,
-{
-    fn dispatch_with_helpstring(self, flag_values: Value<B>) -> R {
-        let inner = flag_values.unwrap();
-        let help_string = self.help();
-        (self.handler)(help_string, inner)
-    }
+        let header = |title: &str| {
+            if color {
+                format!("\x1b[1m{}\x1b[0m", title)
+            } else {
+                title.to_string()
+            }
+        };
 
-    fn dispatch_with_supplied_helpstring(self, help_string: String, flag_values: Value<B>) -> R {
-        let inner = flag_values.unwrap();
-        (self.handler)(help_string, inner)
-    }
-}
+        let mut positionals = self.positionals.clone();
+        positionals.sort_by_key(|(idx, _)| *idx);
 
-impl<'a, A, T, H, B, R> DispatchableWithHelpStringAndArgs<A, B, R> for Cmd<T, H>
-where
-    Self: Helpable<Output = String>,
-    T: Evaluatable<'a, A, B>,
-    H: Fn(String, StringArgs, B) -> R,
-{
+        write!(w, "{} {} [OPTIONS]", header("Usage:"), self.name)?;
+        for (_, name) in positionals.iter() {
+            write!(w, " <{}>", name)?;
+        }
+        write!(w, "\n{}\n", self.description)?;
+
+        if !positionals.is_empty() {
+            writeln!(w, "{}", header("Arguments:"))?;
+            for (_, name) in positionals.iter() {
+                writeln!(w, "    <{}>", name)?;
+            }
+        }
+        writeln!(w, "{}", header("Flags:"))?;
+
+        let flag_help = self.flags.short_help();
+        let mut contexts = flag_help.contexts();
+        if self.help_style.sort_flags {
+            contexts.sort_by_key(|ctx| ctx.long_name());
+        }
+
+        for (idx, ctx) in contexts.iter().enumerate() {
+            if idx > 0 {
+                writeln!(w)?;
+            }
+            write!(w, "{}", ctx)?;
+        }
+
+        let env_contexts = contexts
+            .iter()
+            .filter_map(|ctx| ctx.env_var.map(|var| (var, ctx.description)))
+            .collect::<Vec<_>>();
+        if !env_contexts.is_empty() {
+            write!(w, "\n{}", header("Environment:"))?;
+            for (var, description) in env_contexts {
+                write!(w, "\n    {:<16} {}", var, description)?;
+            }
+        }
+
+        let after_help = self.rendered_after_help();
+        if !after_help.is_empty() {
+            write!(w, "\n\n{}", after_help)?;
+        }
+        Ok(())
+    }
+
+    /// Renders help to the writer in the layout of Python's argparse.
+    fn render_argparse<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        let mut positionals = self.positionals.clone();
+        positionals.sort_by_key(|(idx, _)| *idx);
+
+        let flag_help = self.flags.short_help();
+        let mut contexts = flag_help.contexts();
+        if self.help_style.sort_flags {
+            contexts.sort_by_key(|ctx| ctx.long_name());
+        }
+
+        write!(w, "usage: {}", self.name)?;
+        for ctx in contexts.iter() {
+            write!(w, " {}", ctx.argparse_usage())?;
+        }
+        for (_, name) in positionals.iter() {
+            write!(w, " {}", name)?;
+        }
+        writeln!(w)?;
+
+        if !self.description.is_empty() {
+            write!(w, "\n{}\n", self.description)?;
+        }
+
+        if !positionals.is_empty() {
+            write!(w, "\npositional arguments:")?;
+            for (_, name) in positionals.iter() {
+                write!(w, "\n  {}", name)?;
+            }
+            writeln!(w)?;
+        }
+
+        write!(w, "\noptions:")?;
+        for ctx in contexts.iter() {
+            let mut help = ctx.description.to_string();
+            for modifier in ctx.modifiers.iter() {
+                help.push_str(&format!(" ({})", modifier));
+            }
+
+            // argparse wraps long invocations, placing their help on the
+            // following line.
+            let invocation = ctx.argparse_invocation();
+            if invocation.len() > 20 {
+                write!(w, "\n  {}\n{:<24}{}", invocation, "", help)?;
+            } else {
+                write!(w, "\n  {:<22}{}", invocation, help)?;
+            }
+        }
+
+        let env_contexts = contexts
+            .iter()
+            .filter_map(|ctx| ctx.env_var.map(|var| (var, ctx.description)))
+            .collect::<Vec<_>>();
+        if !env_contexts.is_empty() {
+            write!(w, "\n\nenvironment variables:")?;
+            for (var, description) in env_contexts {
+                write!(w, "\n  {:<22}{}", var, description)?;
+            }
+        }
+
+        let after_help = self.rendered_after_help();
+        if !after_help.is_empty() {
+            write!(w, "\n\n{}", after_help)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the help for a command, colored when stdout is a terminal and
+    /// the `NO_COLOR` environment variable is unset, and plain otherwise. An
+    /// explicit `HelpStyle::color` setting takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let help = Cmd::new("test")
+    ///     .help_style(HelpStyle::new().color(false))
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .help_auto();
+    ///
+    /// assert!(!help.contains('\x1b'));
+    /// ```
+    pub fn help_auto(&self) -> String {
+        let color = self
+            .help_style
+            .color
+            .unwrap_or_else(|| HelpStream::Stdout.supports_color());
+
+        let mut buf = Vec::new();
+        // writes to a Vec are infallible and only ever contain valid utf-8.
+        let _ = self.render_styled(&mut buf, color);
+        String::from_utf8(buf).unwrap_or_default()
+    }
+
+    /// Returns true if the input contains an explicit `--help` or `-h` flag
+    /// that isn't claimed by one of the command's own flags.
+    fn help_requested(&self, input: &[&str]) -> bool {
+        let flag_help = self.flags.short_help();
+        let defined = flag_help.contexts();
+
+        input.iter().skip(1).any(|&arg| {
+            (arg == "--help" && !defined.iter().any(|fhc| fhc.long_name() == "help"))
+                || (arg == "-h" && !defined.iter().any(|fhc| fhc.short_code == "h"))
+        })
+    }
+
+    /// Reports, for each of the command's flags, whether its long or short
+    /// form is present in the input, without evaluating any values. As no
+    /// values are parsed, no evaluator side effects, such as the file-system
+    /// checks of `FileValue` or `WithOpen`, take place, making this suitable
+    /// for introspection like completion or linting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = Cmd::new("test")
+    ///     .with_flag(Flag::expect_u8("count", "c", "A count."))
+    ///     .with_flag(Flag::store_true("verbose", "v", "Enable verbose output."))
+    ///     .with_handler(|_| {});
+    ///
+    /// assert_eq!(
+    ///     vec![("count".to_string(), true), ("verbose".to_string(), false)],
+    ///     cmd.dry_run(&["test", "--count", "not-a-number"][..])
+    /// );
+    /// ```
+    pub fn dry_run(&self, input: &[&str]) -> Vec<(String, bool)> {
+        self.flags
+            .short_help()
+            .contexts()
+            .into_iter()
+            .map(|ctx| {
+                let present = input.iter().skip(1).any(|&arg| ctx.matches(arg));
+                (ctx.long_name(), present)
+            })
+            .collect()
+    }
+
+    /// Evaluates and dispatches the command over the arguments of the passed
+    /// `ArgSource`, returning the handler's result or the evaluation error,
+    /// without writing anything. This is an alternative to, rather than the
+    /// basis of, the `run` helpers: a `--help` or `-h` flag receives no
+    /// special treatment and no help is rendered on error, leaving callers to
+    /// present errors and help themselves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = || Cmd::new("test")
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_handler(|name| name);
+    ///
+    /// assert_eq!(Ok("foo".to_string()), cmd().try_run(&["test", "-n", "foo"][..]));
+    /// assert_eq!(
+    ///     Err(CliError::FlagEvaluation("name".to_string())),
+    ///     cmd().try_run(&["test"][..])
+    /// );
+    /// ```
+    pub fn try_run<S, B, R>(self, source: S) -> Result<R, CliError>
+    where
+        S: ArgSource,
+        Self: for<'a> Evaluatable<'a, &'a [&'a str], B> + for<'a> Dispatchable<&'a [&'a str], B, R>,
+    {
+        let args = source.args();
+        let input = args.iter().map(String::as_str).collect::<Vec<&str>>();
+
+        self.evaluate(&input[..])
+            .map(|flag_values| self.dispatch(flag_values))
+    }
+
+    /// Evaluates and dispatches the command over the arguments of the passed
+    /// `ArgSource`, writing any generated help to the passed writers.
+    /// Explicitly requested help, via a `--help` or `-h` flag that the command
+    /// doesn't define itself, is written to `out` by default while help
+    /// generated from an evaluation error is written, along with the error, to
+    /// `err`. Help only accompanies errors of `ErrorKind::Usage`, other errors
+    /// are written alone. Returns `Ok(None)` when help was requested and no
+    /// handler was dispatched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = || Cmd::new("test")
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_handler(|name| name);
+    ///
+    /// let (mut out, mut err) = (Vec::new(), Vec::new());
+    /// assert_eq!(
+    ///     Ok(Some("foo".to_string())),
+    ///     cmd().run_to(&["test", "-n", "foo"][..], &mut out, &mut err)
+    /// );
+    /// assert!(out.is_empty() && err.is_empty());
+    /// ```
+    pub fn run_to<S, B, R, O, E>(
+        self,
+        source: S,
+        out: &mut O,
+        err: &mut E,
+    ) -> Result<Option<R>, CliError>
+    where
+        S: ArgSource,
+        Self: for<'a> Evaluatable<'a, &'a [&'a str], B> + for<'a> Dispatchable<&'a [&'a str], B, R>,
+        O: std::io::Write,
+        E: std::io::Write,
+    {
+        let args = source.args();
+        let input = args.iter().map(String::as_str).collect::<Vec<&str>>();
+        let color = self.help_style.color.unwrap_or(false);
+
+        self.run_styled(&input[..], out, err, move |_| color)
+    }
+
+    /// Evaluates and dispatches the command as described by `run_to`, with
+    /// the color of any generated help determined per stream.
+    fn run_styled<'a, B, R, O, E, C>(
+        self,
+        input: &'a [&'a str],
+        out: &mut O,
+        err: &mut E,
+        color: C,
+    ) -> Result<Option<R>, CliError>
+    where
+        Self: Evaluatable<'a, &'a [&'a str], B> + Dispatchable<&'a [&'a str], B, R>,
+        O: std::io::Write,
+        E: std::io::Write,
+        C: Fn(HelpStream) -> bool,
+    {
+        if self.help_requested(input) {
+            let mut w: &mut dyn std::io::Write = match self.run_options.requested_help {
+                HelpStream::Stdout => out,
+                HelpStream::Stderr => err,
+            };
+            let color = color(self.run_options.requested_help);
+            let _ = self.render_styled(&mut w, color).and_then(|_| writeln!(w));
+            return Ok(None);
+        }
+
+        match self.evaluate(input) {
+            Ok(flag_values) => Ok(Some(self.dispatch(flag_values))),
+            Err(e) if self.run_options.quiet_errors => Err(e),
+            Err(e) => {
+                let mut w: &mut dyn std::io::Write = match self.run_options.error_help {
+                    HelpStream::Stdout => out,
+                    HelpStream::Stderr => err,
+                };
+                let color = color(self.run_options.error_help);
+                // usage is only shown for errors in how the command was invoked.
+                let _ = match e.kind() {
+                    ErrorKind::Usage => writeln!(w, "{}\n", e)
+                        .and_then(|_| self.render_styled(&mut w, color))
+                        .and_then(|_| writeln!(w)),
+                    ErrorKind::Value | ErrorKind::Io => writeln!(w, "{}", e),
+                };
+                Err(e)
+            }
+        }
+    }
+
+    /// Evaluates and dispatches the command over the arguments of the passed
+    /// `ArgSource`, such as `EnvArgs` for those of the current process,
+    /// writing any generated help to stdout or stderr as described in
+    /// `run_to`. Help is colored when the stream it's written to is a terminal
+    /// and `NO_COLOR` is unset, unless color is set explicitly by
+    /// `HelpStyle::color`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Some("foo".to_string())),
+    ///     Cmd::new("test")
+    ///         .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///         .with_handler(|name| name)
+    ///         .run(&["test", "-n", "foo"][..])
+    /// );
+    /// ```
+    pub fn run<S, B, R>(self, source: S) -> Result<Option<R>, CliError>
+    where
+        S: ArgSource,
+        Self: for<'a> Evaluatable<'a, &'a [&'a str], B> + for<'a> Dispatchable<&'a [&'a str], B, R>,
+    {
+        let args = source.args();
+        let input = args.iter().map(String::as_str).collect::<Vec<&str>>();
+        let forced = self.help_style.color;
+
+        self.run_styled(
+            &input[..],
+            &mut std::io::stdout(),
+            &mut std::io::stderr(),
+            move |stream| forced.unwrap_or_else(|| stream.supports_color()),
+        )
+    }
+
+    /// Evaluates and dispatches the command like `run`, returning the
+    /// handler's result. The process exits with a status of `0` when help is
+    /// explicitly requested and `2` on an evaluation error.
+    pub fn run_exit<S, B, R>(self, source: S) -> R
+    where
+        S: ArgSource,
+        Self: for<'a> Evaluatable<'a, &'a [&'a str], B> + for<'a> Dispatchable<&'a [&'a str], B, R>,
+    {
+        match self.run(source) {
+            Ok(Some(r)) => r,
+            Ok(None) => std::process::exit(0),
+            Err(_) => std::process::exit(2),
+        }
+    }
+
+    /// Evaluates the command, returning a ParseReport summarizing the
+    /// evaluated value and its span alongside the names of the flags matched
+    /// and any arguments left unused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let cmd = Cmd::new("test")
+    ///     .with_flag(Flag::expect_string("name", "n", "A name."))
+    ///     .with_handler(|_| {});
+    ///
+    /// let report = cmd.evaluate_report(&["test", "-n", "foo", "--extra", "bar"][..]).unwrap();
+    ///
+    /// assert_eq!("foo".to_string(), report.value);
+    /// assert_eq!(Span::from_range(0..3), report.span);
+    /// assert_eq!(vec!["name".to_string()], report.matched_flags);
+    /// assert_eq!(vec![(3, "--extra".to_string())], report.unused_flags);
+    /// assert_eq!(vec![(4, "bar".to_string())], report.unused_positionals);
+    /// ```
+    pub fn evaluate_report<'a, B>(&self, input: &'a [&'a str]) -> Result<ParseReport<B>, CliError>
+    where
+        Self: Evaluatable<'a, &'a [&'a str], B>,
+    {
+        let Value { span, value } = self.evaluate(input)?;
+
+        // the command name is skipped, offsetting occurrences by one.
+        let flag_help = self.flags.short_help();
+        let matched_flags = flag_help
+            .contexts()
+            .into_iter()
+            .zip(flag_help.occurrences(&input[1..]))
+            .filter(|(_, occurrences)| occurrences.iter().any(|idx| span.0.contains(&(idx + 1))))
+            .map(|(ctx, _)| ctx.long_name())
+            .collect();
+
+        let (unused_flags, unused_positionals) = return_unused_args_with_positions(input, &span)
+            .into_iter()
+            .partition(|(_, arg)| !self.is_positional(arg));
+
+        Ok(ParseReport {
+            value,
+            span,
+            matched_flags,
+            unused_flags,
+            unused_positionals,
+        })
+    }
+}
+
+/// ParseReport summarizes a command's evaluation, as returned by
+/// `Cmd::evaluate_report`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseReport<B> {
+    /// The evaluated value.
+    pub value: B,
+    /// The span of arguments consumed by evaluation.
+    pub span: Span,
+    /// The names of the command's flags present in the input.
+    pub matched_flags: Vec<String>,
+    /// Unused flag-like arguments, paired with their index in the input.
+    pub unused_flags: Vec<(usize, String)>,
+    /// Unused non-flag arguments, paired with their index in the input.
+    pub unused_positionals: Vec<(usize, String)>,
+}
+
+impl<'a, T, H, A, B, R> Dispatchable<A, B, R> for Cmd<T, H>
+where
+    T: Evaluatable<'a, A, B>,
+    H: Fn(B) -> R,
+{
+    fn dispatch(self, flag_values: Value<B>) -> R {
+        let inner = flag_values.unwrap();
+        (self.handler)(inner)
+    }
+}
+
+impl<'a, T, H, A, B, R> DispatchableWithArgs<A, B, R> for Cmd<T, H>
+where
+    T: Evaluatable<'a, A, B>,
+    H: Fn(StringArgs, B) -> R,
+{
+    fn dispatch_with_args(self, args: StringArgs, flag_values: Value<B>) -> R {
+        let inner = flag_values.unwrap();
+        (self.handler)(args, inner)
+    }
+}
+
+impl<'a, T, H, A, B, R> DispatchableWithRawArgs<A, B, R> for Cmd<T, H>
+where
+    T: Evaluatable<'a, A, B>,
+    H: Fn(B, &[&str]) -> R,
+{
+    fn dispatch_with_raw_args(self, raw_args: &[&str], flag_values: Value<B>) -> R {
+        let inner = flag_values.unwrap();
+        (self.handler)(inner, raw_args)
+    }
+}
+
+impl<'a, T, H, A, B, R> DispatchableOnce<A, B, R> for Cmd<T, H>
+where
+    T: Evaluatable<'a, A, B>,
+    H: FnOnce(B) -> R,
+{
+    fn dispatch_once(self, flag_values: Value<B>) -> R {
+        let inner = flag_values.unwrap();
+        (self.handler)(inner)
+    }
+}
+
+impl<'a, A, T, H, B, R> DispatchableWithHelpString<A, B, R> for Cmd<T, H>
+where
+    Self: Helpable<Output = String>,
+    T: Evaluatable<'a, A, B>,
+    H: Fn(String, B) -> R,
+{
+    fn dispatch_with_helpstring(self, flag_values: Value<B>) -> R {
+        let inner = flag_values.unwrap();
+        let help_string = self.help();
+        (self.handler)(help_string, inner)
+    }
+
+    fn dispatch_with_supplied_helpstring(self, help_string: String, flag_values: Value<B>) -> R {
+        let inner = flag_values.unwrap();
+        (self.handler)(help_string, inner)
+    }
+}
+
+impl<'a, A, T, H, B, R> DispatchableWithHelpStringAndArgs<A, B, R> for Cmd<T, H>
+where
+    Self: Helpable<Output = String>,
+    T: Evaluatable<'a, A, B>,
+    H: Fn(String, StringArgs, B) -> R,
+{
     fn dispatch_with_helpstring_and_args(self, args: StringArgs, flag_values: Value<B>) -> R {
         let inner = flag_values.unwrap();
         let help_string = self.help();
@@ -1018,6 +3406,18 @@ pub trait DispatchableWithArgs<A, B, R> {
     fn dispatch_with_args(self, args: StringArgs, flag_values: Value<B>) -> R;
 }
 
+/// Defines behaviors for types that can dispatch an evaluator to a function
+/// with the complete, unparsed, original arguments.
+pub trait DispatchableWithRawArgs<A, B, R> {
+    fn dispatch_with_raw_args(self, raw_args: &[&str], flag_values: Value<B>) -> R;
+}
+
+/// Defines behaviors for types that can dispatch an evaluator to a
+/// single-use function.
+pub trait DispatchableOnce<A, B, R> {
+    fn dispatch_once(self, flag_values: Value<B>) -> R;
+}
+
 /// Defines behaviors for types that can dispatch an evaluator to a function
 /// with additional help documentation.
 pub trait DispatchableWithHelpString<A, B, R> {
@@ -1059,6 +3459,13 @@ where
     type Output;
 
     fn help(&self) -> Self::Output;
+
+    /// Writes the helpstring directly to the passed writer. Implementors that
+    /// can stream their help output may override this to avoid allocating an
+    /// intermediate helpstring.
+    fn render_to<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        write!(w, "{}", self.help())
+    }
 }
 
 /// A marker trait to denote flag-like objects from terminal objects.
@@ -1155,6 +3562,28 @@ impl Flag {
         FlagWithValue::new(name, short_code, description, ValueOnMatch::new(false))
     }
 
+    /// Provides a convenient helper for generating a CountValue flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), 2)),
+    ///     Flag::count("verbose", "v", "Raise the verbosity.")
+    ///         .evaluate(&["test", "-v", "-v"][..])
+    /// );
+    /// ```
+    pub fn count(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> CountValue {
+        CountValue::new(name, short_code, description)
+    }
+
     /// Provides a convenient helper for generating an ExpectI8Value flag.
     ///
     /// # Examples
@@ -1379,7 +3808,7 @@ impl Flag {
         FlagWithValue::new(name, short_code, description, U64Value)
     }
 
-    /// Provides a convenient wrapper for generating `WithChoices` flags.
+    /// Provides a convenient helper for generating an I128Value flag.
     ///
     /// # Examples
     ///
@@ -1388,130 +3817,165 @@ impl Flag {
     /// use scrap::*;
     ///
     /// assert_eq!(
-    ///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
-    ///     Flag::with_choices("log-level", "l", "A log level.", ["info".to_string(), "warn".to_string()], StringValue)
-    ///         .evaluate(&["hello", "-l", "info"][..])
-    /// );
-    ///
-    /// assert_eq!(
-    ///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
-    ///     WithChoices::new(
-    ///         ["info".to_string(), "warn".to_string()],
-    ///         FlagWithValue::new("log-level", "l", "A log level.", StringValue)
-    ///     ).evaluate(&["hello", "-l", "info"][..])
+    ///     Ok(Value::new(Span::from_range(1..3), -18446744073709551616)),
+    ///     Flag::expect_i128("offset", "o", "An offset.")
+    ///         .evaluate(&["test", "-o", "-18446744073709551616"][..])
     /// );
     /// ```
-    pub fn with_choices<B, E, const N: usize>(
+    pub fn expect_i128(
         name: &'static str,
         short_code: &'static str,
         description: &'static str,
-        choices: [B; N],
-        evaluator: E,
-    ) -> WithChoices<B, FlagWithValue<E>, N> {
-        WithChoices::new(
-            choices,
-            FlagWithValue::new(name, short_code, description, evaluator),
-        )
+    ) -> FlagWithValue<I128Value> {
+        FlagWithValue::new(name, short_code, description, I128Value)
     }
-}
-
-/// FlagHelpCollector provides a helper enum for collecting flag help strings
-/// that are either derived from a single flag or joined flags.
-pub enum FlagHelpCollector {
-    Single(FlagHelpContext),
-    Joined(Box<Self>, Box<Self>),
-}
 
-impl Default for FlagHelpCollector {
-    fn default() -> Self {
-        Self::Single(FlagHelpContext::default())
+    /// Provides a convenient helper for generating an U128Value flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), 18446744073709551616)),
+    ///     Flag::expect_u128("id", "i", "An id.")
+    ///         .evaluate(&["test", "-i", "18446744073709551616"][..])
+    /// );
+    /// ```
+    pub fn expect_u128(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<U128Value> {
+        FlagWithValue::new(name, short_code, description, U128Value)
     }
-}
 
-impl std::fmt::Display for FlagHelpCollector {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            FlagHelpCollector::Single(fhc) => write!(f, "{}", fhc),
-            FlagHelpCollector::Joined(lfhc, rfhc) => write!(f, "{}\n{}", lfhc, rfhc),
-        }
+    /// Provides a convenient helper for generating a NonZeroU32Value flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), std::num::NonZeroU32::new(4).unwrap())),
+    ///     Flag::expect_nonzero_u32("workers", "w", "A worker count.")
+    ///         .evaluate(&["test", "-w", "4"][..])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Err(CliError::InvalidValue {
+    ///         flag: "workers".to_string(),
+    ///         value: "0".to_string(),
+    ///         message: None,
+    ///     }),
+    ///     Flag::expect_nonzero_u32("workers", "w", "A worker count.")
+    ///         .evaluate(&["test", "-w", "0"][..])
+    /// );
+    /// ```
+    pub fn expect_nonzero_u32(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<NonZeroU32Value> {
+        FlagWithValue::new(name, short_code, description, NonZeroU32Value)
     }
-}
 
-/// FlagHelpContext provides a type to store flag data that may be modified
-/// through the course of generating a help string.
-#[derive(Default)]
-pub struct FlagHelpContext {
-    name: &'static str,
-    short_code: &'static str,
-    description: &'static str,
-    /// Additional String values to be appended after the description.
-    modifiers: Vec<String>,
-}
-
-impl FlagHelpContext {
-    /// Instantiates a new instance of FlagHelpContext.
-    pub fn new(
+    /// Provides a convenient helper for generating a u32 flag interpreted in
+    /// a fixed base, for values carrying no base prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), 0o644)),
+    ///     Flag::expect_u32_radix("mode", "m", "A file mode.", 8)
+    ///         .evaluate(&["test", "-m", "644"][..])
+    /// );
+    /// ```
+    pub fn expect_u32_radix(
         name: &'static str,
         short_code: &'static str,
         description: &'static str,
-        modifiers: Vec<String>,
-    ) -> Self {
-        Self {
-            name,
-            short_code,
-            description,
-            modifiers,
-        }
+        radix: u32,
+    ) -> FlagWithValue<RadixValue<u32>> {
+        FlagWithValue::new(name, short_code, description, RadixValue::new(radix))
     }
 
-    /// with_modifier returns an instances of FlagHelpContext with a provided
-    /// modifier appended to the end of the modifiers vector.
-    pub fn with_modifier(mut self, modifier: String) -> Self {
-        self.modifiers.push(modifier);
-        self
+    /// Provides a convenient helper for generating a u32 flag interpreted in
+    /// the base denoted by a `0x`, `0o` or `0b` prefix, or base 10 otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), 0b1010)),
+    ///     Flag::expect_u32_prefixed("mask", "m", "A mask.")
+    ///         .evaluate(&["test", "-m", "0b1010"][..])
+    /// );
+    /// ```
+    pub fn expect_u32_prefixed(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<RadixValue<u32>> {
+        FlagWithValue::new(name, short_code, description, RadixValue::prefixed())
     }
-}
 
-impl std::fmt::Display for FlagHelpContext {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.modifiers.is_empty() {
-            write!(
-                f,
-                "    {:<16} {:<40}",
-                format!("--{}, -{}", self.name, self.short_code),
-                self.description,
-            )
-        } else {
-            write!(
-                f,
-                "    {:<16} {:<40} [{}]",
-                format!("--{}, -{}", self.name, self.short_code),
-                self.description,
-                self.modifiers
-                    .iter()
-                    .map(|modifier| format!("({})", modifier))
-                    .collect::<Vec<String>>()
-                    .join(", ")
-            )
-        }
+    /// Provides a convenient helper for generating an F32Value flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), 0.75)),
+    ///     Flag::expect_f32("threshold", "t", "A threshold.")
+    ///         .evaluate(&["test", "-t", "0.75"][..])
+    /// );
+    /// ```
+    pub fn expect_f32(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<F32Value> {
+        FlagWithValue::new(name, short_code, description, F32Value)
     }
-}
-
-/// Represents a vector of spanning arguments.
-pub type StringArgs = Vec<Value<String>>;
-
-use core::ops::Range;
-
-/// Span provides tracking of matched positions in an argument array.
-#[derive(Debug, Default, PartialEq, Clone)]
-pub struct Span(Vec<usize>);
 
-impl Span {
-    pub fn new(matches: Vec<usize>) -> Self {
-        Self(matches)
+    /// Provides a convenient helper for generating an F64Value flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), 0.75)),
+    ///     Flag::expect_f64("threshold", "t", "A threshold.")
+    ///         .evaluate(&["test", "-t", "0.75"][..])
+    /// );
+    /// ```
+    pub fn expect_f64(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<F64Value> {
+        FlagWithValue::new(name, short_code, description, F64Value)
     }
 
-    /// Returns an empty span.
+    /// Provides a convenient helper for generating a BoolValue flag.
     ///
     /// # Examples
     ///
@@ -1519,13 +3983,21 @@ impl Span {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// assert_eq!(Span::new(vec![]), Span::empty());
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), true)),
+    ///     Flag::expect_bool("enabled", "e", "Enable it.")
+    ///         .evaluate(&["test", "-e", "yes"][..])
+    /// );
     /// ```
-    pub const fn empty() -> Self {
-        Span(vec![])
+    pub fn expect_bool(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<BoolValue> {
+        FlagWithValue::new(name, short_code, description, BoolValue)
     }
 
-    /// Generates a Span from a given range.
+    /// Provides a convenient helper for generating a RangeListValue flag.
     ///
     /// # Examples
     ///
@@ -1533,55 +4005,50 @@ impl Span {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// assert_eq!(Span::new(vec![0, 1, 2]), Span::from_range(0..3));
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), vec![1, 2, 3, 5])),
+    ///     Flag::expect_range_list("pages", "p", "pages to print.")
+    ///         .evaluate(&["test", "-p", "1-3,5"][..])
+    /// );
     /// ```
-    pub fn from_range(range: Range<usize>) -> Self {
-        Self::from(range)
+    pub fn expect_range_list(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<RangeListValue> {
+        FlagWithValue::new(name, short_code, description, RangeListValue)
     }
 
-    /// Joins two Spans together.
+    /// Provides a convenient helper for generating a MapValue flag, with each
+    /// value in the map evaluated by the provided value type.
     ///
     /// # Examples
     ///
     /// ```
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
+    /// use std::collections::HashMap;
     ///
-    /// let span_1 = Span::from_range(0..2);
-    /// let span_2 = Span::from_range(2..4);
+    /// let expected: HashMap<String, String> = vec![("env".to_string(), "prod".to_string())]
+    ///     .into_iter()
+    ///     .collect();
     ///
-    /// assert_eq!(Span::new(vec![0, 1, 2, 3]), span_1.join(span_2));
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), expected)),
+    ///     Flag::expect_map("labels", "l", "labels to apply.", StringValue)
+    ///         .evaluate(&["test", "-l", "env=prod"][..])
+    /// );
     /// ```
-    pub fn join(mut self, other: Span) -> Self {
-        for v in other.0 {
-            self.0.push(v)
-        }
-
-        self
-    }
-}
-
-impl From<Range<usize>> for Span {
-    fn from(src: Range<usize>) -> Self {
-        let range = src.collect();
-        Self(range)
-    }
-}
-
-/// Value wraps a matched parse, containing contextual data, like it's
-/// argument position.
-#[derive(Debug, PartialEq, Clone)]
-pub struct Value<T> {
-    pub span: Span,
-    pub value: T,
-}
-
-impl<T> Value<T> {
-    pub fn new(span: Span, value: T) -> Self {
-        Self { span, value }
+    pub fn expect_map<V>(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+        value: V,
+    ) -> FlagWithValue<MapValue<V>> {
+        FlagWithValue::new(name, short_code, description, MapValue::new(value))
     }
 
-    /// Adjusts the spans of a given value to align with an offset.
+    /// Provides a convenient helper for generating a JsonValueEvaluator flag.
     ///
     /// # Examples
     ///
@@ -1589,36 +4056,44 @@ impl<T> Value<T> {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// let base = Value::new(Span::from_range(0..1), ());
-    /// let adjusted = base.from_offset(2);
-    ///
-    /// assert_eq!(Value::new(Span::from_range(2..3), ()), adjusted);
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), JsonValue::Bool(true))),
+    ///     Flag::expect_json("meta", "m", "Inline metadata.")
+    ///         .evaluate(&["test", "-m", "true"][..])
+    /// );
     /// ```
-    pub fn from_offset(self, offset: usize) -> Self {
-        let adjusted_span_inner = self.span.0.iter().map(|v| *v + offset).collect();
-        let span = Span(adjusted_span_inner);
-
-        Self {
-            span,
-            value: self.value,
-        }
+    pub fn expect_json(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<JsonValueEvaluator> {
+        FlagWithValue::new(name, short_code, description, JsonValueEvaluator)
     }
 
-    /// Unwraps the enclosed inner value from the Value type.
+    /// Provides a convenient helper for generating a TimestampValue flag.
     ///
     /// # Examples
     ///
     /// ```
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
+    /// use std::time::UNIX_EPOCH;
     ///
-    /// assert_eq!((), Value::new(Span::empty(), ()).unwrap());
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), UNIX_EPOCH)),
+    ///     Flag::expect_timestamp("since", "s", "A start time.")
+    ///         .evaluate(&["test", "-s", "1970-01-01"][..])
+    /// );
     /// ```
-    pub fn unwrap(self) -> T {
-        self.value
+    pub fn expect_timestamp(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<TimestampValue> {
+        FlagWithValue::new(name, short_code, description, TimestampValue)
     }
 
-    /// Allows the mapping of the enclosed value to a new value.
+    /// Provides a convenient helper for generating a DurationValue flag.
     ///
     /// # Examples
     ///
@@ -1626,99 +4101,4693 @@ impl<T> Value<T> {
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// let base = Value::new(Span::empty(), 1);
-    /// let adjusted = base.map(|inner| inner + 1);
-    ///
-    /// assert_eq!(2, adjusted.unwrap());
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), std::time::Duration::from_secs(30))),
+    ///     Flag::expect_duration("timeout", "t", "A timeout.")
+    ///         .evaluate(&["test", "-t", "30s"][..])
+    /// );
     /// ```
-    pub fn map<V, F>(self, map_fn: F) -> Value<V>
-    where
-        F: FnOnce(T) -> V,
-    {
-        let (span, value) = (self.span, self.value);
-        Value::new(span, map_fn(value))
+    pub fn expect_duration(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<DurationValue> {
+        FlagWithValue::new(name, short_code, description, DurationValue)
     }
-}
 
-/// Represents the result of an Evaluatable::evaluate call signifying whether
+    /// Provides a convenient helper for generating a ByteSizeValue flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), 1000)),
+    ///     Flag::expect_byte_size("max-size", "m", "A maximum size.")
+    ///         .evaluate(&["test", "-m", "1k"][..])
+    /// );
+    /// ```
+    pub fn expect_byte_size(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<ByteSizeValue> {
+        FlagWithValue::new(name, short_code, description, ByteSizeValue)
+    }
+
+    /// Provides a convenient helper for generating a PathBufValue flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), std::path::PathBuf::from("out.txt"))),
+    ///     Flag::expect_path("out", "o", "An output path.")
+    ///         .evaluate(&["test", "-o", "out.txt"][..])
+    /// );
+    /// ```
+    pub fn expect_path(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<PathBufValue> {
+        FlagWithValue::new(name, short_code, description, PathBufValue)
+    }
+
+    /// Provides a convenient helper for generating an IpAddrValue flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), "::1".parse::<std::net::IpAddr>().unwrap())),
+    ///     Flag::expect_ip("bind", "b", "An address to bind.")
+    ///         .evaluate(&["test", "-b", "::1"][..])
+    /// );
+    /// ```
+    pub fn expect_ip(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<IpAddrValue> {
+        FlagWithValue::new(name, short_code, description, IpAddrValue)
+    }
+
+    /// Provides a convenient helper for generating a SocketAddrValue flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), "[::1]:443".parse::<std::net::SocketAddr>().unwrap())),
+    ///     Flag::expect_socket_addr("listen", "l", "An address to listen on.")
+    ///         .evaluate(&["test", "-l", "[::1]:443"][..])
+    /// );
+    /// ```
+    pub fn expect_socket_addr(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<SocketAddrValue> {
+        FlagWithValue::new(name, short_code, description, SocketAddrValue)
+    }
+
+    /// Provides a convenient helper for generating a flag accepting either an
+    /// integer or a string value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), IntOrString::Int(42))),
+    ///     Flag::expect_one_of_types("id", "i", "An id or name.")
+    ///         .evaluate(&["test", "-i", "42"][..])
+    /// );
+    /// ```
+    pub fn expect_one_of_types(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+    ) -> FlagWithValue<IntOrStringValue> {
+        FlagWithValue::new(name, short_code, description, IntOrStringValue)
+    }
+
+    /// Provides a convenient wrapper for generating `WithMultipleChoices`
+    /// flags, mapping a comma-delimited list of names to their values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum Feature {
+    ///     Fast,
+    ///     Safe,
+    /// }
+    ///
+    /// let flag = Flag::expect_multiple_choice_enum(
+    ///     "features",
+    ///     "f",
+    ///     "features to enable.",
+    ///     [("fast", Feature::Fast), ("safe", Feature::Safe)],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), vec![Feature::Safe, Feature::Fast])),
+    ///     flag.evaluate(&["hello", "-f", "safe,fast"][..])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "    --features, -f   features to enable.                      [(multiple choices: [\"fast\", \"safe\"])]",
+    ///     flag.short_help().to_string()
+    /// );
+    /// ```
+    pub fn expect_multiple_choice_enum<T, const N: usize>(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+        choices: [(&'static str, T); N],
+    ) -> WithMultipleChoices<T, FlagWithValue<StringValue>, N> {
+        WithMultipleChoices::new(
+            choices,
+            FlagWithValue::new(name, short_code, description, StringValue),
+        )
+    }
+
+    /// Provides a convenient wrapper for generating `WithChoices` flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
+    ///     Flag::with_choices("log-level", "l", "A log level.", ["info".to_string(), "warn".to_string()], StringValue)
+    ///         .evaluate(&["hello", "-l", "info"][..])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
+    ///     WithChoices::new(
+    ///         ["info".to_string(), "warn".to_string()],
+    ///         FlagWithValue::new("log-level", "l", "A log level.", StringValue)
+    ///     ).evaluate(&["hello", "-l", "info"][..])
+    /// );
+    /// ```
+    pub fn with_choices<B, E, const N: usize>(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+        choices: [B; N],
+        evaluator: E,
+    ) -> WithChoices<B, FlagWithValue<E>, N> {
+        WithChoices::new(
+            choices,
+            FlagWithValue::new(name, short_code, description, evaluator),
+        )
+    }
+}
+
+/// FlagSet captures a reusable set of flag definitions that can be shared
+/// between multiple commands. Because evaluators are consumed by the `Cmd`
+/// they are attached to, a FlagSet stores a constructor for the evaluator tree
+/// rather than the tree itself, building a fresh copy for each command.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let shared = FlagSet::new(|| {
+///     Flag::expect_string("config", "c", "A config path.")
+///         .optional()
+///         .with_default("/etc/test.conf".to_string())
+/// });
+///
+/// let left_cmd = Cmd::new("test_one").with_flag_set(&shared);
+/// let right_cmd = Cmd::new("test_two")
+///     .with_flag(Flag::store_true("debug", "d", "Run command in debug mode."))
+///     .with_flag_set(&shared);
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(0..3), "local.conf".to_string())),
+///     left_cmd.evaluate(&["test_one", "-c", "local.conf"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(0..2), (true, "/etc/test.conf".to_string()))),
+///     right_cmd.evaluate(&["test_two", "-d"][..])
+/// );
+/// ```
+pub struct FlagSet<C> {
+    constructor: C,
+}
+
+impl<C, E> FlagSet<C>
+where
+    C: Fn() -> E,
+{
+    /// Instantiates a new FlagSet from a constructor function that returns
+    /// the flag evaluator tree.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// FlagSet::new(|| Flag::store_true("debug", "d", "Run command in debug mode."));
+    /// ```
+    pub fn new(constructor: C) -> Self {
+        Self { constructor }
+    }
+
+    /// Builds a new instance of the flag evaluator tree.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let flag_set = FlagSet::new(|| Flag::store_true("debug", "d", "Run command in debug mode."));
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..2), true)),
+    ///     flag_set.build().evaluate(&["test", "-d"][..])
+    /// );
+    /// ```
+    pub fn build(&self) -> E {
+        (self.constructor)()
+    }
+}
+
+/// FlagHelpCollector provides a helper enum for collecting flag help strings
+/// that are either derived from a single flag or joined flags.
+pub enum FlagHelpCollector {
+    Single(FlagHelpContext),
+    Joined(Box<Self>, Box<Self>),
+}
+
+impl Default for FlagHelpCollector {
+    fn default() -> Self {
+        Self::Single(FlagHelpContext::default())
+    }
+}
+
+impl FlagHelpCollector {
+    /// Returns the help context of every flag in the collector, in order.
+    pub fn contexts(&self) -> Vec<&FlagHelpContext> {
+        match self {
+            FlagHelpCollector::Single(fhc) => vec![fhc],
+            FlagHelpCollector::Joined(lfhc, rfhc) => {
+                let mut contexts = lfhc.contexts();
+                contexts.extend(rfhc.contexts());
+                contexts
+            }
+        }
+    }
+
+    /// Returns whether any flag in the collector is passed in the input.
+    fn is_present(&self, input: &[&str]) -> bool {
+        self.contexts()
+            .iter()
+            .any(|ctx| input.iter().any(|&arg| ctx.matches(arg)))
+    }
+
+    /// Returns, for each of the described flags in the order of `contexts`,
+    /// the indices of the input at which the flag is passed. The argument
+    /// following a flag that takes a separate value is skipped, so a value
+    /// spelled like another flag isn't mistaken for one.
+    fn occurrences(&self, input: &[&str]) -> Vec<Vec<usize>> {
+        let contexts = self.contexts();
+        let mut occurrences = vec![Vec::new(); contexts.len()];
+
+        let mut idx = 0;
+        while idx < input.len() {
+            let arg = input[idx];
+            if let Some(pos) = contexts.iter().position(|ctx| ctx.matches_separate(arg)) {
+                occurrences[pos].push(idx);
+                if contexts[pos].takes_value {
+                    idx += 1;
+                }
+            } else if let Some(pos) = contexts
+                .iter()
+                .position(|ctx| ctx.inline_value(arg).is_some())
+            {
+                occurrences[pos].push(idx);
+            }
+            idx += 1;
+        }
+
+        occurrences
+    }
+}
+
+impl std::fmt::Display for FlagHelpCollector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlagHelpCollector::Single(fhc) => write!(f, "{}", fhc),
+            FlagHelpCollector::Joined(lfhc, rfhc) => write!(f, "{}\n{}", lfhc, rfhc),
+        }
+    }
+}
+
+/// FlagHelpContext provides a type to store flag data that may be modified
+/// through the course of generating a help string.
+#[derive(Default)]
+pub struct FlagHelpContext {
+    name: &'static str,
+    short_code: &'static str,
+    description: &'static str,
+    /// Additional String values to be appended after the description.
+    modifiers: Vec<String>,
+    /// The environment variable a flag's value may be read from.
+    env_var: Option<&'static str>,
+    /// The dash-terminated namespace prefixing the flag's long form.
+    prefix: String,
+    /// Whether the flag consumes a value, rather than being set by its
+    /// presence alone.
+    takes_value: bool,
+    /// Whether the flag is also passed as `+{short}`.
+    plus_form: bool,
+}
+
+impl FlagHelpContext {
+    /// Instantiates a new instance of FlagHelpContext.
+    pub fn new(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+        modifiers: Vec<String>,
+    ) -> Self {
+        Self {
+            name,
+            short_code,
+            description,
+            modifiers,
+            env_var: None,
+            prefix: String::new(),
+            takes_value: true,
+            plus_form: false,
+        }
+    }
+
+    /// without_value returns an instance of FlagHelpContext describing a
+    /// flag that is set by its presence alone, rather than consuming a value.
+    pub fn without_value(mut self) -> Self {
+        self.takes_value = false;
+        self
+    }
+
+    /// with_plus_form returns an instance of FlagHelpContext describing a
+    /// flag that may also be passed as `+{short}`, as with `PlusMinusFlag`.
+    pub fn with_plus_form(mut self) -> Self {
+        self.plus_form = true;
+        self
+    }
+
+    /// with_env_var returns an instance of FlagHelpContext recording the
+    /// environment variable the flag's value may be read from.
+    pub fn with_env_var(mut self, var: &'static str) -> Self {
+        self.env_var = Some(var);
+        self
+    }
+
+    /// with_modifier returns an instances of FlagHelpContext with a provided
+    /// modifier appended to the end of the modifiers vector. As each wrapper
+    /// appends its modifier while unwinding, modifiers render from the
+    /// innermost wrapper to the outermost.
+    pub fn with_modifier(mut self, modifier: String) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    /// with_prefix returns an instance of FlagHelpContext with its long form
+    /// namespaced by the provided prefix. As each wrapper prefixes while
+    /// unwinding, the outermost prefix renders first.
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = format!("{}-{}", prefix, self.prefix);
+        self
+    }
+
+    /// Returns the long form of the described flag, without leading dashes
+    /// but including any prefix, i.e. `db-host`. This is the name by which a
+    /// flag is reported and compared.
+    pub fn long_name(&self) -> String {
+        format!("{}{}", self.prefix, self.name)
+    }
+
+    /// Returns true if the passed argument matches either the long or short
+    /// form of the described flag, including with an inline `=value`,
+    /// following the rules of `FlagWithValue`.
+    fn matches(&self, arg: &str) -> bool {
+        self.matches_separate(arg) || self.inline_value(arg).is_some()
+    }
+
+    /// Returns true if the passed argument matches either the long or short
+    /// form of the described flag without an inline value, leaving any value
+    /// to the argument that follows.
+    fn matches_separate(&self, arg: &str) -> bool {
+        arg != "-"
+            && (arg
+                .strip_prefix("--")
+                .and_then(|long| long.strip_prefix(self.prefix.as_str()))
+                == Some(self.name)
+                || arg.strip_prefix('-') == Some(self.short_code)
+                || self.plus_form && arg.strip_prefix('+') == Some(self.short_code))
+    }
+
+    /// Returns the value of an argument passing the described flag in the
+    /// `--name=value` or `-n=value` form, split on the first `=`.
+    fn inline_value<'v>(&self, arg: &'v str) -> Option<&'v str> {
+        let (flag, value) = arg.split_once('=')?;
+
+        let long = flag
+            .strip_prefix("--")
+            .and_then(|long| long.strip_prefix(self.prefix.as_str()))
+            == Some(self.name);
+        let short = !self.short_code.is_empty() && flag.strip_prefix('-') == Some(self.short_code);
+        (long || short).then_some(value)
+    }
+}
+
+impl FlagHelpContext {
+    /// Returns the argparse-style invocation of a flag, i.e.
+    /// `-n NAME, --name NAME`, with the value placeholder omitted for flags
+    /// that don't take a value.
+    fn argparse_invocation(&self) -> String {
+        let long = format!("{}{}", self.prefix, self.name);
+        if self.takes_value {
+            let metavar = long.to_uppercase().replace('-', "_");
+            format!("-{} {}, --{} {}", self.short_code, metavar, long, metavar)
+        } else {
+            format!("-{}, --{}", self.short_code, long)
+        }
+    }
+
+    /// Returns the short form of a flag as rendered in an argparse-style
+    /// usage line, i.e. `[-n NAME]`.
+    fn argparse_usage(&self) -> String {
+        if self.takes_value {
+            let long = format!("{}{}", self.prefix, self.name);
+            format!(
+                "[-{} {}]",
+                self.short_code,
+                long.to_uppercase().replace('-', "_")
+            )
+        } else {
+            format!("[-{}]", self.short_code)
+        }
+    }
+}
+
+impl std::fmt::Display for FlagHelpContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.modifiers.is_empty() {
+            write!(
+                f,
+                "    {:<16} {:<40}",
+                format!("--{}{}, -{}", self.prefix, self.name, self.short_code),
+                self.description,
+            )
+        } else {
+            write!(
+                f,
+                "    {:<16} {:<40} [{}]",
+                format!("--{}{}, -{}", self.prefix, self.name, self.short_code),
+                self.description,
+                self.modifiers
+                    .iter()
+                    .map(|modifier| format!("({})", modifier))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        }
+    }
+}
+
+/// Represents a vector of spanning arguments.
+pub type StringArgs = Vec<Value<String>>;
+
+/// ArgSource provides a common interface for acquiring the raw arguments of a
+/// command from an arbitrary origin.
+pub trait ArgSource {
+    fn args(&self) -> Vec<String>;
+}
+
+impl<'a> ArgSource for &'a [&'a str] {
+    fn args(&self) -> Vec<String> {
+        self.iter().map(|arg| arg.to_string()).collect()
+    }
+}
+
+/// EnvArgs sources arguments from the current process via `std::env::args`.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(std::env::args().collect::<Vec<String>>(), EnvArgs.args());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EnvArgs;
+
+impl ArgSource for EnvArgs {
+    fn args(&self) -> Vec<String> {
+        std::env::args().collect()
+    }
+}
+
+/// ResponseFile sources arguments from a file containing one argument per
+/// line. Empty lines are ignored, all other lines are taken verbatim,
+/// preserving any whitespace within an argument.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let path = std::env::temp_dir().join(format!("scrap-response-{}", std::process::id()));
+/// std::fs::write(&path, "-n\nfoo bar\n\n-d\n").unwrap();
+///
+/// assert_eq!(
+///     vec!["-n".to_string(), "foo bar".to_string(), "-d".to_string()],
+///     ResponseFile::open(&path).unwrap().args()
+/// );
+///
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResponseFile {
+    args: Vec<String>,
+}
+
+impl ResponseFile {
+    /// Reads the arguments from the file at the passed path.
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        std::fs::read_to_string(path).map(|contents| Self {
+            args: contents
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| line.to_string())
+                .collect(),
+        })
+    }
+}
+
+impl ArgSource for ResponseFile {
+    fn args(&self) -> Vec<String> {
+        self.args.clone()
+    }
+}
+
+/// LayeredArgs composes two argument sources, returning the arguments of the
+/// first source followed by the arguments of the second.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let path = std::env::temp_dir().join(format!("scrap-layered-{}", std::process::id()));
+/// std::fs::write(&path, "-n\nfoo\n").unwrap();
+///
+/// let static_args = &["test", "-d"][..];
+/// let raw_args = LayeredArgs::new(static_args, ResponseFile::open(&path).unwrap()).args();
+/// let args = raw_args.iter().map(|a| a.as_str()).collect::<Vec<&str>>();
+///
+/// assert_eq!(vec!["test", "-d", "-n", "foo"], args);
+/// assert_eq!(
+///     Ok(Value::new(Span::new(vec![0, 1, 2, 3]), (true, "foo".to_string()))),
+///     Cmd::new("test")
+///         .with_flag(Flag::store_true("debug", "d", "Run command in debug mode."))
+///         .with_flag(Flag::expect_string("name", "n", "A name."))
+///         .evaluate(&args[..])
+/// );
+///
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct LayeredArgs<S1, S2> {
+    first: S1,
+    second: S2,
+}
+
+impl<S1, S2> LayeredArgs<S1, S2> {
+    /// Instantiates a new LayeredArgs from two argument sources.
+    pub fn new(first: S1, second: S2) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<S1, S2> ArgSource for LayeredArgs<S1, S2>
+where
+    S1: ArgSource,
+    S2: ArgSource,
+{
+    fn args(&self) -> Vec<String> {
+        let mut args = self.first.args();
+        args.extend(self.second.args());
+        args
+    }
+}
+
+use core::ops::Range;
+
+/// Span provides tracking of matched positions in an argument array.
+///
+/// Spans are ordered by their lowest matched position, with an empty span
+/// ordered first, then by the number of positions matched. Any remaining ties
+/// are broken by comparing the matched positions in order.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let mut spans = vec![
+///     Span::from_range(3..5),
+///     Span::from_range(1..3),
+///     Span::from_range(1..2),
+///     Span::empty(),
+/// ];
+/// spans.sort();
+///
+/// assert_eq!(
+///     vec![
+///         Span::empty(),
+///         Span::from_range(1..2),
+///         Span::from_range(1..3),
+///         Span::from_range(3..5),
+///     ],
+///     spans
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Span(Vec<usize>);
+
+impl PartialOrd for Span {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Span {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+            .iter()
+            .min()
+            .cmp(&other.0.iter().min())
+            .then_with(|| self.len().cmp(&other.len()))
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl Span {
+    pub fn new(matches: Vec<usize>) -> Self {
+        Self(matches)
+    }
+
+    /// Returns an empty span.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(Span::new(vec![]), Span::empty());
+    /// ```
+    pub const fn empty() -> Self {
+        Span(vec![])
+    }
+
+    /// Generates a Span from a given range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(Span::new(vec![0, 1, 2]), Span::from_range(0..3));
+    /// ```
+    pub fn from_range(range: Range<usize>) -> Self {
+        Self::from(range)
+    }
+
+    /// Joins two Spans together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let span_1 = Span::from_range(0..2);
+    /// let span_2 = Span::from_range(2..4);
+    ///
+    /// assert_eq!(Span::new(vec![0, 1, 2, 3]), span_1.join(span_2));
+    /// ```
+    pub fn join(mut self, other: Span) -> Self {
+        for v in other.0 {
+            self.0.push(v)
+        }
+
+        self
+    }
+
+    /// Returns the number of distinct argument positions matched by the Span.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(3, Span::from_range(0..2).join(Span::from_range(4..5)).len());
+    /// assert_eq!(0, Span::empty().len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0
+            .iter()
+            .collect::<std::collections::BTreeSet<_>>()
+            .len()
+    }
+
+    /// Returns true if the Span matches no argument positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert!(Span::empty().is_empty());
+    /// assert!(!Span::from_range(0..1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the fraction of `total` arguments matched by the Span. An
+    /// empty input of zero arguments is considered uncovered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(0.5, Span::from_range(0..2).coverage(4));
+    /// assert_eq!(0.0, Span::empty().coverage(0));
+    /// ```
+    pub fn coverage(&self, total: usize) -> f64 {
+        if total == 0 {
+            0.0
+        } else {
+            self.len() as f64 / total as f64
+        }
+    }
+
+    /// Returns a Span of the positions in this Span that aren't matched by
+    /// `other`, in their original order, such as the arguments a second
+    /// evaluation consumed beyond a first. As a Span tracks individual
+    /// positions rather than a single range, the difference of overlapping
+    /// ranges may be non-contiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Span::new(vec![0, 3]),
+    ///     Span::from_range(0..4).difference(&Span::from_range(1..3))
+    /// );
+    /// assert!(Span::from_range(1..3).difference(&Span::from_range(0..4)).is_empty());
+    /// ```
+    pub fn difference(&self, other: &Span) -> Span {
+        Span(
+            self.0
+                .iter()
+                .filter(|position| !other.0.contains(position))
+                .copied()
+                .collect(),
+        )
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(src: Range<usize>) -> Self {
+        let range = src.collect();
+        Self(range)
+    }
+}
+
+/// Formats a Span as a comma-separated list of the ranges of consecutive
+/// positions it covers, preserving the order positions were matched in.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!("0..3, 5..6", Span::new(vec![0, 1, 2, 5]).to_string());
+/// assert_eq!("", Span::empty().to_string());
+/// ```
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for &position in self.0.iter() {
+            match ranges.last_mut() {
+                Some(range) if range.end == position => range.end += 1,
+                _ => ranges.push(position..position + 1),
+            }
+        }
+
+        let formatted = ranges
+            .iter()
+            .map(|range| format!("{}..{}", range.start, range.end))
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(f, "{}", formatted)
+    }
+}
+
+/// Parses a Span from its `Display` form, failing with a
+/// `CliError::ValueEvaluation` on malformed or empty ranges.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(Ok(Span::new(vec![0, 1, 2, 5])), "0..3, 5..6".parse::<Span>());
+/// assert_eq!(Err(CliError::ValueEvaluation), "3..0".parse::<Span>());
+/// ```
+impl std::str::FromStr for Span {
+    type Err = CliError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Span::empty());
+        }
+
+        s.split(',')
+            .map(|range| {
+                let (start, end) = range.trim().split_once("..")?;
+                let (start, end) = (start.parse::<usize>().ok()?, end.parse::<usize>().ok()?);
+                (start < end).then(|| Span::from_range(start..end))
+            })
+            .try_fold(Span::empty(), |acc, span| span.map(|span| acc.join(span)))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
+
+/// Value wraps a matched parse, containing contextual data, like it's
+/// argument position.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Value<T> {
+    pub span: Span,
+    pub value: T,
+}
+
+impl<T> Value<T> {
+    pub fn new(span: Span, value: T) -> Self {
+        Self { span, value }
+    }
+
+    /// Instantiates a new Value spanning a contiguous range of positions.
+    /// Functionally this is an alias for
+    /// `Value::new(Span::from_range(range), value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Value::new(Span::from_range(1..3), "foo".to_string()),
+    ///     Value::spanned(1..3, "foo".to_string())
+    /// );
+    /// ```
+    pub fn spanned(range: Range<usize>, value: T) -> Self {
+        Self::new(Span::from_range(range), value)
+    }
+
+    /// Adjusts the spans of a given value to align with an offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let base = Value::new(Span::from_range(0..1), ());
+    /// let adjusted = base.from_offset(2);
+    ///
+    /// assert_eq!(Value::new(Span::from_range(2..3), ()), adjusted);
+    /// ```
+    pub fn from_offset(self, offset: usize) -> Self {
+        let adjusted_span_inner = self.span.0.iter().map(|v| *v + offset).collect();
+        let span = Span(adjusted_span_inner);
+
+        Self {
+            span,
+            value: self.value,
+        }
+    }
+
+    /// Unwraps the enclosed inner value from the Value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!((), Value::new(Span::empty(), ()).unwrap());
+    /// ```
+    pub fn unwrap(self) -> T {
+        self.value
+    }
+
+    /// Allows the mapping of the enclosed value to a new value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let base = Value::new(Span::empty(), 1);
+    /// let adjusted = base.map(|inner| inner + 1);
+    ///
+    /// assert_eq!(2, adjusted.unwrap());
+    /// ```
+    pub fn map<V, F>(self, map_fn: F) -> Value<V>
+    where
+        F: FnOnce(T) -> V,
+    {
+        let (span, value) = (self.span, self.value);
+        Value::new(span, map_fn(value))
+    }
+
+    /// Returns the Value unchanged if the enclosed value satisfies the
+    /// predicate, otherwise a `CliError::ValueEvaluation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let port = |input| {
+    ///     Flag::expect_u16("port", "p", "A port.")
+    ///         .evaluate(input)
+    ///         .and_then(|port| port.filter(|&port| (1024..=49151).contains(&port)))
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), 8080)),
+    ///     port(&["test", "-p", "8080"][..])
+    /// );
+    /// assert_eq!(Err(CliError::ValueEvaluation), port(&["test", "-p", "80"][..]));
+    /// ```
+    pub fn filter<F>(self, predicate: F) -> Result<Value<T>, CliError>
+    where
+        F: Fn(&T) -> bool,
+    {
+        if predicate(&self.value) {
+            Ok(self)
+        } else {
+            Err(CliError::ValueEvaluation)
+        }
+    }
+
+    /// Converts from `&Value<T>` to a `Value<&T>`, borrowing the enclosed
+    /// value alongside a copy of its span without consuming the original.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let value = Value::new(Span::from_range(1..3), "foo".to_string());
+    /// let borrowed: Value<&String> = value.as_ref();
+    ///
+    /// assert_eq!("foo", borrowed.value.as_str());
+    /// assert_eq!(Span::from_range(1..3), borrowed.span);
+    ///
+    /// // the original value remains usable.
+    /// assert_eq!("foo".to_string(), value.unwrap());
+    /// ```
+    pub fn as_ref(&self) -> Value<&T> {
+        Value::new(self.span.clone(), &self.value)
+    }
+}
+
+/// Represents the result of an Evaluatable::evaluate call signifying whether
 /// the call returned an error or correctly evaluated a flag to a type T.
 pub type EvaluateResult<'a, T> = Result<Value<T>, CliError>;
 
-/// A marker trait signifying that this implementation of Evaluatable is terminal.
-pub trait TerminalEvaluatable<'a, A, B>: Evaluatable<'a, A, B> {}
+/// A marker trait signifying that this implementation of Evaluatable is terminal.
+pub trait TerminalEvaluatable<'a, A, B>: Evaluatable<'a, A, B> {}
+
+/// Evaluatable provides methods for parsing and evaluating input values into a
+/// corresponding concrete type.
+pub trait Evaluatable<'a, A, B> {
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, B>;
+
+    fn join<E, C>(self, evaluator2: E) -> BoxedEvaluator<'a, A, (B, C)>
+    where
+        Self: Sized + BoxedEvaluatable<'a, A, B> + 'a,
+        E: BoxedEvaluatable<'a, A, C> + 'a,
+        A: Copy + 'a,
+    {
+        BoxedEvaluator::new(Join::<Self, E>::new(self, evaluator2))
+    }
+}
+
+/// Provenance identifies the layer that supplied an evaluated value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Provenance {
+    /// The value was passed explicitly as a flag.
+    Flag,
+    /// The value was read from an environment variable.
+    Env,
+    /// The value fell back to a configured default.
+    Default,
+}
+
+/// ProvenanceEvaluatable extends an Evaluatable with a method that reports
+/// which layer supplied the evaluated value alongside the value itself.
+pub trait ProvenanceEvaluatable<'a, A, B>: Evaluatable<'a, A, B> {
+    fn evaluate_with_provenance(&self, input: A) -> EvaluateResult<'a, (B, Provenance)>;
+}
+
+/// BoxedEvaluatable serves as a compound trait for the sake of combining the
+/// Helpable and Evaluator traits.
+pub trait BoxedEvaluatable<'a, A, B>:
+    Evaluatable<'a, A, B> + ShortHelpable<Output = FlagHelpCollector>
+{
+}
+
+impl<'a, A, B, T> BoxedEvaluatable<'a, A, B> for T where
+    T: Evaluatable<'a, A, B> + ShortHelpable<Output = FlagHelpCollector> + 'a
+{
+}
+
+/// BoxedEvaluator provides a wrapper for Evaluatable types.
+pub struct BoxedEvaluator<'a, A, B> {
+    evaluator: Box<dyn BoxedEvaluatable<'a, A, B> + 'a>,
+}
+
+impl<'a, A, B> IsFlag for BoxedEvaluator<'a, A, B> {}
+
+impl<'a, A, B> BoxedEvaluator<'a, A, B> {
+    pub fn new<E>(evaluator: E) -> Self
+    where
+        E: BoxedEvaluatable<'a, A, B> + 'a,
+    {
+        BoxedEvaluator {
+            evaluator: Box::new(evaluator),
+        }
+    }
+}
+
+impl<'a, A, B> ShortHelpable for BoxedEvaluator<'a, A, B> {
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.evaluator.short_help()
+    }
+}
+
+impl<'a, A, B> Evaluatable<'a, A, B> for BoxedEvaluator<'a, A, B> {
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
+        self.evaluator.evaluate(input)
+    }
+}
+
+impl<'a, F, A, B> Evaluatable<'a, A, B> for F
+where
+    A: 'a,
+    F: Fn(A) -> EvaluateResult<'a, B>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
+        self(input)
+    }
+}
+
+/// Join provides a wrapper type for flag `Evaluators` allowing two evaluators
+/// to be joined into a two return value. This join provides the basis for
+/// compound or multiple flag values being passed upstream to a `Cmd`.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let input = ["hello", "-n", "foo", "-l", "info"];
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..5), ("foo".to_string(), "info".to_string()))),
+///     Join::new(
+///         FlagWithValue::new("name", "n", "A name.", StringValue),
+///         FlagWithValue::new("log-level", "l", "A given log level setting.", StringValue),
+///     )
+///     .evaluate(&input[..])
+/// );
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..5), ("foo".to_string(), "info".to_string()))),
+///     Flag::expect_string("name", "n", "A name.")
+///         .join(FlagWithValue::new(
+///             "log-level",
+///             "l",
+///             "A given log level setting.",
+///             StringValue
+///         ))
+///         .evaluate(&input[..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Join<E1, E2> {
+    evaluator1: E1,
+    evaluator2: E2,
+}
+
+impl<E1, E2> IsFlag for Join<E1, E2> {}
+
+impl<E1, E2> Join<E1, E2> {
+    /// Instantiates a new instance of Join with two given evaluators.
+    pub fn new(evaluator1: E1, evaluator2: E2) -> Self {
+        Self {
+            evaluator1,
+            evaluator2,
+        }
+    }
+}
+
+impl<'a, E1, E2, A, B, C> Evaluatable<'a, A, (B, C)> for Join<E1, E2>
+where
+    A: Copy + std::borrow::Borrow<A> + 'a,
+    E1: Evaluatable<'a, A, B>,
+    E2: Evaluatable<'a, A, C>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, (B, C)> {
+        self.evaluator1
+            .evaluate(input)
+            .and_then(|e1_res| match self.evaluator2.evaluate(input) {
+                Ok(e2_res) => {
+                    let (e1_span, e1_val) = (e1_res.span, e1_res.value);
+                    let (e2_span, e2_val) = (e2_res.span, e2_res.value);
+                    let joined_span = e1_span.join(e2_span);
+
+                    Ok(Value::new(joined_span, (e1_val, e2_val)))
+                }
+                Err(e) => Err(e),
+            })
+    }
+}
+
+impl<E1, E2> ShortHelpable for Join<E1, E2>
+where
+    E1: ShortHelpable<Output = FlagHelpCollector>,
+    E2: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        FlagHelpCollector::Joined(
+            Box::new(self.evaluator1.short_help()),
+            Box::new(self.evaluator2.short_help()),
+        )
+    }
+}
+
+/// JoinOptional joins two evaluators like `Join`, but tolerates a failure of
+/// the right-hand evaluator, yielding `None` in its place. A failure of the
+/// left-hand evaluator still fails the join.
+///
+/// This is equivalent to a `Join` with its right-hand side wrapped in
+/// `Optional`, without the additional wrapper at each step when chaining
+/// several optional flags onto a required one. Unlike wrapping each flag in
+/// `Optional` the left-hand side remains required, so a required flag can
+/// anchor the chain.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flags = JoinOptional::new(
+///     Flag::expect_string("name", "n", "A name."),
+///     Flag::expect_string("log-level", "l", "A given log level setting."),
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), ("foo".to_string(), None))),
+///     flags.evaluate(&["hello", "-n", "foo"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::FlagEvaluation("name".to_string())),
+///     flags.evaluate(&["hello", "-l", "info"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct JoinOptional<E1, E2> {
+    evaluator1: E1,
+    evaluator2: E2,
+}
+
+impl<E1, E2> IsFlag for JoinOptional<E1, E2> {}
+
+impl<E1, E2> JoinOptional<E1, E2> {
+    /// Instantiates a new instance of JoinOptional with a required left-hand
+    /// evaluator and an optional right-hand evaluator.
+    pub fn new(evaluator1: E1, evaluator2: E2) -> Self {
+        Self {
+            evaluator1,
+            evaluator2,
+        }
+    }
+}
+
+impl<'a, E1, E2, A, B, C> Evaluatable<'a, A, (B, Option<C>)> for JoinOptional<E1, E2>
+where
+    A: Copy + 'a,
+    E1: Evaluatable<'a, A, B>,
+    E2: Evaluatable<'a, A, C>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, (B, Option<C>)> {
+        self.evaluator1.evaluate(input).map(|e1_res| {
+            let (e1_span, e1_val) = (e1_res.span, e1_res.value);
+
+            match self.evaluator2.evaluate(input) {
+                Ok(Value {
+                    span: e2_span,
+                    value: e2_val,
+                }) => Value::new(e1_span.join(e2_span), (e1_val, Some(e2_val))),
+                Err(_) => Value::new(e1_span, (e1_val, None)),
+            }
+        })
+    }
+}
+
+impl<E1, E2> ShortHelpable for JoinOptional<E1, E2>
+where
+    E1: ShortHelpable<Output = FlagHelpCollector>,
+    E2: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        let optional_help = match self.evaluator2.short_help() {
+            FlagHelpCollector::Single(fhc) => {
+                FlagHelpCollector::Single(fhc.with_modifier("optional".to_string()))
+            }
+            // a joined right-hand side carries the modifiers of its members.
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        };
+
+        FlagHelpCollector::Joined(
+            Box::new(self.evaluator1.short_help()),
+            Box::new(optional_help),
+        )
+    }
+}
+
+/// AtLeastOne joins two evaluators, requiring that at least one of them
+/// evaluates successfully, yielding the value of each as an `Option`. When
+/// neither evaluates, a `CliError::MissingRequiredFlag` naming the flags of
+/// both is returned, while a flag that is passed but fails to evaluate, such
+/// as with an invalid value, returns its own error.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flags = AtLeastOne::new(
+///     Flag::expect_string("input", "i", "An input file."),
+///     Flag::store_true("stdin", "s", "Read from stdin."),
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), (None, Some(true)))),
+///     flags.evaluate(&["hello", "--stdin"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::MissingRequiredFlag(vec![
+///         "input".to_string(),
+///         "stdin".to_string()
+///     ])),
+///     flags.evaluate(&["hello"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct AtLeastOne<E1, E2> {
+    evaluator1: E1,
+    evaluator2: E2,
+}
+
+impl<E1, E2> IsFlag for AtLeastOne<E1, E2> {}
+
+impl<E1, E2> AtLeastOne<E1, E2> {
+    /// Instantiates a new instance of AtLeastOne with two given evaluators.
+    pub fn new(evaluator1: E1, evaluator2: E2) -> Self {
+        Self {
+            evaluator1,
+            evaluator2,
+        }
+    }
+}
+
+impl<'a, E1, E2, B, C> Evaluatable<'a, &'a [&'a str], (Option<B>, Option<C>)> for AtLeastOne<E1, E2>
+where
+    E1: Evaluatable<'a, &'a [&'a str], B> + ShortHelpable<Output = FlagHelpCollector>,
+    E2: Evaluatable<'a, &'a [&'a str], C> + ShortHelpable<Output = FlagHelpCollector>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, (Option<B>, Option<C>)> {
+        match (
+            self.evaluator1.evaluate(input),
+            self.evaluator2.evaluate(input),
+        ) {
+            // a flag that is passed with an invalid value is reported, not skipped.
+            (Err(e), _) if self.evaluator1.short_help().is_present(input) => Err(e),
+            (_, Err(e)) if self.evaluator2.short_help().is_present(input) => Err(e),
+            (Err(_), Err(_)) => {
+                let help = FlagHelpCollector::Joined(
+                    Box::new(self.evaluator1.short_help()),
+                    Box::new(self.evaluator2.short_help()),
+                );
+
+                Err(CliError::MissingRequiredFlag(
+                    help.contexts().iter().map(|ctx| ctx.long_name()).collect(),
+                ))
+            }
+            (e1_res, e2_res) => {
+                let (e1_span, e1_val) = match e1_res {
+                    Ok(Value { span, value }) => (span, Some(value)),
+                    Err(_) => (Span::empty(), None),
+                };
+                let (e2_span, e2_val) = match e2_res {
+                    Ok(Value { span, value }) => (span, Some(value)),
+                    Err(_) => (Span::empty(), None),
+                };
+
+                Ok(Value::new(e1_span.join(e2_span), (e1_val, e2_val)))
+            }
+        }
+    }
+}
+
+impl<E1, E2> ShortHelpable for AtLeastOne<E1, E2>
+where
+    E1: ShortHelpable<Output = FlagHelpCollector>,
+    E2: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        FlagHelpCollector::Joined(
+            Box::new(self.evaluator1.short_help()),
+            Box::new(self.evaluator2.short_help()),
+        )
+    }
+}
+
+/// AllOrNone joins two evaluators that must be supplied together or not at
+/// all, such as a certificate and its key, yielding the value of each as an
+/// `Option`. The dual of `Conflicts`, when exactly one is passed, a
+/// `CliError::MissingDependency` naming the flags of each side is returned,
+/// while a flag that is passed but fails to evaluate, such as with an invalid
+/// value, returns its own error.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flags = AllOrNone::new(
+///     Flag::expect_string("cert", "c", "A certificate path."),
+///     Flag::expect_string("key", "k", "A key path."),
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), (None, None))),
+///     flags.evaluate(&["hello"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::MissingDependency {
+///         present: vec!["cert".to_string()],
+///         missing: vec!["key".to_string()],
+///     }),
+///     flags.evaluate(&["hello", "--cert", "tls.crt"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct AllOrNone<E1, E2> {
+    evaluator1: E1,
+    evaluator2: E2,
+}
+
+impl<E1, E2> IsFlag for AllOrNone<E1, E2> {}
+
+impl<E1, E2> AllOrNone<E1, E2> {
+    /// Instantiates a new instance of AllOrNone with two given evaluators.
+    pub fn new(evaluator1: E1, evaluator2: E2) -> Self {
+        Self {
+            evaluator1,
+            evaluator2,
+        }
+    }
+}
+
+impl<'a, E1, E2, B, C> Evaluatable<'a, &'a [&'a str], (Option<B>, Option<C>)> for AllOrNone<E1, E2>
+where
+    E1: Evaluatable<'a, &'a [&'a str], B> + ShortHelpable<Output = FlagHelpCollector>,
+    E2: Evaluatable<'a, &'a [&'a str], C> + ShortHelpable<Output = FlagHelpCollector>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, (Option<B>, Option<C>)> {
+        let names = |help: FlagHelpCollector| {
+            help.contexts()
+                .iter()
+                .map(|ctx| ctx.long_name())
+                .collect::<Vec<String>>()
+        };
+
+        match (
+            self.evaluator1.evaluate(input),
+            self.evaluator2.evaluate(input),
+        ) {
+            (
+                Ok(Value {
+                    span: e1_span,
+                    value: b,
+                }),
+                Ok(Value {
+                    span: e2_span,
+                    value: c,
+                }),
+            ) => Ok(Value::new(e1_span.join(e2_span), (Some(b), Some(c)))),
+            // a flag that is passed with an invalid value is reported, not skipped.
+            (Err(e), _) if self.evaluator1.short_help().is_present(input) => Err(e),
+            (_, Err(e)) if self.evaluator2.short_help().is_present(input) => Err(e),
+            (Err(_), Err(_)) => Ok(Value::new(Span::empty(), (None, None))),
+            (Ok(_), Err(_)) => Err(CliError::MissingDependency {
+                present: names(self.evaluator1.short_help()),
+                missing: names(self.evaluator2.short_help()),
+            }),
+            (Err(_), Ok(_)) => Err(CliError::MissingDependency {
+                present: names(self.evaluator2.short_help()),
+                missing: names(self.evaluator1.short_help()),
+            }),
+        }
+    }
+}
+
+impl<E1, E2> ShortHelpable for AllOrNone<E1, E2>
+where
+    E1: ShortHelpable<Output = FlagHelpCollector>,
+    E2: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        FlagHelpCollector::Joined(
+            Box::new(self.evaluator1.short_help()),
+            Box::new(self.evaluator2.short_help()),
+        )
+    }
+}
+
+/// Conflicts wraps an evaluator, declaring a set of its flags, by name,
+/// mutually exclusive. Once evaluated, the input is checked for the long or
+/// short form of each named flag and, when more than one is present,
+/// evaluation fails with a `CliError::MutuallyExclusive` listing them.
+#[derive(Debug, Clone)]
+pub struct Conflicts<E, const N: usize> {
+    names: [&'static str; N],
+    evaluator: E,
+}
+
+impl<E, const N: usize> IsFlag for Conflicts<E, N> {}
+
+impl<E, const N: usize> Conflicts<E, N> {
+    /// Instantiates a new instance of Conflicts over the named flags.
+    pub fn new(names: [&'static str; N], evaluator: E) -> Self {
+        Self { names, evaluator }
+    }
+}
+
+impl<'a, E, B, const N: usize> Evaluatable<'a, &'a [&'a str], B> for Conflicts<E, N>
+where
+    E: Evaluatable<'a, &'a [&'a str], B> + ShortHelpable<Output = FlagHelpCollector>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
+        let value = self.evaluator.evaluate(input)?;
+
+        let flag_help = self.evaluator.short_help();
+        let present = flag_help
+            .contexts()
+            .into_iter()
+            .map(|ctx| (ctx.long_name(), ctx))
+            .filter(|(name, _)| self.names.contains(&name.as_str()))
+            .filter(|(_, ctx)| input.iter().any(|&arg| ctx.matches(arg)))
+            .map(|(name, _)| name)
+            .collect::<Vec<String>>();
+
+        if present.len() > 1 {
+            Err(CliError::MutuallyExclusive(present))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl<E, const N: usize> ShortHelpable for Conflicts<E, N>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.evaluator.short_help()
+    }
+}
+
+/// Prefixed wraps an evaluator, namespacing the long form of each of its
+/// flags with a prefix, such that a `host` flag prefixed with `db` is matched
+/// and documented as `--db-host`. This allows a group of flags, such as those
+/// of a former subcommand, to be merged into a parent command without their
+/// names colliding. Unprefixed long forms are hidden from the enclosed
+/// evaluator, neither matching its flags nor being consumed as a value, while
+/// short codes are passed through unchanged and must remain distinct from
+/// those of the parent. Errors report flags by their prefixed name.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = Prefixed::new("db", Flag::expect_string("host", "H", "A database host."));
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), "localhost".to_string())),
+///     flag.evaluate(&["hello", "--db-host", "localhost"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::FlagEvaluation("db-host".to_string())),
+///     flag.evaluate(&["hello", "--host", "localhost"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::MissingValue("db-host".to_string())),
+///     flag.evaluate(&["hello", "--db-host", "--verbose"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prefixed<E> {
+    prefix: &'static str,
+    evaluator: E,
+}
+
+impl<E> IsFlag for Prefixed<E> {}
+
+impl<E> Prefixed<E> {
+    /// Instantiates a new instance of Prefixed with the given prefix.
+    pub fn new(prefix: &'static str, evaluator: E) -> Self {
+        Self { prefix, evaluator }
+    }
+
+    /// Rewrites an argument as seen by the enclosed evaluator, stripping the
+    /// prefix from prefixed long forms, or returning `None` for any other
+    /// long form to be hidden.
+    fn unprefix(&self, arg: &str) -> Option<String> {
+        match arg.strip_prefix("--") {
+            // the end of options marker is never namespaced.
+            Some("") => Some(arg.to_string()),
+            Some(long) => long
+                .strip_prefix(self.prefix)
+                .and_then(|long| long.strip_prefix('-'))
+                .map(|long| format!("--{}", long)),
+            None => Some(arg.to_string()),
+        }
+    }
+
+    /// Returns the name of an enclosed flag as namespaced by the prefix.
+    fn prefixed_name(&self, name: &str) -> String {
+        format!("{}-{}", self.prefix, name)
+    }
+
+    /// Prefixes the long form of every flag in the collector.
+    fn prefix_help(&self, help: FlagHelpCollector) -> FlagHelpCollector {
+        match help {
+            FlagHelpCollector::Single(fhc) => {
+                FlagHelpCollector::Single(fhc.with_prefix(self.prefix))
+            }
+            FlagHelpCollector::Joined(lfhc, rfhc) => FlagHelpCollector::Joined(
+                Box::new(self.prefix_help(*lfhc)),
+                Box::new(self.prefix_help(*rfhc)),
+            ),
+        }
+    }
+}
+
+impl<'a, E, B> Evaluatable<'a, &'a [&'a str], B> for Prefixed<E>
+where
+    E: for<'b> Evaluatable<'b, &'b [&'b str], B> + ShortHelpable<Output = FlagHelpCollector>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
+        // arguments are rewritten in place, preserving the positions of spans.
+        let rewritten = input
+            .iter()
+            .map(|arg| self.unprefix(arg))
+            .collect::<Vec<Option<String>>>();
+        // a hidden long form is replaced by a flag-like argument that no flag
+        // matches.
+        let args = rewritten
+            .iter()
+            .map(|arg| arg.as_deref().unwrap_or("---"))
+            .collect::<Vec<&str>>();
+
+        let value = self
+            .evaluator
+            .evaluate(&args[..])
+            .map_err(|e| e.map_flag_names(|name| self.prefixed_name(name)))?;
+
+        // a hidden long form belongs to another flag, so it can't be consumed
+        // as the value of the flag preceding it.
+        match value.span.0.iter().find(|&&idx| rewritten[idx].is_none()) {
+            Some(&hidden) => {
+                let help = self.short_help();
+                let contexts = help.contexts();
+                let consumer = input[..hidden]
+                    .iter()
+                    .rev()
+                    .find_map(|&arg| contexts.iter().find(|ctx| ctx.matches(arg)))
+                    .map(|ctx| ctx.long_name())
+                    .unwrap_or_else(|| self.prefix.to_string());
+
+                Err(CliError::MissingValue(consumer))
+            }
+            None => Ok(value),
+        }
+    }
+}
+
+impl<E> ShortHelpable for Prefixed<E>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.prefix_help(self.evaluator.short_help())
+    }
+}
+
+/// Validated wraps an evaluator, running a validator against its evaluated
+/// value. A validation failure is returned as a `CliError::Validation`
+/// carrying the validator's message.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = Validated::new(Flag::expect_u8("count", "c", "A count."), |count: &u8| {
+///     if *count > 0 {
+///         Ok(())
+///     } else {
+///         Err("count must be positive".to_string())
+///     }
+/// });
+///
+/// assert_eq!(
+///     Err(CliError::Validation("count must be positive".to_string())),
+///     flag.evaluate(&["hello", "-c", "0"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Validated<E, V> {
+    evaluator: E,
+    validator: V,
+}
+
+impl<E, V> IsFlag for Validated<E, V> {}
+
+impl<E, V> Validated<E, V> {
+    /// Instantiates a new instance of Validated with a given evaluator and
+    /// validator.
+    pub fn new(evaluator: E, validator: V) -> Self {
+        Self {
+            evaluator,
+            validator,
+        }
+    }
+}
+
+impl<'a, E, V, A, B> Evaluatable<'a, A, B> for Validated<E, V>
+where
+    E: Evaluatable<'a, A, B>,
+    V: Fn(&B) -> Result<(), String>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
+        self.evaluator.evaluate(input).and_then(|v| {
+            (self.validator)(&v.value)
+                .map(|_| v)
+                .map_err(CliError::Validation)
+        })
+    }
+}
+
+impl<E, V> ShortHelpable for Validated<E, V>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.evaluator.short_help()
+    }
+}
+
+// Tuple types
+
+// Tuples of evaluators provide a flat alternative to nesting `Join`, evaluating
+// each member against the same input and yielding a tuple of their values.
+macro_rules! generate_tuple_evaluators {
+    ($(($($evaluator:ident, $value:ident, $idx:tt),*),)*) => {
+        $(
+        impl<$($evaluator),*> IsFlag for ($($evaluator,)*) {}
+
+        impl<'a, A, $($evaluator, $value),*> Evaluatable<'a, A, ($($value,)*)> for ($($evaluator,)*)
+        where
+            A: Copy + 'a,
+            $($evaluator: Evaluatable<'a, A, $value>,)*
+        {
+            fn evaluate(&self, input: A) -> EvaluateResult<'a, ($($value,)*)> {
+                let mut span = Span::empty();
+                let values = ($(
+                    {
+                        let Value { span: member_span, value } = self.$idx.evaluate(input)?;
+                        span = span.join(member_span);
+                        value
+                    },
+                )*);
+
+                Ok(Value::new(span, values))
+            }
+        }
+
+        impl<$($evaluator),*> ShortHelpable for ($($evaluator,)*)
+        where
+            $($evaluator: ShortHelpable<Output = FlagHelpCollector>,)*
+        {
+            type Output = FlagHelpCollector;
+
+            fn short_help(&self) -> Self::Output {
+                vec![$(self.$idx.short_help()),*]
+                    .into_iter()
+                    .reduce(|lfhc, rfhc| FlagHelpCollector::Joined(Box::new(lfhc), Box::new(rfhc)))
+                    .unwrap_or_default()
+            }
+        }
+        )*
+    };
+}
+
+#[rustfmt::skip]
+generate_tuple_evaluators!(
+    (E1, B1, 0, E2, B2, 1),
+    (E1, B1, 0, E2, B2, 1, E3, B3, 2),
+    (E1, B1, 0, E2, B2, 1, E3, B3, 2, E4, B4, 3),
+    (E1, B1, 0, E2, B2, 1, E3, B3, 2, E4, B4, 3, E5, B5, 4),
+    (E1, B1, 0, E2, B2, 1, E3, B3, 2, E4, B4, 3, E5, B5, 4, E6, B6, 5),
+    (E1, B1, 0, E2, B2, 1, E3, B3, 2, E4, B4, 3, E5, B5, 4, E6, B6, 5, E7, B7, 6),
+    (E1, B1, 0, E2, B2, 1, E3, B3, 2, E4, B4, 3, E5, B5, 4, E6, B6, 5, E7, B7, 6, E8, B8, 7),
+);
+
+/// A trait that signifies if a type can be assigned a default value. This
+/// includes helper methods for assigning a type as optional and assigning a
+/// default.
+pub trait Defaultable
+where
+    Self: Sized,
+{
+    /// with_default returns a given type wrapped in a WithDefault with the
+    /// provided default value. Functionally this is an alias for
+    /// `WithDefault::new(self, default)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// FlagWithValue::new("name", "n", "A name.", StringValue).optional().with_default("foo".to_string());
+    /// ```
+    fn with_default<D>(self, default: D) -> WithDefault<D, Self> {
+        WithDefault::new(default, self)
+    }
+
+    /// hidden_default returns a given type wrapped in a WithDefault with the
+    /// provided default value, like `with_default`, but without advertising
+    /// the default in help.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let flag = FlagWithValue::new("batch-size", "b", "A batch size.", U16Value)
+    ///     .optional()
+    ///     .hidden_default(64u16);
+    ///
+    /// assert_eq!(Ok(Value::new(Span::empty(), 64)), flag.evaluate(&["hello"][..]));
+    /// assert!(!flag.short_help().to_string().contains("default"));
+    /// ```
+    fn hidden_default<D>(self, default: D) -> WithDefault<D, Self> {
+        let mut with_default = WithDefault::new(default, self);
+        with_default.hidden = true;
+        with_default
+    }
+
+    /// optional wraps a given type in an Optional struct. Functionally this
+    /// is an alias for `Optional::new(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// FlagWithValue::new("name", "n", "A name.", StringValue).optional();
+    /// ```
+    fn optional(self) -> Optional<Self> {
+        Optional::new(self)
+    }
+}
+
+/// WithDefault takes an evaluator E and a default value B that agrees with the
+/// return type of the Evaluator. This default is meant to wrap the enclosed
+/// evaluator, returning the A success with the default value for any
+/// evaluation that fails.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let input = ["hello", "--log-level", "info"];
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(0..0), "foo".to_string())),
+///     WithDefault::new(
+///         "foo",
+///         Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue))
+///     )
+///     .evaluate(&input[..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(0..0), "foo".to_string())),
+///     Flag::expect_string("name", "n", "A name.")
+///         .optional()
+///         .with_default("foo".to_string())
+///         .evaluate(&input[..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithDefault<B, E> {
+    default: B,
+    evaluator: E,
+    /// Omits the default from help when set.
+    hidden: bool,
+}
+
+impl<B, E> IsFlag for WithDefault<B, E> {}
+
+impl<B, E> WithDefault<B, E> {
+    /// Instantiates a new of WithDefault for a given type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// WithDefault::<String, _>::new(
+    ///     "foo",
+    ///     Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue))
+    /// );
+    /// ```
+    pub fn new<D>(default: D, evaluator: E) -> Self
+    where
+        D: Into<B>,
+    {
+        Self {
+            default: Into::<B>::into(default),
+            evaluator,
+            hidden: false,
+        }
+    }
+}
+
+impl<'a, E, A, B> Evaluatable<'a, A, B> for WithDefault<B, E>
+where
+    A: 'a,
+    B: Clone,
+    E: Evaluatable<'a, A, Option<B>>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
+        self.evaluator
+            .evaluate(input)
+            .map(|op| op.map(|opt| opt.unwrap_or_else(|| self.default.clone())))
+    }
+}
+
+impl<'a, E, A, B> ProvenanceEvaluatable<'a, A, B> for WithDefault<B, Optional<E>>
+where
+    A: 'a,
+    B: Clone,
+    E: ProvenanceEvaluatable<'a, A, B>,
+{
+    /// Evaluates the enclosed evaluator, reporting a `Provenance::Default`
+    /// when the default value is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let flag = Flag::expect_string("name", "n", "A name.")
+    ///     .optional()
+    ///     .with_default("foo".to_string());
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), ("bar".to_string(), Provenance::Flag))),
+    ///     flag.evaluate_with_provenance(&["hello", "-n", "bar"][..])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::empty(), ("foo".to_string(), Provenance::Default))),
+    ///     flag.evaluate_with_provenance(&["hello"][..])
+    /// );
+    /// ```
+    fn evaluate_with_provenance(&self, input: A) -> EvaluateResult<'a, (B, Provenance)> {
+        match self.evaluator.evaluator.evaluate_with_provenance(input) {
+            Ok(value) => Ok(value),
+            Err(_) => Ok(Value::new(
+                Span::default(),
+                (self.default.clone(), Provenance::Default),
+            )),
+        }
+    }
+}
+
+impl<B, E> ShortHelpable for WithDefault<B, E>
+where
+    B: Clone + std::fmt::Debug,
+    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            fhc @ FlagHelpCollector::Single(_) if self.hidden => fhc,
+            FlagHelpCollector::Single(fhc) => FlagHelpCollector::Single(
+                fhc.with_modifier(format!("default: {:?}", self.default.clone())),
+            ),
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// Fallbacks takes an evaluator E, that agrees with the return type
+/// `Option<B>`, and an ordered list of fallback providers that are consulted,
+/// in order, when the evaluator yields no value. The value of the first
+/// provider to return `Some` is used, and if every provider misses the hard
+/// default is returned.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = || Fallbacks::new(
+///     "default".to_string(),
+///     Flag::expect_string("name", "n", "A name.").optional(),
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), "flag".to_string())),
+///     flag()
+///         .with_provider(|| Some("first".to_string()))
+///         .evaluate(&["hello", "-n", "flag"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), "second".to_string())),
+///     flag()
+///         .with_provider(|| None)
+///         .with_provider(|| Some("second".to_string()))
+///         .evaluate(&["hello"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), "default".to_string())),
+///     flag()
+///         .with_provider(|| None)
+///         .with_provider(|| None)
+///         .evaluate(&["hello"][..])
+/// );
+/// ```
+pub struct Fallbacks<B, E> {
+    default: B,
+    providers: Vec<Box<dyn Fn() -> Option<B>>>,
+    evaluator: E,
+}
+
+impl<B, E> IsFlag for Fallbacks<B, E> {}
+
+impl<B, E> Fallbacks<B, E> {
+    /// Instantiates a new instance of Fallbacks with a hard default and no
+    /// fallback providers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Fallbacks::<String, _>::new(
+    ///     "foo",
+    ///     Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue))
+    /// );
+    /// ```
+    pub fn new<D>(default: D, evaluator: E) -> Self
+    where
+        D: Into<B>,
+    {
+        Self {
+            default: Into::<B>::into(default),
+            providers: Vec::new(),
+            evaluator,
+        }
+    }
+
+    /// Returns Fallbacks with the provider appended to the end of the
+    /// fallback providers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Fallbacks::<String, _>::new(
+    ///     "foo",
+    ///     Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue))
+    /// )
+    /// .with_provider(|| std::env::var("NAME").ok());
+    /// ```
+    pub fn with_provider<P>(mut self, provider: P) -> Self
+    where
+        P: Fn() -> Option<B> + 'static,
+    {
+        self.providers.push(Box::new(provider));
+        self
+    }
+}
+
+impl<'a, E, A, B> Evaluatable<'a, A, B> for Fallbacks<B, E>
+where
+    A: 'a,
+    B: Clone,
+    E: Evaluatable<'a, A, Option<B>>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
+        self.evaluator.evaluate(input).map(|op| {
+            op.map(|opt| {
+                opt.or_else(|| self.providers.iter().find_map(|provider| provider()))
+                    .unwrap_or_else(|| self.default.clone())
+            })
+        })
+    }
+}
+
+impl<B, E> ShortHelpable for Fallbacks<B, E>
+where
+    B: std::fmt::Debug,
+    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => {
+                FlagHelpCollector::Single(fhc.with_modifier(format!("default: {:?}", self.default)))
+            }
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// Optional wraps an evaluator, for the purpose of transforming the enclosed
+/// evaluator from an `Evaluator<A, B>` to an `Evaluator<A, Option<B>>` where
+/// the success state of the evaluation is capture in the value of the
+/// `Option<B>`.
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let input = ["hello", "-n", "foo"];
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), Some("foo".to_string()))),
+///     Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue)).evaluate(&input[..])
+/// );
+///
+/// // validate boxed syntax works
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), Some("foo".to_string()))),
+///     FlagWithValue::new("name", "n", "A name.", StringValue)
+///         .optional()
+///         .evaluate(&input[..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), None)),
+///     Optional::new(FlagWithValue::new(
+///         "log-level",
+///         "l",
+///         "A given log level setting.",
+///         StringValue
+///     ))
+///     .evaluate(&input[..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Optional<E> {
+    evaluator: E,
+}
+
+impl<E> IsFlag for Optional<E> {}
+
+impl<E> Defaultable for Optional<E> where E: Defaultable {}
+
+impl<E> Optional<E> {
+    /// Instantiates a new instance of Optional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue));
+    /// ```
+    pub fn new(evaluator: E) -> Self {
+        Self { evaluator }
+    }
+}
+
+impl<'a, E, A, B> Evaluatable<'a, A, Option<B>> for Optional<E>
+where
+    A: 'a,
+    E: Evaluatable<'a, A, B>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, Option<B>> {
+        match self.evaluator.evaluate(input).ok() {
+            Some(Value { span, value }) => Ok(Value::new(span, Some(value))),
+            None => Ok(Value::new(Span::default(), None)),
+        }
+    }
+}
+
+impl<E> ShortHelpable for Optional<E>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => {
+                FlagHelpCollector::Single(fhc.with_modifier("optional".to_string()))
+            }
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// MaxOccurrences wraps a flag, capping the number of times it may be passed
+/// across both its long and short forms. Exceeding the cap fails evaluation
+/// with a `CliError::TooManyOccurrences`.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = Flag::store_true("verbose", "v", "verbose output.").max_occurrences(2);
+///
+/// assert!(flag.evaluate(&["hello", "-v", "--verbose"][..]).is_ok());
+/// assert_eq!(
+///     Err(CliError::TooManyOccurrences { flag: "verbose".to_string(), max: 2 }),
+///     flag.evaluate(&["hello", "-v", "-v", "-v"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct MaxOccurrences<E> {
+    max: usize,
+    evaluator: E,
+}
+
+impl<E> IsFlag for MaxOccurrences<E> {}
+
+impl<E> Defaultable for MaxOccurrences<E> where E: Defaultable {}
+
+impl<E> MaxOccurrences<E> {
+    /// Instantiates a new instance of MaxOccurrences, permitting at most
+    /// `max` occurrences of the wrapped flag.
+    pub fn new(max: usize, evaluator: E) -> Self {
+        Self { max, evaluator }
+    }
+}
+
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], B> for MaxOccurrences<FlagWithValue<V>>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
+        if self.evaluator.occurrences(input).len() > self.max {
+            Err(CliError::TooManyOccurrences {
+                flag: self.evaluator.name.to_string(),
+                max: self.max,
+            })
+        } else {
+            self.evaluator.evaluate(input)
+        }
+    }
+}
+
+impl<E> ShortHelpable for MaxOccurrences<E>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => FlagHelpCollector::Single(
+                fhc.with_modifier(format!("max occurrences: {}", self.max)),
+            ),
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// Repeated wraps a flag, collecting the value of every occurrence of the
+/// flag, across both its long and short forms, in input order. An absent
+/// flag evaluates to an empty Vec, while any occurrence failing to evaluate
+/// fails the whole evaluation.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = Repeated::new(Flag::expect_string("include", "i", "A path to include."));
+///
+/// assert_eq!(
+///     Ok(Value::new(
+///         Span::from_range(1..7),
+///         vec!["a".to_string(), "b".to_string(), "c".to_string()]
+///     )),
+///     flag.evaluate(&["hello", "--include", "a", "-i", "b", "--include", "c"][..])
+/// );
+///
+/// assert_eq!(Ok(Value::new(Span::empty(), vec![])), flag.evaluate(&["hello"][..]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Repeated<E> {
+    evaluator: E,
+}
+
+impl<E> IsFlag for Repeated<E> {}
+
+impl<E> Defaultable for Repeated<E> where E: Defaultable {}
+
+impl<E> Repeated<E> {
+    /// Instantiates a new instance of Repeated wrapping the passed flag.
+    pub fn new(evaluator: E) -> Self {
+        Self { evaluator }
+    }
+}
+
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], Vec<B>> for Repeated<FlagWithValue<V>>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, Vec<B>> {
+        self.evaluator.occurrences(input).into_iter().try_fold(
+            Value::new(Span::empty(), Vec::new()),
+            |mut acc, idx| {
+                // each occurrence is evaluated as the first of the remaining input.
+                let Value { span, value } =
+                    self.evaluator.evaluate(&input[idx..])?.from_offset(idx);
+                acc.value.push(value);
+                Ok(Value::new(acc.span.join(span), acc.value))
+            },
+        )
+    }
+}
+
+impl<E> ShortHelpable for Repeated<E>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => {
+                FlagHelpCollector::Single(fhc.with_modifier("repeatable".to_string()))
+            }
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// WhichAlias wraps a flag, returning the spelling of the flag, either its
+/// long or short form, that matched alongside its value. This allows a
+/// handler to react to the form a user supplied, such as nudging them away
+/// from a deprecated spelling.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = WhichAlias::new(Flag::expect_string("name", "n", "A name."));
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), ("-n".to_string(), "foo".to_string()))),
+///     flag.evaluate(&["hello", "-n", "foo"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), ("--name".to_string(), "foo".to_string()))),
+///     flag.evaluate(&["hello", "--name", "foo"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), ("--name".to_string(), "foo".to_string()))),
+///     flag.evaluate(&["hello", "--name=foo"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WhichAlias<E> {
+    evaluator: E,
+}
+
+impl<E> IsFlag for WhichAlias<E> {}
+
+impl<E> WhichAlias<E> {
+    /// Instantiates a new instance of WhichAlias wrapping a given flag.
+    pub fn new(evaluator: E) -> Self {
+        Self { evaluator }
+    }
+}
+
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], (String, B)> for WhichAlias<FlagWithValue<V>>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, (String, B)> {
+        self.evaluator.evaluate(input).map(|v| {
+            // the first position of a flag's span is always the flag itself.
+            let alias = v
+                .span
+                .0
+                .first()
+                .and_then(|&idx| input.get(idx))
+                .map(|&arg| match self.evaluator.inline_value(arg) {
+                    // an inline value isn't part of the alias.
+                    Some(value) => arg[..arg.len() - value.len() - 1].to_string(),
+                    None => arg.to_string(),
+                })
+                .unwrap_or_default();
+
+            v.map(|value| (alias, value))
+        })
+    }
+}
+
+impl<E> ShortHelpable for WhichAlias<E>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.evaluator.short_help()
+    }
+}
+
+/// WithEnv wraps a flag, falling back to reading its value from an
+/// environment variable when the flag isn't passed. The environment value is
+/// evaluated by the flag's own value type, and an environment-sourced value
+/// carries an empty span as it consumes no arguments.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// std::env::set_var("SCRAP_WITH_ENV_EXAMPLE_PORT", "8080");
+///
+/// let flag = Flag::expect_u16("port", "p", "A port.").with_env("SCRAP_WITH_ENV_EXAMPLE_PORT");
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), 22)),
+///     flag.evaluate(&["hello", "-p", "22"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), 8080)),
+///     flag.evaluate(&["hello"][..])
+/// );
+///
+/// assert!(
+///     Flag::expect_u16("port", "p", "A port.")
+///         .with_env("SCRAP_WITH_ENV_EXAMPLE_UNSET")
+///         .evaluate(&["hello"][..])
+///         .is_err()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithEnv<E> {
+    var: &'static str,
+    evaluator: E,
+}
+
+impl<E> IsFlag for WithEnv<E> {}
+
+impl<E> Defaultable for WithEnv<E> where E: Defaultable {}
+
+impl<E> WithEnv<E> {
+    /// Instantiates a new instance of WithEnv, reading from the named
+    /// environment variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// WithEnv::new("NAME", FlagWithValue::new("name", "n", "A name.", StringValue));
+    /// ```
+    pub fn new(var: &'static str, evaluator: E) -> Self {
+        Self { var, evaluator }
+    }
+}
+
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], B> for WithEnv<FlagWithValue<V>>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
+        self.evaluate_with_provenance(input)
+            .map(|v| v.map(|(value, _)| value))
+    }
+}
+
+impl<'a, V, B> ProvenanceEvaluatable<'a, &'a [&'a str], B> for WithEnv<FlagWithValue<V>>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    /// Evaluates the enclosed flag, reporting a `Provenance::Env` when the
+    /// value is read from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// std::env::set_var("SCRAP_PROVENANCE_EXAMPLE_NAME", "baz");
+    ///
+    /// let flag = Flag::expect_string("name", "n", "A name.")
+    ///     .with_env("SCRAP_PROVENANCE_EXAMPLE_NAME");
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), ("bar".to_string(), Provenance::Flag))),
+    ///     flag.evaluate_with_provenance(&["hello", "-n", "bar"][..])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::empty(), ("baz".to_string(), Provenance::Env))),
+    ///     flag.evaluate_with_provenance(&["hello"][..])
+    /// );
+    ///
+    /// std::env::remove_var("SCRAP_PROVENANCE_EXAMPLE_NAME");
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::empty(), ("foo".to_string(), Provenance::Default))),
+    ///     flag.optional()
+    ///         .with_default("foo".to_string())
+    ///         .evaluate_with_provenance(&["hello"][..])
+    /// );
+    /// ```
+    fn evaluate_with_provenance(
+        &self,
+        input: &'a [&'a str],
+    ) -> EvaluateResult<'a, (B, Provenance)> {
+        self.evaluator.evaluate_with_provenance(input).or_else(|e| {
+            // a passed flag with an invalid value is reported, not replaced.
+            if !self.evaluator.occurrences(input).is_empty() {
+                return Err(e);
+            }
+
+            std::env::var(self.var)
+                .map_err(|_| e)
+                .and_then(|env_value| {
+                    self.evaluator
+                        .value
+                        .evaluate(&[env_value.as_str()][..])
+                        .map(|v| Value::new(Span::empty(), (v.value, Provenance::Env)))
+                })
+        })
+    }
+}
+
+impl<E> ShortHelpable for WithEnv<E>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => FlagHelpCollector::Single(
+                fhc.with_modifier(format!("env: {}", self.var))
+                    .with_env_var(self.var),
+            ),
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// StdinFallback wraps a flag, falling back to reading the entirety of stdin
+/// as its value when the flag isn't passed and stdin is piped rather than a
+/// terminal, supporting invocations like `echo foo | tool`. A single trailing
+/// newline is stripped from the read value. When stdin is a terminal, or
+/// nothing was piped, the flag's own absent handling applies, while a passed
+/// flag that fails to evaluate returns its error without reading stdin. Stdin
+/// is read at most once, with the read value reused by any subsequent
+/// evaluation.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = StdinFallback::with_reader(
+///     Flag::expect_string("name", "n", "A name."),
+///     &b"foo\n"[..],
+///     false,
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), "bar".to_string())),
+///     flag.evaluate(&["hello", "-n", "bar"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), "foo".to_string())),
+///     flag.evaluate(&["hello"][..])
+/// );
+/// ```
+#[derive(Debug)]
+pub struct StdinFallback<E, R> {
+    evaluator: E,
+    reader: std::cell::RefCell<R>,
+    is_terminal: bool,
+    /// The value read from the reader, once read.
+    read: std::cell::OnceCell<Result<String, CliError>>,
+}
+
+impl<E, R> IsFlag for StdinFallback<E, R> {}
+
+impl<E, R> Defaultable for StdinFallback<E, R> where E: Defaultable {}
+
+impl<E> StdinFallback<E, std::io::Stdin> {
+    /// Instantiates a new instance of StdinFallback reading from the
+    /// process's stdin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// StdinFallback::new(FlagWithValue::new("name", "n", "A name.", StringValue));
+    /// ```
+    pub fn new(evaluator: E) -> Self {
+        use std::io::IsTerminal;
+
+        let stdin = std::io::stdin();
+        let is_terminal = stdin.is_terminal();
+        Self::with_reader(evaluator, stdin, is_terminal)
+    }
+}
+
+impl<E, R> StdinFallback<E, R> {
+    /// Instantiates a new instance of StdinFallback reading from the passed
+    /// reader in place of stdin, treated as a terminal when `is_terminal` is
+    /// set.
+    pub fn with_reader(evaluator: E, reader: R, is_terminal: bool) -> Self {
+        Self {
+            evaluator,
+            reader: std::cell::RefCell::new(reader),
+            is_terminal,
+            read: std::cell::OnceCell::new(),
+        }
+    }
+}
+
+impl<E, R> StdinFallback<E, R>
+where
+    R: std::io::Read,
+{
+    /// Returns the piped input, reading it on first use.
+    fn piped(&self) -> Result<&str, CliError> {
+        self.read
+            .get_or_init(|| {
+                let mut buf = String::new();
+                self.reader
+                    .borrow_mut()
+                    .read_to_string(&mut buf)
+                    .map_err(|e| CliError::Io(format!("unable to read stdin: {}", e)))
+                    .map(|_| {
+                        let trimmed = buf
+                            .strip_suffix('\n')
+                            .map(|v| v.strip_suffix('\r').unwrap_or(v))
+                            .unwrap_or(&buf);
+                        trimmed.to_string()
+                    })
+            })
+            .as_deref()
+            .map_err(Clone::clone)
+    }
+}
+
+impl<'a, V, B, R> Evaluatable<'a, &'a [&'a str], B> for StdinFallback<FlagWithValue<V>, R>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+    R: std::io::Read,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
+        self.evaluator.evaluate(input).or_else(|e| {
+            // a passed flag's own errors, like an invalid value, propagate.
+            if self.is_terminal || !self.evaluator.occurrences(input).is_empty() {
+                return Err(e);
+            }
+
+            match self.piped()? {
+                "" => Err(e),
+                piped => self
+                    .evaluator
+                    .value
+                    .evaluate(&[piped][..])
+                    .map(|v| Value::new(Span::empty(), v.value)),
+            }
+        })
+    }
+}
+
+impl<E, R> ShortHelpable for StdinFallback<E, R>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => {
+                FlagHelpCollector::Single(fhc.with_modifier("stdin".to_string()))
+            }
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// WithChoices takes an evaluator E and a default value B that agrees with the
+/// return type of the Evaluator. This default is meant to wrap the enclosed
+/// evaluator, returning the A success with the default value for any
+/// evaluation that fails.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let input = ["hello", "--log-level", "info"];
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
+///     Flag::with_choices(
+///         "log-level", "l", "logging level",
+///         ["info".to_string(), "warn".to_string()],
+///         StringValue
+///     )
+///     .evaluate(&input[..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
+///     WithChoices::new(
+///         ["info".to_string(), "warn".to_string()],
+///         FlagWithValue::new("log-level", "l", "logging level", StringValue)
+///     )
+///     .evaluate(&input[..])
+/// );
+///
+/// assert!(
+///     WithChoices::new(
+///         ["error".to_string()],
+///         FlagWithValue::new("log-level", "l", "logging level", StringValue)
+///     )
+///     .evaluate(&input[..]).is_err()
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::default(), "debug".to_string())),
+///     WithDefault::new(
+///         "debug".to_string(),
+///         Optional::new(WithChoices::new(
+///             ["error".to_string()],
+///             FlagWithValue::new("log-level", "l", "logging level", StringValue)
+///         ))
+///     )
+///     .evaluate(&input[..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithChoices<B, E, const N: usize> {
+    choices: [B; N],
+    evaluator: E,
+    help_limit: Option<usize>,
+}
+
+impl<B, E, const N: usize> IsFlag for WithChoices<B, E, N> {}
+
+#[allow(deprecated)]
+impl<B, E, const N: usize> Defaultable for WithChoices<B, E, N> where E: Defaultable {}
+
+impl<B, E, const N: usize> WithChoices<B, E, N> {
+    /// Instantiates a new choices wrapper on an evaluator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// WithChoices::new(
+    ///     ["foo".to_string(), "bar".to_string()],
+    ///     Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue))
+    /// );
+    /// ```
+    pub fn new(choices: [B; N], evaluator: E) -> Self {
+        Self {
+            choices,
+            evaluator,
+            help_limit: None,
+        }
+    }
+
+    /// Returns the choices wrapper with help listing only the first `limit`
+    /// choices, followed by a count of those omitted. Evaluation still
+    /// considers every choice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let flag = WithChoices::new(
+    ///     [1u8, 2, 3, 4],
+    ///     FlagWithValue::new("level", "l", "A level.", U8Value),
+    /// )
+    /// .choices_help_limit(2);
+    ///
+    /// assert!(flag.short_help().to_string().contains("(choices: [1, 2, ... (+2 more)])"));
+    /// assert!(flag.evaluate(&["hello", "-l", "4"][..]).is_ok());
+    /// ```
+    pub fn choices_help_limit(mut self, limit: usize) -> Self {
+        self.help_limit = Some(limit);
+        self
+    }
+}
+
+impl<B, E, const N: usize> WithChoices<B, E, N>
+where
+    B: PartialEq,
+{
+    /// Returns the choices wrapped as an optional flag with the provided
+    /// default. Unlike chaining `optional().with_default()`, the default is
+    /// debug-asserted to be one of the valid choices at construction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::empty(), "warn".to_string())),
+    ///     WithChoices::new(
+    ///         ["info".to_string(), "warn".to_string()],
+    ///         FlagWithValue::new("log-level", "l", "logging level", StringValue)
+    ///     )
+    ///     .with_default_checked("warn")
+    ///     .evaluate(&["hello"][..])
+    /// );
+    /// ```
+    pub fn with_default_checked<D>(self, default: D) -> WithDefault<B, Optional<Self>>
+    where
+        D: Into<B>,
+    {
+        let default = Into::<B>::into(default);
+        debug_assert!(
+            self.choices.iter().any(|choice| choice == &default),
+            "default value is not one of the valid choices"
+        );
+
+        WithDefault::new(default, Optional::new(self))
+    }
+}
+
+impl<E, const N: usize> WithChoices<String, E, N> {
+    /// Returns the choices wrapped in a `PrefixChoices`, accepting an
+    /// unambiguous prefix of a choice in place of the full choice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
+    ///     WithChoices::new(
+    ///         ["info".to_string(), "warn".to_string()],
+    ///         FlagWithValue::new("log-level", "l", "logging level", StringValue)
+    ///     )
+    ///     .allow_prefix()
+    ///     .evaluate(&["hello", "-l", "inf"][..])
+    /// );
+    /// ```
+    pub fn allow_prefix(self) -> PrefixChoices<E, N> {
+        PrefixChoices::new(self)
+    }
+}
+
+impl<'a, E, A, B, const N: usize> Evaluatable<'a, A, B> for WithChoices<B, E, N>
+where
+    A: 'a,
+    B: Clone + PartialEq,
+    E: Evaluatable<'a, A, B>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
+        self.evaluator.evaluate(input).and_then(|op| {
+            self.choices
+                .iter()
+                .any(|choice| choice == &op.value)
+                .then_some(op)
+                .ok_or(CliError::ValueEvaluation)
+        })
+    }
+}
+
+impl<B, E, const N: usize> ShortHelpable for WithChoices<B, E, N>
+where
+    B: Clone + std::fmt::Debug,
+    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => match self.help_limit {
+                Some(limit) if limit < N => {
+                    let listed = self.choices[..limit]
+                        .iter()
+                        .map(|choice| format!("{:?}, ", choice))
+                        .collect::<String>();
+
+                    FlagHelpCollector::Single(fhc.with_modifier(format!(
+                        "choices: [{}... (+{} more)]",
+                        listed,
+                        N - limit
+                    )))
+                }
+                _ => FlagHelpCollector::Single(
+                    fhc.with_modifier(format!("choices: {:?}", self.choices)),
+                ),
+            },
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// PrefixChoices wraps a `WithChoices` of strings, expanding an input that is
+/// a prefix of exactly one choice to that choice, such as `inf` to `info`, in
+/// the way a flag may be abbreviated. An exact match is always accepted, even
+/// when it is also a prefix of another choice, while an ambiguous or
+/// unmatched prefix fails evaluation with an `InvalidChoice` error.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = WithChoices::new(
+///     ["info".to_string(), "warn".to_string(), "warning".to_string()],
+///     FlagWithValue::new("log-level", "l", "logging level", StringValue),
+/// )
+/// .allow_prefix();
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), "warn".to_string())),
+///     flag.evaluate(&["hello", "-l", "warn"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::InvalidChoice("wa".to_string())),
+///     flag.evaluate(&["hello", "-l", "wa"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrefixChoices<E, const N: usize> {
+    choices: WithChoices<String, E, N>,
+}
+
+impl<E, const N: usize> IsFlag for PrefixChoices<E, N> {}
+
+impl<E, const N: usize> Defaultable for PrefixChoices<E, N> where E: Defaultable {}
+
+impl<E, const N: usize> PrefixChoices<E, N> {
+    /// Instantiates a new prefix matching wrapper on a choices wrapper.
+    pub fn new(choices: WithChoices<String, E, N>) -> Self {
+        Self { choices }
+    }
+}
+
+impl<'a, E, A, const N: usize> Evaluatable<'a, A, String> for PrefixChoices<E, N>
+where
+    A: 'a,
+    E: Evaluatable<'a, A, String>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, String> {
+        self.choices
+            .evaluator
+            .evaluate(input)
+            .and_then(|Value { span, value }| {
+                let choices = &self.choices.choices;
+                if choices.contains(&value) {
+                    return Ok(Value::new(span, value));
+                }
+
+                let mut candidates = choices.iter().filter(|choice| choice.starts_with(&value));
+                match (candidates.next(), candidates.next()) {
+                    (Some(choice), None) => Ok(Value::new(span, choice.clone())),
+                    _ => Err(CliError::InvalidChoice(value)),
+                }
+            })
+    }
+}
+
+impl<E, const N: usize> ShortHelpable for PrefixChoices<E, N>
+where
+    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.choices.short_help()
+    }
+}
+
+/// WithMultipleChoices takes an evaluator E that yields a comma-delimited list
+/// of choice names and maps each name to its corresponding value, via a table
+/// of name and value pairs, returning the values in the order they were
+/// passed. Any name not found in the table fails evaluation with an
+/// `InvalidChoice` error.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Feature {
+///     Fast,
+///     Safe,
+///     Small,
+/// }
+///
+/// let flag = WithMultipleChoices::new(
+///     [("fast", Feature::Fast), ("safe", Feature::Safe), ("small", Feature::Small)],
+///     FlagWithValue::new("features", "f", "features to enable.", StringValue),
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), vec![Feature::Fast, Feature::Safe])),
+///     flag.evaluate(&["hello", "--features", "fast,safe"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::InvalidChoice("bogus".to_string())),
+///     flag.evaluate(&["hello", "--features", "fast,bogus"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithMultipleChoices<T, E, const N: usize> {
+    choices: [(&'static str, T); N],
+    evaluator: E,
+}
+
+impl<T, E, const N: usize> IsFlag for WithMultipleChoices<T, E, N> {}
+
+impl<T, E, const N: usize> Defaultable for WithMultipleChoices<T, E, N> where E: Defaultable {}
+
+impl<T, E, const N: usize> WithMultipleChoices<T, E, N> {
+    /// Instantiates a new multiple choice wrapper on an evaluator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// WithMultipleChoices::new(
+    ///     [("fast", 0), ("safe", 1)],
+    ///     FlagWithValue::new("features", "f", "features to enable.", StringValue)
+    /// );
+    /// ```
+    pub fn new(choices: [(&'static str, T); N], evaluator: E) -> Self {
+        Self { choices, evaluator }
+    }
+}
+
+impl<'a, E, A, T, const N: usize> Evaluatable<'a, A, Vec<T>> for WithMultipleChoices<T, E, N>
+where
+    A: 'a,
+    T: Clone,
+    E: Evaluatable<'a, A, String>,
+{
+    fn evaluate(&self, input: A) -> EvaluateResult<'a, Vec<T>> {
+        self.evaluator
+            .evaluate(input)
+            .and_then(|Value { span, value }| {
+                value
+                    .split(',')
+                    .map(|name| {
+                        self.choices
+                            .iter()
+                            .find(|(choice, _)| *choice == name)
+                            .map(|(_, choice_value)| choice_value.clone())
+                            .ok_or_else(|| CliError::InvalidChoice(name.to_string()))
+                    })
+                    .collect::<Result<Vec<T>, CliError>>()
+                    .map(|values| Value::new(span, values))
+            })
+    }
+}
+
+impl<T, E, const N: usize> ShortHelpable for WithMultipleChoices<T, E, N>
+where
+    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        let names: Vec<&'static str> = self.choices.iter().map(|(name, _)| *name).collect();
+
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => FlagHelpCollector::Single(
+                fhc.with_modifier(format!("multiple choices: {:?}", names)),
+            ),
+            // this case should never be hit as joined is not defaultable
+            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
+        }
+    }
+}
+
+/// ExpectStringValue represents a terminal flag type, returning the next string value passed.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
+///    ExpectStringValue::new("name", "n", "A name.").evaluate(&["hello", "--name", "foo"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
+///     ExpectStringValue::new("name", "n", "A name.").evaluate(&["hello", "-n", "foo"][..])
+/// );
+/// ```
+#[deprecated]
+#[derive(Debug)]
+pub struct ExpectStringValue {
+    inner: FlagWithValue<StringValue>,
+}
+
+#[allow(deprecated)]
+impl IsFlag for ExpectStringValue {}
+
+#[allow(deprecated)]
+impl ExpectStringValue {
+    /// Instantiates a new instance of ExpectStringValue with a given flag name,
+    /// shortcode and description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// ExpectStringValue::new("name", "n", "A name.");
+    /// ```
+    #[allow(dead_code)]
+    pub fn new(name: &'static str, short_code: &'static str, description: &'static str) -> Self {
+        Self {
+            inner: FlagWithValue::new(name, short_code, description, StringValue),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl Defaultable for ExpectStringValue {}
+
+#[allow(deprecated)]
+impl<'a> Evaluatable<'a, &'a [&'a str], String> for ExpectStringValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
+        self.inner.evaluate(input)
+    }
+}
+
+#[allow(deprecated)]
+impl ShortHelpable for ExpectStringValue {
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.inner.short_help()
+    }
+}
+
+/// StoreTrue represents a terminal flag type, returning a boolean set to true if set.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::from_range(1..2), true)),
+///    StoreTrue::new("debug", "d", "Run in debug mode.").evaluate(&["hello", "--debug"][..])
+/// );
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::from_range(1..2), true)),
+///    StoreTrue::new("debug", "d", "Run in debug mode.").evaluate(&["hello", "-d"][..])
+/// );
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::empty(), false)),
+///    WithDefault::new(
+///        false,
+///        Optional::new(StoreTrue::new("debug", "d", "Run in debug mode."))
+///    )
+///    .evaluate(&["hello"][..])
+/// );
+/// ```
+#[deprecated]
+#[derive(Debug)]
+pub struct StoreTrue {
+    inner: FlagWithValue<ValueOnMatch<bool>>,
+}
+
+#[allow(deprecated)]
+impl IsFlag for StoreTrue {}
+
+#[allow(deprecated)]
+impl Defaultable for StoreTrue {}
+
+#[allow(deprecated)]
+impl StoreTrue {
+    /// Instantiates a new instance of StoreTrue with a given flag name,
+    /// shortcode and description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// StoreTrue::new("debug", "d", "Run a command in debug mode.");
+    /// ```
+    #[allow(dead_code)]
+    pub fn new(name: &'static str, short_code: &'static str, description: &'static str) -> Self {
+        Self {
+            inner: FlagWithValue::new(name, short_code, description, ValueOnMatch::new(true)),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'a> Evaluatable<'a, &'a [&'a str], bool> for StoreTrue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, bool> {
+        self.inner.evaluate(input)
+    }
+}
+
+#[allow(deprecated)]
+impl ShortHelpable for StoreTrue {
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.inner.short_help()
+    }
+}
+
+/// StoreFalse represents a terminal flag type, returning a boolean set to false if set.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), false)),
+///     StoreFalse::new("no-wait", "n", "don't wait for a response.").evaluate(&["hello", "--no-wait"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), false)),
+///     StoreFalse::new("no-wait", "n", "don't wait for a response.").evaluate(&["hello", "-n"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), true)),
+///     WithDefault::new(
+///         true,
+///         Optional::new(StoreFalse::new("no-wait", "n", "don't wait for a response."))
+///     )
+///     .evaluate(&["hello"][..])
+/// );
+/// ```
+#[deprecated]
+#[derive(Debug)]
+pub struct StoreFalse {
+    inner: FlagWithValue<ValueOnMatch<bool>>,
+}
+
+#[allow(deprecated)]
+impl IsFlag for StoreFalse {}
+
+#[allow(deprecated)]
+impl Defaultable for StoreFalse {}
+
+#[allow(deprecated)]
+impl StoreFalse {
+    /// Instantiates a new instance of StoreFalse with a given flag name,
+    /// shortcode and description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// StoreFalse::new("no-wait", "n", "don't wait for a response.");
+    /// ```
+    #[allow(dead_code)]
+    pub fn new(name: &'static str, short_code: &'static str, description: &'static str) -> Self {
+        Self {
+            inner: FlagWithValue::new(name, short_code, description, ValueOnMatch::new(false)),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'a> Evaluatable<'a, &'a [&'a str], bool> for StoreFalse {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, bool> {
+        self.inner.evaluate(input)
+    }
+}
+
+#[allow(deprecated)]
+impl ShortHelpable for StoreFalse {
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.inner.short_help()
+    }
+}
+
+// Integer types
+
+macro_rules! generate_integer_evaluators {
+    ($($name:tt, $value_name:tt, $primitive:ty,)*) => {
+        $(
+        #[deprecated]
+        #[derive(Debug)]
+        pub struct $name {
+            inner: FlagWithValue<$value_name>,
+        }
+
+        #[allow(deprecated)]
+        impl IsFlag for $name {}
+
+        #[allow(deprecated)]
+        impl Defaultable for $name {}
+
+        #[allow(deprecated)]
+        impl $name {
+            #[allow(dead_code)]
+            pub fn new(
+                name: &'static str,
+                short_code: &'static str,
+                description: &'static str,
+            ) -> Self {
+                Self {
+                    inner:FlagWithValue::new(name, short_code, description, $value_name),
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'a> Evaluatable<'a, &'a [&'a str], $primitive> for $name {
+            fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, $primitive> {
+                self.inner.evaluate(input)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ShortHelpable for $name {
+            type Output = FlagHelpCollector;
+
+            fn short_help(&self) -> Self::Output {
+                self.inner.short_help()
+            }
+        }
+
+        /// Represents a Numeric argument
+        #[derive(Debug, Clone, Copy)]
+        pub struct $value_name;
+
+        impl<'a> PositionalArgumentValue<'a, &'a [&'a str], $primitive> for $value_name {
+            fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, $primitive> {
+                self.evaluate(&input[pos..])
+            }
+        }
+
+        impl<'a> Evaluatable<'a, &'a [&'a str], $primitive> for $value_name {
+            fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, $primitive> {
+                let result = input
+                    .get(0)
+                    .and_then(|&v| v.parse::<$primitive>().ok())
+                    .ok_or(CliError::ValueEvaluation);
+
+               result.map(|matching_int| Value::new(Span::from_range(0..1), matching_int))
+            }
+        }
+
+        impl<'a> TerminalEvaluatable<'a, &'a [&'a str], $primitive> for $value_name {}
+
+        impl TakesValue for $value_name {}
+    )*
+    };
+}
+
+#[rustfmt::skip]
+generate_integer_evaluators!(
+    ExpectI8Value, I8Value, i8,
+    ExpectI16Value, I16Value, i16,
+    ExpectI32Value, I32Value, i32,
+    ExpectI64Value, I64Value, i64,
+    ExpectU8Value, U8Value, u8,
+    ExpectU16Value, U16Value, u16,
+    ExpectU32Value, U32Value, u32,
+    ExpectU64Value, U64Value, u64,
+    ExpectI128Value, I128Value, i128,
+    ExpectU128Value, U128Value, u128,
+);
+
+macro_rules! generate_nonzero_evaluators {
+    ($($value_name:tt, $nonzero:ty,)*) => {
+        $(
+        /// Represents a non-zero numeric argument, rejecting `0`.
+        #[derive(Debug, Clone, Copy)]
+        pub struct $value_name;
+
+        impl<'a> PositionalArgumentValue<'a, &'a [&'a str], $nonzero> for $value_name {
+            fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, $nonzero> {
+                self.evaluate(&input[pos..])
+            }
+        }
+
+        impl<'a> Evaluatable<'a, &'a [&'a str], $nonzero> for $value_name {
+            fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, $nonzero> {
+                input
+                    .first()
+                    .and_then(|&v| v.parse::<$nonzero>().ok())
+                    .map(|matching_int| Value::new(Span::from_range(0..1), matching_int))
+                    .ok_or(CliError::ValueEvaluation)
+            }
+        }
+
+        impl<'a> TerminalEvaluatable<'a, &'a [&'a str], $nonzero> for $value_name {}
+
+        impl TakesValue for $value_name {}
+    )*
+    };
+}
+
+#[rustfmt::skip]
+generate_nonzero_evaluators!(
+    NonZeroI8Value, std::num::NonZeroI8,
+    NonZeroI16Value, std::num::NonZeroI16,
+    NonZeroI32Value, std::num::NonZeroI32,
+    NonZeroI64Value, std::num::NonZeroI64,
+    NonZeroI128Value, std::num::NonZeroI128,
+    NonZeroU8Value, std::num::NonZeroU8,
+    NonZeroU16Value, std::num::NonZeroU16,
+    NonZeroU32Value, std::num::NonZeroU32,
+    NonZeroU64Value, std::num::NonZeroU64,
+    NonZeroU128Value, std::num::NonZeroU128,
+);
+
+/// FromStrRadix abstracts over the `from_str_radix` constructors of the
+/// integer primitives.
+pub trait FromStrRadix: Sized {
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, std::num::ParseIntError>;
+}
+
+macro_rules! impl_from_str_radix {
+    ($($primitive:ty,)*) => {
+        $(
+        impl FromStrRadix for $primitive {
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, std::num::ParseIntError> {
+                <$primitive>::from_str_radix(src, radix)
+            }
+        }
+        )*
+    };
+}
+
+impl_from_str_radix!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128,);
+
+/// Represents an integer argument interpreted in either a fixed base, such
+/// as file modes in octal, with the argument carrying no base prefix, or in
+/// the base denoted by a `0x`, `0o` or `0b` prefix.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::from_range(1..3), 0o755u32)),
+///    FlagWithValue::new("mode", "m", "A file mode.", RadixValue::<u32>::new(8))
+///        .evaluate(&["hello", "--mode", "755"][..])
+/// );
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::from_range(1..3), 0xffu32)),
+///    FlagWithValue::new("mask", "m", "A mask.", RadixValue::<u32>::prefixed())
+///        .evaluate(&["hello", "--mask", "0xFF"][..])
+/// );
+/// ```
+#[derive(Debug)]
+pub struct RadixValue<T> {
+    /// The fixed base, or `None` to detect it from a prefix.
+    radix: Option<u32>,
+    _type: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> RadixValue<T> {
+    /// Instantiates a new instance of RadixValue parsing in the given base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range from 2 to 36.
+    pub fn new(radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2 to 36, got {}",
+            radix
+        );
+
+        Self {
+            radix: Some(radix),
+            _type: std::marker::PhantomData,
+        }
+    }
+
+    /// Instantiates a new instance of RadixValue detecting the base from a
+    /// `0x`, `0o` or `0b` prefix, following any sign, and otherwise parsing
+    /// in base 10.
+    pub fn prefixed() -> Self {
+        Self {
+            radix: None,
+            _type: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> RadixValue<T>
+where
+    T: FromStrRadix,
+{
+    /// Parses an argument with a base denoted by its prefix.
+    fn parse_prefixed(arg: &str) -> Option<T> {
+        let (sign, unsigned) = match arg.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", arg.strip_prefix('+').unwrap_or(arg)),
+        };
+
+        let prefix = unsigned.get(..2).map(|prefix| prefix.to_ascii_lowercase());
+        let (radix, digits) = match prefix.as_deref() {
+            Some("0x") => (16, &unsigned[2..]),
+            Some("0o") => (8, &unsigned[2..]),
+            Some("0b") => (2, &unsigned[2..]),
+            _ => (10, unsigned),
+        };
+
+        // a sign is only accepted ahead of the prefix.
+        if digits.starts_with(['+', '-']) {
+            return None;
+        }
+
+        T::from_str_radix(&format!("{}{}", sign, digits), radix).ok()
+    }
+}
+
+impl<T> Clone for RadixValue<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RadixValue<T> {}
+
+impl<'a, T> PositionalArgumentValue<'a, &'a [&'a str], T> for RadixValue<T>
+where
+    T: FromStrRadix,
+{
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, T> {
+        self.evaluate(&input[pos..])
+    }
+}
+
+impl<'a, T> Evaluatable<'a, &'a [&'a str], T> for RadixValue<T>
+where
+    T: FromStrRadix,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, T> {
+        input
+            .first()
+            .and_then(|v| match self.radix {
+                Some(radix) => T::from_str_radix(v, radix).ok(),
+                None => Self::parse_prefixed(v),
+            })
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
+
+impl<'a, T> TerminalEvaluatable<'a, &'a [&'a str], T> for RadixValue<T> where T: FromStrRadix {}
+
+impl<T> TakesValue for RadixValue<T> {}
+
+// Floating-point types
+
+macro_rules! generate_float_evaluators {
+    ($($value_name:tt, $primitive:ty,)*) => {
+        $(
+        /// Represents a floating-point argument, as parsed by `str::parse`.
+        /// As such, special values like `inf` and `NaN` are accepted.
+        #[derive(Debug, Clone, Copy)]
+        pub struct $value_name;
+
+        impl<'a> PositionalArgumentValue<'a, &'a [&'a str], $primitive> for $value_name {
+            fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, $primitive> {
+                self.evaluate(&input[pos..])
+            }
+        }
+
+        impl<'a> Evaluatable<'a, &'a [&'a str], $primitive> for $value_name {
+            fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, $primitive> {
+                input
+                    .first()
+                    .and_then(|&v| v.parse::<$primitive>().ok())
+                    .map(|matching_float| Value::new(Span::from_range(0..1), matching_float))
+                    .ok_or(CliError::ValueEvaluation)
+            }
+        }
+
+        impl<'a> TerminalEvaluatable<'a, &'a [&'a str], $primitive> for $value_name {}
+
+        impl TakesValue for $value_name {}
+    )*
+    };
+}
+
+#[rustfmt::skip]
+generate_float_evaluators!(
+    F32Value, f32,
+    F64Value, f64,
+);
+
+/// Represents a boolean argument, parsing `true`/`false`, `1`/`0` and
+/// `yes`/`no` case-insensitively. Unlike `store_true` and `store_false`, the
+/// value is taken from the argument following the flag.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::from_range(1..3), false)),
+///    FlagWithValue::new("enabled", "e", "Enable it.", BoolValue)
+///        .evaluate(&["hello", "--enabled", "No"][..])
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BoolValue;
+
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], bool> for BoolValue {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, bool> {
+        self.evaluate(&input[pos..])
+    }
+}
+
+impl<'a> Evaluatable<'a, &'a [&'a str], bool> for BoolValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, bool> {
+        input
+            .first()
+            .and_then(|v| match v.to_lowercase().as_str() {
+                "true" | "1" | "yes" => Some(true),
+                "false" | "0" | "no" => Some(false),
+                _ => None,
+            })
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
+
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], bool> for BoolValue {}
+
+impl TakesValue for BoolValue {}
+
+/// Defines a marker trait for types that can be opened via the WithOpen
+/// evaluator.
+pub trait Openable {}
+
+/// WithOpen represents an evaluator that can take a filepath as parsed by
+/// `ExpectFilePath` and return an opened file handler for said path. Function
+/// this works much like `WithDefault` in that it is an optional augmentation
+/// for an existing evaluator.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+/// use std::fs::File;
+///
+/// assert!(
+///     WithOpen::new(
+///         ExpectFilePath::new("file", "f", "A file to open", true, false, true)
+///     ).evaluate(&["hello", "--file", "/etc/hostname"][..]).is_ok()
+/// );
+///
+/// assert!(
+///     WithOpen::new(
+///         ExpectFilePath::new("file", "f", "A file to open", true, false, true)
+///     ).evaluate(&["hello", "-f", "/etc/hostname"][..]).is_ok()
+/// );
+///
+/// assert!(
+///     WithOpen::new(
+///         ExpectFilePath::new("file", "f", "A file to open", true, false, true)
+///     ).evaluate(&["hello"][..]).is_err()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithOpen<E> {
+    evaluator: E,
+}
+
+impl<E> IsFlag for WithOpen<E> {}
+
+impl<E> WithOpen<E> {
+    /// Instantiates a new of WithOpen for a given type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// WithOpen::new(
+    ///     ExpectFilePath::new("file", "f", "A file to open", true, false, true)
+    /// );
+    /// ```
+    pub fn new(evaluator: E) -> Self {
+        Self { evaluator }
+    }
+}
+
+impl<'a, E> Evaluatable<'a, &'a [&'a str], std::fs::File> for WithOpen<E>
+where
+    E: Evaluatable<'a, &'a [&'a str], String> + Openable,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, std::fs::File> {
+        self.evaluator.evaluate(input).and_then(|vfp| {
+            std::fs::File::open(&vfp.value)
+                .map_err(|e| CliError::Io(format!("unable to open file evaluator: {}", e)))
+                .map(|f| Value::new(vfp.span, f))
+        })
+    }
+}
+
+impl<E> ShortHelpable for WithOpen<E>
+where
+    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
+{
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        match self.evaluator.short_help() {
+            FlagHelpCollector::Single(fhc) => {
+                FlagHelpCollector::Single(fhc.with_modifier("will_open".to_string()))
+            }
+            // this case should never be hit as joined is not defaultable
+            fhcj => fhcj,
+        }
+    }
+}
+
+/// ExpectFilePath represents a terminal flag type, that parses and validates a
+/// file exists in a path. Returning the file path as a String.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), "/etc/hostname".to_string())),
+///     ExpectFilePath::new("file", "f", "A filepath to read", true, false, true).evaluate(&["hello", "--file", "/etc/hostname"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), "/etc/hostname".to_string())),
+///     WithDefault::new(
+///         "/etc/hostname".to_string(),
+///         Optional::new(ExpectFilePath::new("file", "f", "A filepath to read", true, false, true))
+///     )
+///     .evaluate(&["hello"][..])
+/// );
+/// ```
+#[deprecated]
+#[derive(Debug)]
+pub struct ExpectFilePath {
+    inner: FlagWithValue<FileValue>,
+}
+
+#[allow(deprecated)]
+impl IsFlag for ExpectFilePath {}
+
+#[allow(deprecated)]
+impl ExpectFilePath {
+    /// Instantiates a new instance of ExpectFilePath with a given flag name,
+    /// shortcode and description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// ExpectFilePath::new("file", "f", "A file name.", true, false, true);
+    /// ```
+    #[allow(dead_code)]
+    pub fn new(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+        readable: bool,
+        writable: bool,
+        exists: bool,
+    ) -> Self {
+        Self {
+            inner: FlagWithValue::new(
+                name,
+                short_code,
+                description,
+                FileValue::new(readable, writable, exists),
+            ),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl Openable for ExpectFilePath {}
+
+#[allow(deprecated)]
+impl Defaultable for ExpectFilePath {}
+
+#[allow(deprecated)]
+impl<'a> Evaluatable<'a, &'a [&'a str], String> for ExpectFilePath {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
+        self.inner.evaluate(input)
+    }
+}
+
+#[allow(deprecated)]
+impl ShortHelpable for ExpectFilePath {
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        self.inner.short_help()
+    }
+}
+
+// Unit type
+
+// This implementation exists mostly for cases where a Cmd, or SubCommands
+// object has no flags associated with it.
+impl<'a> Evaluatable<'a, &'a [&'a str], ()> for () {
+    fn evaluate(&self, _: &'a [&'a str]) -> EvaluateResult<'a, ()> {
+        Ok(Value::new(Span::from_range(0..1), ()))
+    }
+}
+
+/// FlagWithValue represents a flag evaluating the argument following it, or
+/// a value passed inline as `--name=value` or `-n=value`, with the enclosed
+/// value type. As an inline value is evaluated as an argument of its own, the
+/// value type must be implemented for any input lifetime, as every value type
+/// of this crate is.
+#[derive(Debug, Clone)]
+pub struct FlagWithValue<V> {
+    name: &'static str,
+    short_code: &'static str,
+    description: &'static str,
+    value: V,
+    /// Rejects a consumed value that looks like a flag.
+    no_flaglike_values: bool,
+    /// Replaces the default message when the flag fails to evaluate.
+    error_message: Option<&'static str>,
+}
+
+impl<V> IsFlag for FlagWithValue<V> {}
+
+impl<V> FlagWithValue<V> {
+    /// Instantiates a new instance of FlagWithValue with a given flag name,
+    /// shortcode and description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// FlagWithValue::new("name", "n", "A name.", StringValue);
+    /// ```
+    #[allow(dead_code)]
+    pub fn new(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+        value: V,
+    ) -> Self {
+        Self {
+            name,
+            short_code,
+            description,
+            value,
+            no_flaglike_values: false,
+            error_message: None,
+        }
+    }
+
+    /// Returns the flag with a custom message replacing the default when the
+    /// flag fails to evaluate, surfaced as a `CliError::FlagEvaluationMessage`
+    /// when the flag is absent, or carried by a `CliError::InvalidValue` when
+    /// its value is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let flag = Flag::expect_u16("port", "p", "A port.")
+    ///     .error_message("provide a valid port with --port");
+    ///
+    /// assert_eq!(
+    ///     Err(CliError::InvalidValue {
+    ///         flag: "port".to_string(),
+    ///         value: "http".to_string(),
+    ///         message: Some("provide a valid port with --port".to_string()),
+    ///     }),
+    ///     flag.evaluate(&["test", "--port", "http"][..])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Err(CliError::FlagEvaluationMessage {
+    ///         flag: "port".to_string(),
+    ///         message: "provide a valid port with --port".to_string(),
+    ///     }),
+    ///     flag.evaluate(&["test"][..])
+    /// );
+    /// ```
+    pub fn error_message(mut self, message: &'static str) -> Self {
+        self.error_message = Some(message);
+        self
+    }
+
+    /// Returns the error for the flag failing to evaluate, carrying the
+    /// custom error message if set.
+    fn evaluation_error(&self) -> CliError {
+        match self.error_message {
+            Some(message) => CliError::FlagEvaluationMessage {
+                flag: self.name.to_string(),
+                message: message.to_string(),
+            },
+            None => CliError::FlagEvaluation(self.name.to_string()),
+        }
+    }
 
-/// Evaluatable provides methods for parsing and evaluating input values into a
-/// corresponding concrete type.
-pub trait Evaluatable<'a, A, B> {
-    fn evaluate(&self, input: A) -> EvaluateResult<'a, B>;
+    /// Returns the error for a value passed to the flag failing to evaluate,
+    /// carrying the custom error message if set.
+    fn value_error(&self, value: &str) -> CliError {
+        CliError::InvalidValue {
+            flag: self.name.to_string(),
+            value: value.to_string(),
+            message: self.error_message.map(str::to_string),
+        }
+    }
 
-    fn join<E, C>(self, evaluator2: E) -> BoxedEvaluator<'a, A, (B, C)>
+    /// Returns the error for a value passed to the flag being rejected by its
+    /// value type for the given reason, preferring the custom error message
+    /// if set.
+    fn value_error_with_reason(&self, value: &str, reason: String) -> CliError {
+        CliError::InvalidValue {
+            flag: self.name.to_string(),
+            value: value.to_string(),
+            message: Some(self.error_message.map_or(reason, str::to_string)),
+        }
+    }
+
+    /// Returns the flag rejecting values that look like flags. By default a
+    /// value type like `StringValue` consumes whatever token follows the flag,
+    /// so `--name --debug` would evaluate name to `--debug`. Once set, a
+    /// consumed value that begins with a `-`, other than a standalone `-`,
+    /// fails evaluation with a `CliError::MissingValue`. As this includes
+    /// negative numbers, it is best suited to flags expecting names or paths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let flag = Flag::expect_string("name", "n", "A name.").no_flaglike_values();
+    ///
+    /// assert_eq!(
+    ///     Err(CliError::MissingValue("name".to_string())),
+    ///     flag.evaluate(&["test", "--name", "--debug"][..])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Ok(Value::new(Span::from_range(1..3), "-".to_string())),
+    ///     flag.evaluate(&["test", "--name", "-"][..])
+    /// );
+    /// ```
+    pub fn no_flaglike_values(mut self) -> Self {
+        self.no_flaglike_values = true;
+        self
+    }
+
+    /// Returns the index of every token in the input that matches either the
+    /// long (`--name`) or short (`-n`) form of the flag, including those
+    /// passing an inline value like `--name=value`, in input order. This
+    /// provides the basis for aggregating repeatable flags across both forms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// let flag = Flag::store_true("verbose", "v", "verbose output.");
+    ///
+    /// assert_eq!(
+    ///     vec![1, 3],
+    ///     flag.occurrences(&["test", "--verbose", "a", "-v"][..])
+    /// );
+    /// assert!(flag.occurrences(&["test", "a"][..]).is_empty());
+    /// ```
+    pub fn occurrences(&self, input: &[&str]) -> Vec<usize> {
+        input
+            .iter()
+            .enumerate()
+            .filter(|(_, &arg)| self.matches(arg) || self.inline_value(arg).is_some())
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Returns the flag wrapped in a `WithEnv`, falling back to the named
+    /// environment variable when the flag isn't passed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Flag::expect_string("name", "n", "A name.").with_env("NAME");
+    /// ```
+    pub fn with_env(self, var: &'static str) -> WithEnv<Self> {
+        WithEnv::new(var, self)
+    }
+
+    /// Returns the flag wrapped in a `MaxOccurrences`, failing evaluation
+    /// when the flag is passed more than `max` times.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Flag::store_true("verbose", "v", "verbose output.").max_occurrences(3);
+    /// ```
+    pub fn max_occurrences(self, max: usize) -> MaxOccurrences<Self> {
+        MaxOccurrences::new(max, self)
+    }
+
+    /// Returns the flag wrapped in a `Repeated`, collecting the value of
+    /// every occurrence of the flag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// Flag::expect_string("include", "i", "A path to include.").repeated();
+    /// ```
+    pub fn repeated(self) -> Repeated<Self> {
+        Repeated::new(self)
+    }
+
+    /// Returns true if the passed argument matches either the long or short
+    /// form of the flag. A standalone `-`, conventionally denoting stdin, is
+    /// never considered a flag, even for a flag without a short code.
+    fn matches(&self, arg: &str) -> bool {
+        // compared by prefix to avoid allocating each form for every argument.
+        arg != "-"
+            && (arg.strip_prefix("--") == Some(self.name)
+                || arg.strip_prefix('-') == Some(self.short_code))
+    }
+
+    /// Returns the value of an argument passed in the `--name=value` or
+    /// `-n=value` form, split on the first `=` so the value may itself
+    /// contain an `=`.
+    fn inline_value<'v>(&self, arg: &'v str) -> Option<&'v str> {
+        let (flag, value) = arg.split_once('=')?;
+
+        let long = flag.strip_prefix("--") == Some(self.name);
+        let short = !self.short_code.is_empty() && flag.strip_prefix('-') == Some(self.short_code);
+        if long || short {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Evaluates a value passed inline with the flag at `idx`, returning a
+    /// span covering only the flag's own token.
+    fn evaluate_inline<B>(&self, idx: usize, value_arg: &str) -> Result<Value<B>, CliError>
     where
-        Self: Sized + BoxedEvaluatable<'a, A, B> + 'a,
-        E: BoxedEvaluatable<'a, A, C> + 'a,
-        A: Copy + 'a,
+        V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
     {
-        BoxedEvaluator::new(Join::<Self, E>::new(self, evaluator2))
+        let flaglike = value_arg.starts_with('-') && value_arg != "-";
+        if self.no_flaglike_values && flaglike {
+            return Err(CliError::MissingValue(self.name.to_string()));
+        }
+
+        let synthetic = [value_arg];
+        match self.value.evaluate_at(&synthetic[..], 0) {
+            // a value the evaluator didn't consume, like `--verbose=true` on
+            // a flag set by its presence alone, is rejected.
+            Ok(v) if !v.span.0.contains(&0) => Err(self.value_error(value_arg)),
+            Ok(v) => Ok(Value::new(Span::from_range(idx..idx + 1), v.value)),
+            Err(CliError::ValueEvaluation) => Err(self.value_error(value_arg)),
+            Err(CliError::ValueEvaluationMessage(reason)) => {
+                Err(self.value_error_with_reason(value_arg, reason))
+            }
+            // preserve descriptive errors from the value.
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<V> Defaultable for FlagWithValue<V> {}
+
+impl<V> Openable for FlagWithValue<V> where V: Openable {}
+
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], B> for FlagWithValue<V>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
+        input[..]
+            .iter()
+            .enumerate()
+            .find(|(_, &arg)| self.matches(arg) || self.inline_value(arg).is_some())
+            // Only need the index.
+            .map(|(idx, _)| idx)
+            .ok_or_else(|| self.evaluation_error())
+            .and_then(|idx| {
+                if let Some(value_arg) = self.inline_value(input[idx]) {
+                    return self.evaluate_inline(idx, value_arg);
+                }
+
+                self.value
+                    .evaluate_at(input, idx + 1)
+                    .map(|val| val.from_offset(idx + 1))
+                    .and_then(|v| {
+                        let value_arg = input.get(idx + 1).copied().unwrap_or_default();
+                        let flaglike = value_arg.starts_with('-') && value_arg != "-";
+
+                        if self.no_flaglike_values && flaglike && v.span.0.contains(&(idx + 1)) {
+                            Err(CliError::MissingValue(self.name.to_string()))
+                        } else {
+                            Ok(v)
+                        }
+                    })
+                    .map(|v| {
+                        let span = v.span;
+                        let adjusted = Span::from_range(idx..idx + 1).join(span);
+                        Value::new(adjusted, v.value)
+                    })
+                    .map_err(|e| match e {
+                        // the flag is the trailing argument with no value following it.
+                        CliError::ValueEvaluation if idx + 1 >= input.len() => {
+                            CliError::MissingValue(self.name.to_string())
+                        }
+                        CliError::ValueEvaluation => self.value_error(input[idx + 1]),
+                        CliError::ValueEvaluationMessage(reason) => {
+                            self.value_error_with_reason(input[idx + 1], reason)
+                        }
+                        // preserve descriptive errors from the value.
+                        e => e,
+                    })
+            })
+    }
+}
+
+impl<'a, V, B> ProvenanceEvaluatable<'a, &'a [&'a str], B> for FlagWithValue<V>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate_with_provenance(
+        &self,
+        input: &'a [&'a str],
+    ) -> EvaluateResult<'a, (B, Provenance)> {
+        self.evaluate(input)
+            .map(|v| v.map(|value| (value, Provenance::Flag)))
+    }
+}
+
+impl<V: TakesValue> ShortHelpable for FlagWithValue<V> {
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        let ctx = FlagHelpContext::new(self.name, self.short_code, self.description, Vec::new());
+
+        if !self.value.takes_value() {
+            FlagHelpCollector::Single(ctx.without_value())
+        } else {
+            FlagHelpCollector::Single(ctx)
+        }
+    }
+}
+
+/// OptionalValue represents a flag that may be passed on its own, or with an
+/// attached value using the `--name=value` form, evaluating to `None` or
+/// `Some(value)` respectively. To avoid ambiguity with a following positional
+/// argument, a value is only ever captured from the `=` form. A missing flag
+/// fails evaluation and can be handled by wrapping it in an `Optional`.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = OptionalValue::new("color", "c", "colorize output.", StringValue);
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), None)),
+///     flag.evaluate(&["hello", "--color", "file.txt"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), Some("always".to_string()))),
+///     flag.evaluate(&["hello", "--color=always"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), None)),
+///     flag.optional().evaluate(&["hello"][..]).map(|v| v.map(Option::flatten))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct OptionalValue<V> {
+    name: &'static str,
+    short_code: &'static str,
+    description: &'static str,
+    value: V,
+}
+
+impl<V> IsFlag for OptionalValue<V> {}
+
+impl<V> Defaultable for OptionalValue<V> {}
+
+impl<V> OptionalValue<V> {
+    /// Instantiates a new instance of OptionalValue with a given flag name,
+    /// shortcode, description and value type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// OptionalValue::new("color", "c", "colorize output.", StringValue);
+    /// ```
+    pub fn new(
+        name: &'static str,
+        short_code: &'static str,
+        description: &'static str,
+        value: V,
+    ) -> Self {
+        Self {
+            name,
+            short_code,
+            description,
+            value,
+        }
+    }
+}
+
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], Option<B>> for OptionalValue<V>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, Option<B>> {
+        let (long, short) = (format!("--{}", self.name), format!("-{}", self.short_code));
+
+        input
+            .iter()
+            .enumerate()
+            .find_map(|(idx, &arg)| {
+                if arg == "-" {
+                    // a standalone dash is always positional.
+                    None
+                } else if arg == long || arg == short {
+                    Some((idx, None))
+                } else {
+                    arg.strip_prefix(&long)
+                        .or_else(|| arg.strip_prefix(&short))
+                        .and_then(|remainder| remainder.strip_prefix('='))
+                        .map(|value| (idx, Some(value)))
+                }
+            })
+            .ok_or_else(|| CliError::FlagEvaluation(self.name.to_string()))
+            .and_then(|(idx, value)| {
+                let span = Span::from_range(idx..idx + 1);
+
+                match value {
+                    Some(v) => self
+                        .value
+                        .evaluate_at(&[v][..], 0)
+                        .map(|v| Value::new(span, Some(v.value))),
+                    None => Ok(Value::new(span, None)),
+                }
+            })
+    }
+}
+
+impl<V> ShortHelpable for OptionalValue<V> {
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        FlagHelpCollector::Single(FlagHelpContext::new(
+            self.name,
+            self.short_code,
+            self.description,
+            Vec::new(),
+        ))
+    }
+}
+
+/// PlusMinusFlag represents a toggle flag in the style of some legacy tools,
+/// where `+{short}` enables a setting and `-{short}` disables it, evaluating
+/// to `true` or `false` respectively. Unlike `store_true` and `store_false`,
+/// both states are expressed by the same flag. Only the short code is
+/// recognized, so it only applies to single-character short codes. A missing
+/// flag fails evaluation and can be handled by wrapping it in an `Optional`
+/// or `WithDefault`.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = PlusMinusFlag::new("verbose", "v", "Toggle verbose output.");
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), true)),
+///     flag.evaluate(&["hello", "+v"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), false)),
+///     flag.evaluate(&["hello", "-v"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlusMinusFlag {
+    name: &'static str,
+    short_code: &'static str,
+    description: &'static str,
+}
+
+impl IsFlag for PlusMinusFlag {}
+
+impl Defaultable for PlusMinusFlag {}
+
+impl PlusMinusFlag {
+    /// Instantiates a new instance of PlusMinusFlag with a given flag name,
+    /// single-character shortcode and description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scrap::prelude::v1::*;
+    /// use scrap::*;
+    ///
+    /// PlusMinusFlag::new("verbose", "v", "Toggle verbose output.");
+    /// ```
+    pub fn new(name: &'static str, short_code: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            short_code,
+            description,
+        }
     }
 }
 
-/// BoxedEvaluatable serves as a compound trait for the sake of combining the
-/// Helpable and Evaluator traits.
-pub trait BoxedEvaluatable<'a, A, B>:
-    Evaluatable<'a, A, B> + ShortHelpable<Output = FlagHelpCollector>
-{
+impl<'a> Evaluatable<'a, &'a [&'a str], bool> for PlusMinusFlag {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, bool> {
+        input
+            .iter()
+            .enumerate()
+            .find_map(|(idx, &arg)| {
+                if arg.strip_prefix('+') == Some(self.short_code) {
+                    Some((idx, true))
+                } else if arg.strip_prefix('-') == Some(self.short_code) {
+                    Some((idx, false))
+                } else {
+                    None
+                }
+            })
+            .map(|(idx, enabled)| Value::new(Span::from_range(idx..idx + 1), enabled))
+            .ok_or_else(|| CliError::FlagEvaluation(self.name.to_string()))
+    }
 }
 
-impl<'a, A, B, T> BoxedEvaluatable<'a, A, B> for T where
-    T: Evaluatable<'a, A, B> + ShortHelpable<Output = FlagHelpCollector> + 'a
-{
+impl ShortHelpable for PlusMinusFlag {
+    type Output = FlagHelpCollector;
+
+    fn short_help(&self) -> Self::Output {
+        FlagHelpCollector::Single(
+            FlagHelpContext::new(
+                self.name,
+                self.short_code,
+                self.description,
+                vec![format!(
+                    "+{} enables, -{} disables",
+                    self.short_code, self.short_code
+                )],
+            )
+            .without_value()
+            .with_plus_form(),
+        )
+    }
 }
 
-/// BoxedEvaluator provides a wrapper for Evaluatable types.
-pub struct BoxedEvaluator<'a, A, B> {
-    evaluator: Box<dyn BoxedEvaluatable<'a, A, B> + 'a>,
+/// CountValue represents a flag counting the number of times it's passed,
+/// such as raising a verbosity level with `-v -v -v`. Each long (`--name`)
+/// or short (`-n`) form counts once, while a token repeating only the short
+/// code, like `-vvv`, counts once per repetition. An absent flag evaluates to
+/// `0` rather than failing.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = CountValue::new("verbose", "v", "Raise the verbosity.");
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::new(vec![1, 3]), 4)),
+///     flag.evaluate(&["hello", "-vvv", "file", "--verbose"][..])
+/// );
+///
+/// assert_eq!(Ok(Value::new(Span::empty(), 0)), flag.evaluate(&["hello"][..]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CountValue {
+    name: &'static str,
+    short_code: &'static str,
+    description: &'static str,
 }
 
-impl<'a, A, B> IsFlag for BoxedEvaluator<'a, A, B> {}
+impl IsFlag for CountValue {}
 
-impl<'a, A, B> BoxedEvaluator<'a, A, B> {
-    pub fn new<E>(evaluator: E) -> Self
-    where
-        E: BoxedEvaluatable<'a, A, B> + 'a,
-    {
-        BoxedEvaluator {
-            evaluator: Box::new(evaluator),
+impl CountValue {
+    /// Instantiates a new instance of CountValue with a given flag name,
+    /// shortcode and description.
+    pub fn new(name: &'static str, short_code: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            short_code,
+            description,
+        }
+    }
+
+    /// Returns the number of occurrences of the flag in a single argument.
+    fn count(&self, arg: &str) -> usize {
+        if arg.strip_prefix("--") == Some(self.name) {
+            return 1;
+        }
+
+        match arg.strip_prefix('-') {
+            Some(cluster) if !self.short_code.is_empty() && !cluster.is_empty() => {
+                let repetitions = cluster.len() / self.short_code.len();
+                if cluster == self.short_code.repeat(repetitions) {
+                    repetitions
+                } else {
+                    0
+                }
+            }
+            _ => 0,
         }
     }
 }
 
-impl<'a, A, B> ShortHelpable for BoxedEvaluator<'a, A, B> {
+impl<'a> Evaluatable<'a, &'a [&'a str], usize> for CountValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, usize> {
+        Ok(input
+            .iter()
+            .enumerate()
+            .map(|(idx, arg)| (idx, self.count(arg)))
+            .filter(|&(_, count)| count > 0)
+            .fold(Value::new(Span::empty(), 0), |acc, (idx, count)| {
+                Value::new(
+                    acc.span.join(Span::from_range(idx..idx + 1)),
+                    acc.value + count,
+                )
+            }))
+    }
+}
+
+impl ShortHelpable for CountValue {
     type Output = FlagHelpCollector;
 
     fn short_help(&self) -> Self::Output {
-        self.evaluator.short_help()
+        FlagHelpCollector::Single(
+            FlagHelpContext::new(
+                self.name,
+                self.short_code,
+                self.description,
+                vec!["repeatable".to_string()],
+            )
+            .without_value(),
+        )
     }
 }
 
-impl<'a, A, B> Evaluatable<'a, A, B> for BoxedEvaluator<'a, A, B> {
-    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
-        self.evaluator.evaluate(input)
+/// PositionalArgumentValue Provides a value type for evaluating positionally.
+pub trait PositionalArgumentValue<'a, A, B>: Evaluatable<'a, A, B> {
+    fn evaluate_at(&self, input: A, pos: usize) -> EvaluateResult<'a, B>;
+}
+
+/// TakesValue reports whether a value type consumes an argument of its own,
+/// as opposed to being set by the presence of its flag alone, as with
+/// `ValueOnMatch`. This determines how a `FlagWithValue` is rendered in help.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert!(StringValue.takes_value());
+/// assert!(!ValueOnMatch::new(true).takes_value());
+/// ```
+pub trait TakesValue {
+    fn takes_value(&self) -> bool {
+        true
     }
 }
 
-impl<'a, F, A, B> Evaluatable<'a, A, B> for F
-where
-    A: 'a,
-    F: Fn(A) -> EvaluateResult<'a, B>,
-{
-    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
-        self(input)
+/// Represents a String argument
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
+///    FlagWithValue::new("name", "n", "A name.", StringValue).evaluate(&["hello", "--name", "foo"][..])
+/// );
+///
+/// assert_eq!(
+///    Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
+///    FlagWithValue::new("name", "n", "A name.", StringValue).evaluate(&["hello", "-n", "foo"][..])
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StringValue;
+
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], String> for StringValue {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, String> {
+        self.evaluate(&input[pos..])
     }
 }
 
-/// Join provides a wrapper type for flag `Evaluators` allowing two evaluators
-/// to be joined into a two return value. This join provides the basis for
-/// compound or multiple flag values being passed upstream to a `Cmd`.
+impl<'a> Evaluatable<'a, &'a [&'a str], String> for StringValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
+        input
+            .first()
+            .map(|v| Value::new(Span::from_range(0..1), v.to_string()))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
+
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], String> for StringValue {}
+
+impl TakesValue for StringValue {}
+
+/// Represents an argument of any type implementing `FromStr`, parsed from a
+/// single argument.
 ///
 /// # Example
 ///
@@ -1726,125 +8795,138 @@ where
 /// use scrap::prelude::v1::*;
 /// use scrap::*;
 ///
-/// let input = ["hello", "-n", "foo", "-l", "info"];
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..5), ("foo".to_string(), "info".to_string()))),
-///     Join::new(
-///         FlagWithValue::new("name", "n", "A name.", StringValue),
-///         FlagWithValue::new("log-level", "l", "A given log level setting.", StringValue),
-///     )
-///     .evaluate(&input[..])
+///    Ok(Value::new(Span::from_range(1..3), 8080u16)),
+///    FlagWithValue::new("port", "p", "A port.", FromStrValue::<u16>::new())
+///        .evaluate(&["hello", "--port", "8080"][..])
 /// );
+///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..5), ("foo".to_string(), "info".to_string()))),
-///     Flag::expect_string("name", "n", "A name.")
-///         .join(FlagWithValue::new(
-///             "log-level",
-///             "l",
-///             "A given log level setting.",
-///             StringValue
-///         ))
-///         .evaluate(&input[..])
+///    Err(CliError::InvalidValue {
+///        flag: "port".to_string(),
+///        value: "80000".to_string(),
+///        message: None,
+///    }),
+///    FlagWithValue::new("port", "p", "A port.", FromStrValue::<u16>::new())
+///        .evaluate(&["hello", "--port", "80000"][..])
 /// );
 /// ```
 #[derive(Debug)]
-pub struct Join<E1, E2> {
-    evaluator1: E1,
-    evaluator2: E2,
+pub struct FromStrValue<T> {
+    _type: std::marker::PhantomData<fn() -> T>,
 }
 
-impl<E1, E2> IsFlag for Join<E1, E2> {}
-
-impl<E1, E2> Join<E1, E2> {
-    /// Instantiates a new instance of Join with two given evaluators.
-    pub fn new(evaluator1: E1, evaluator2: E2) -> Self {
+impl<T> FromStrValue<T> {
+    /// Instantiates a new instance of FromStrValue.
+    pub fn new() -> Self {
         Self {
-            evaluator1,
-            evaluator2,
+            _type: std::marker::PhantomData,
         }
     }
 }
 
-impl<'a, E1, E2, A, B, C> Evaluatable<'a, A, (B, C)> for Join<E1, E2>
+impl<T> Default for FromStrValue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for FromStrValue<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FromStrValue<T> {}
+
+impl<'a, T> PositionalArgumentValue<'a, &'a [&'a str], T> for FromStrValue<T>
 where
-    A: Copy + std::borrow::Borrow<A> + 'a,
-    E1: Evaluatable<'a, A, B>,
-    E2: Evaluatable<'a, A, C>,
+    T: std::str::FromStr,
 {
-    fn evaluate(&self, input: A) -> EvaluateResult<'a, (B, C)> {
-        self.evaluator1
-            .evaluate(input)
-            .and_then(|e1_res| match self.evaluator2.evaluate(input) {
-                Ok(e2_res) => {
-                    let (e1_span, e1_val) = (e1_res.span, e1_res.value);
-                    let (e2_span, e2_val) = (e2_res.span, e2_res.value);
-                    let joined_span = e1_span.join(e2_span);
-
-                    Ok(Value::new(joined_span, (e1_val, e2_val)))
-                }
-                Err(e) => Err(e),
-            })
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, T> {
+        self.evaluate(&input[pos..])
     }
 }
 
-impl<E1, E2> ShortHelpable for Join<E1, E2>
+impl<'a, T> Evaluatable<'a, &'a [&'a str], T> for FromStrValue<T>
 where
-    E1: ShortHelpable<Output = FlagHelpCollector>,
-    E2: ShortHelpable<Output = FlagHelpCollector>,
+    T: std::str::FromStr,
 {
-    type Output = FlagHelpCollector;
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, T> {
+        input
+            .first()
+            .and_then(|v| v.parse::<T>().ok())
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
 
-    fn short_help(&self) -> Self::Output {
-        FlagHelpCollector::Joined(
-            Box::new(self.evaluator1.short_help()),
-            Box::new(self.evaluator2.short_help()),
-        )
+impl<'a, T> TerminalEvaluatable<'a, &'a [&'a str], T> for FromStrValue<T> where T: std::str::FromStr {}
+
+impl<T> TakesValue for FromStrValue<T> {}
+
+/// ValueOnMatch represents a terminal flag type, returning a given value on a match.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), false)),
+///     FlagWithValue::new("no-wait", "n", "don't wait for a response.", ValueOnMatch::new(false))
+///         .evaluate(&["hello", "--no-wait"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..2), false)),
+///     FlagWithValue::new("no-wait", "n", "don't wait for a response.", ValueOnMatch::new(false))
+///         .evaluate(&["hello", "-n"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::empty(), true)),
+///     WithDefault::new(
+///         true,
+///         Optional::new(FlagWithValue::new("no-wait", "n", "don't wait for a response.", ValueOnMatch::new(false)))
+///     )
+///     .evaluate(&["hello"][..])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValueOnMatch<V> {
+    value: V,
+}
+
+impl<V> ValueOnMatch<V> {
+    pub fn new(value: V) -> Self {
+        Self { value }
+    }
+}
+
+impl<'a, V: Clone> PositionalArgumentValue<'a, &'a [&'a str], V> for ValueOnMatch<V> {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, V> {
+        self.evaluate(&input[pos..])
+    }
+}
+
+impl<'a, V: Clone> Evaluatable<'a, &'a [&'a str], V> for ValueOnMatch<V> {
+    fn evaluate(&self, _: &'a [&'a str]) -> EvaluateResult<'a, V> {
+        Ok(Value::new(Span::empty(), self.value.clone()))
     }
 }
 
-/// A trait that signifies if a type can be assigned a default value. This
-/// includes helper methods for assigning a type as optional and assigning a
-/// default.
-pub trait Defaultable
-where
-    Self: Sized,
-{
-    /// with_default returns a given type wrapped in a WithDefault with the
-    /// provided default value. Functionally this is an alias for
-    /// `WithDefault::new(self, default)`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// FlagWithValue::new("name", "n", "A name.", StringValue).optional().with_default("foo".to_string());
-    /// ```
-    fn with_default<D>(self, default: D) -> WithDefault<D, Self> {
-        WithDefault::new(default, self)
-    }
+impl<'a, V: Clone> TerminalEvaluatable<'a, &'a [&'a str], V> for ValueOnMatch<V> {}
 
-    /// optional wraps a given type in an Optional struct. Functionally this
-    /// is an alias for `Optional::new(self)`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// FlagWithValue::new("name", "n", "A name.", StringValue).optional();
-    /// ```
-    fn optional(self) -> Optional<Self> {
-        Optional::new(self)
+impl<V> TakesValue for ValueOnMatch<V> {
+    fn takes_value(&self) -> bool {
+        false
     }
 }
 
-/// WithDefault takes an evaluator E and a default value B that agrees with the
-/// return type of the Evaluator. This default is meant to wrap the enclosed
-/// evaluator, returning the A success with the default value for any
-/// evaluation that fails.
+/// FileValue represents a terminal flag type, that parses and validates a
+/// file exists in a path. Returning the file path as a String.
 ///
 /// # Example
 ///
@@ -1852,184 +8934,289 @@ where
 /// use scrap::prelude::v1::*;
 /// use scrap::*;
 ///
-/// let input = ["hello", "--log-level", "info"];
-///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(0..0), "foo".to_string())),
-///     WithDefault::new(
-///         "foo",
-///         Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue))
-///     )
-///     .evaluate(&input[..])
+///     Ok(Value::new(Span::from_range(1..3), "/etc/hostname".to_string())),
+///     FlagWithValue::new("file", "f", "A filepath to read", FileValue::new(true, false, true))
+///         .evaluate(&["hello", "--file", "/etc/hostname"][..])
 /// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(0..0), "foo".to_string())),
-///     Flag::expect_string("name", "n", "A name.")
-///         .optional()
-///         .with_default("foo".to_string())
-///         .evaluate(&input[..])
+///     Ok(Value::new(Span::empty(), "/etc/hostname".to_string())),
+///     WithDefault::new(
+///         "/etc/hostname".to_string(),
+///         Optional::new(FlagWithValue::new("file", "f", "A filepath to read", FileValue::new(true, false, true)))
+///     )
+///     .evaluate(&["hello"][..])
 /// );
 /// ```
-#[derive(Debug)]
-pub struct WithDefault<B, E> {
-    default: B,
-    evaluator: E,
+#[derive(Debug, Clone, Copy)]
+pub struct FileValue {
+    readable: bool,
+    writable: bool,
+    exists: bool,
 }
 
-impl<B, E> IsFlag for WithDefault<B, E> {}
+impl IsFlag for FileValue {}
 
-impl<B, E> WithDefault<B, E> {
-    /// Instantiates a new of WithDefault for a given type
+impl FileValue {
+    /// Instantiates a new instance of FileArgument.
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// WithDefault::<String, _>::new(
-    ///     "foo",
-    ///     Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue))
-    /// );
+    /// FileValue::new(true, false, true);
     /// ```
-    pub fn new<D>(default: D, evaluator: E) -> Self
-    where
-        D: Into<B>,
-    {
+    #[allow(dead_code)]
+    pub fn new(readable: bool, writable: bool, exists: bool) -> Self {
         Self {
-            default: Into::<B>::into(default),
-            evaluator,
+            readable,
+            writable,
+            exists,
         }
     }
 }
 
-impl<'a, E, A, B> Evaluatable<'a, A, B> for WithDefault<B, E>
-where
-    A: 'a,
-    B: Clone,
-    E: Evaluatable<'a, A, Option<B>>,
-{
-    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
-        self.evaluator
-            .evaluate(input)
-            .map(|op| op.map(|opt| opt.unwrap_or_else(|| self.default.clone())))
+impl Openable for FileValue {}
+
+impl Defaultable for FileValue {}
+
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], String> for FileValue {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, String> {
+        self.evaluate(&input[pos..])
     }
 }
 
-impl<B, E> ShortHelpable for WithDefault<B, E>
-where
-    B: Clone + std::fmt::Debug,
-    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
-{
-    type Output = FlagHelpCollector;
+impl<'a> Evaluatable<'a, &'a [&'a str], String> for FileValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
+        use std::fs::OpenOptions;
 
-    fn short_help(&self) -> Self::Output {
-        match self.evaluator.short_help() {
-            FlagHelpCollector::Single(fhc) => FlagHelpCollector::Single(
-                fhc.with_modifier(format!("default: {:?}", self.default.clone())),
-            ),
-            // this case should never be hit as joined is not defaultable
-            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
-        }
+        input
+            .first()
+            // check if the file exists with the corresponding flags.
+            .and_then(|p| {
+                OpenOptions::new()
+                    .read(self.readable)
+                    .write(self.writable)
+                    .create(!self.exists)
+                    .open(p)
+                    .ok()
+                    .map(|_| p)
+            })
+            .map(|&v| Value::new(Span::from_range(0..1), v.to_owned()))
+            .ok_or(CliError::ValueEvaluation)
     }
 }
 
-/// Optional wraps an evaluator, for the purpose of transforming the enclosed
-/// evaluator from an `Evaluator<A, B>` to an `Evaluator<A, Option<B>>` where
-/// the success state of the evaluation is capture in the value of the
-/// `Option<B>`.
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], String> for FileValue {}
+
+impl TakesValue for FileValue {}
+
+/// PathBufValue represents a terminal flag type, returning its argument as a
+/// `PathBuf`. Unlike `FileValue` the filesystem is never checked, allowing
+/// paths that don't exist yet, such as an output file. An empty argument
+/// fails to evaluate.
+///
 /// # Example
 ///
 /// ```
 /// use scrap::prelude::v1::*;
 /// use scrap::*;
 ///
-/// let input = ["hello", "-n", "foo"];
-///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..3), Some("foo".to_string()))),
-///     Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue)).evaluate(&input[..])
+///     Ok(Value::new(Span::from_range(1..3), std::path::PathBuf::from("/path/does/not/exist"))),
+///     FlagWithValue::new("out", "o", "An output path.", PathBufValue)
+///         .evaluate(&["hello", "--out", "/path/does/not/exist"][..])
 /// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PathBufValue;
+
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], std::path::PathBuf> for PathBufValue {
+    fn evaluate_at(
+        &self,
+        input: &'a [&'a str],
+        pos: usize,
+    ) -> EvaluateResult<'a, std::path::PathBuf> {
+        self.evaluate(&input[pos..])
+    }
+}
+
+impl<'a> Evaluatable<'a, &'a [&'a str], std::path::PathBuf> for PathBufValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, std::path::PathBuf> {
+        input
+            .first()
+            .filter(|v| !v.is_empty())
+            .map(|v| Value::new(Span::from_range(0..1), std::path::PathBuf::from(v)))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
+
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], std::path::PathBuf> for PathBufValue {}
+
+impl TakesValue for PathBufValue {}
+
+/// IpAddrValue represents a terminal flag type, parsing either an IPv4 or
+/// IPv6 address literal into an `IpAddr`.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
 ///
-/// // validate boxed syntax works
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..3), Some("foo".to_string()))),
-///     FlagWithValue::new("name", "n", "A name.", StringValue)
-///         .optional()
-///         .evaluate(&input[..])
+///     Ok(Value::new(Span::from_range(1..3), std::net::IpAddr::from([127, 0, 0, 1]))),
+///     FlagWithValue::new("bind", "b", "An address to bind.", IpAddrValue)
+///         .evaluate(&["hello", "--bind", "127.0.0.1"][..])
 /// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IpAddrValue;
+
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], std::net::IpAddr> for IpAddrValue {
+    fn evaluate_at(
+        &self,
+        input: &'a [&'a str],
+        pos: usize,
+    ) -> EvaluateResult<'a, std::net::IpAddr> {
+        self.evaluate(&input[pos..])
+    }
+}
+
+impl<'a> Evaluatable<'a, &'a [&'a str], std::net::IpAddr> for IpAddrValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, std::net::IpAddr> {
+        input
+            .first()
+            .and_then(|v| v.parse::<std::net::IpAddr>().ok())
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
+
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], std::net::IpAddr> for IpAddrValue {}
+
+impl TakesValue for IpAddrValue {}
+
+/// SocketAddrValue represents a terminal flag type, parsing an address and
+/// port, such as `127.0.0.1:8080` or `[::1]:443`, into a `SocketAddr`. As no
+/// name resolution is performed, hostnames fail to evaluate.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::empty(), None)),
-///     Optional::new(FlagWithValue::new(
-///         "log-level",
-///         "l",
-///         "A given log level setting.",
-///         StringValue
-///     ))
-///     .evaluate(&input[..])
+///     Ok(Value::new(Span::from_range(1..3), std::net::SocketAddr::from(([127, 0, 0, 1], 8080)))),
+///     FlagWithValue::new("listen", "l", "An address to listen on.", SocketAddrValue)
+///         .evaluate(&["hello", "--listen", "127.0.0.1:8080"][..])
 /// );
 /// ```
-#[derive(Debug)]
-pub struct Optional<E> {
-    evaluator: E,
+#[derive(Debug, Clone, Copy)]
+pub struct SocketAddrValue;
+
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], std::net::SocketAddr> for SocketAddrValue {
+    fn evaluate_at(
+        &self,
+        input: &'a [&'a str],
+        pos: usize,
+    ) -> EvaluateResult<'a, std::net::SocketAddr> {
+        self.evaluate(&input[pos..])
+    }
 }
 
-impl<E> IsFlag for Optional<E> {}
+impl<'a> Evaluatable<'a, &'a [&'a str], std::net::SocketAddr> for SocketAddrValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, std::net::SocketAddr> {
+        input
+            .first()
+            .and_then(|v| v.parse::<std::net::SocketAddr>().ok())
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
 
-impl<E> Defaultable for Optional<E> where E: Defaultable {}
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], std::net::SocketAddr> for SocketAddrValue {}
 
-impl<E> Optional<E> {
-    /// Instantiates a new instance of Optional.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue));
-    /// ```
-    pub fn new(evaluator: E) -> Self {
-        Self { evaluator }
+impl TakesValue for SocketAddrValue {}
+
+/// SecretFileValue represents a terminal flag type, that reads the contents
+/// of a file holding a secret, such as a token or key, as a String with any
+/// trailing line ending removed. On unix platforms the file must not be
+/// accessible by group or other users, mirroring the permission checks
+/// performed on ssh keys, otherwise it fails to evaluate.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert!(
+///     FlagWithValue::new("token-file", "t", "A file containing a token.", SecretFileValue)
+///         .evaluate(&["hello", "--token-file", "/path/does/not/exist"][..])
+///         .is_err()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SecretFileValue;
+
+impl SecretFileValue {
+    #[cfg(unix)]
+    fn check_permissions(path: &str, metadata: &std::fs::Metadata) -> Result<(), CliError> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o077 == 0 {
+            Ok(())
+        } else {
+            Err(CliError::Io(format!(
+                "permissions {:o} for secret file {} are too open, it must not be accessible by group or other users",
+                mode, path
+            )))
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn check_permissions(_: &str, _: &std::fs::Metadata) -> Result<(), CliError> {
+        Ok(())
     }
 }
 
-impl<'a, E, A, B> Evaluatable<'a, A, Option<B>> for Optional<E>
-where
-    A: 'a,
-    E: Evaluatable<'a, A, B>,
-{
-    fn evaluate(&self, input: A) -> EvaluateResult<'a, Option<B>> {
-        match self.evaluator.evaluate(input).ok() {
-            Some(Value { span, value }) => Ok(Value::new(span, Some(value))),
-            None => Ok(Value::new(Span::default(), None)),
-        }
+impl Defaultable for SecretFileValue {}
+
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], String> for SecretFileValue {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, String> {
+        self.evaluate(&input[pos..])
     }
 }
 
-impl<E> ShortHelpable for Optional<E>
-where
-    E: ShortHelpable<Output = FlagHelpCollector>,
-{
-    type Output = FlagHelpCollector;
-
-    fn short_help(&self) -> Self::Output {
-        match self.evaluator.short_help() {
-            FlagHelpCollector::Single(fhc) => {
-                FlagHelpCollector::Single(fhc.with_modifier("optional".to_string()))
-            }
-            // this case should never be hit as joined is not defaultable
-            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
-        }
+impl<'a> Evaluatable<'a, &'a [&'a str], String> for SecretFileValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
+        let path = input.first().ok_or(CliError::ValueEvaluation)?;
+        let metadata = std::fs::metadata(path).map_err(|_| CliError::ValueEvaluation)?;
+        Self::check_permissions(path, &metadata)?;
+
+        std::fs::read_to_string(path)
+            .map(|contents| contents.trim_end_matches(&['\r', '\n'][..]).to_string())
+            .map(|contents| Value::new(Span::from_range(0..1), contents))
+            .map_err(|_| CliError::ValueEvaluation)
     }
 }
 
-/// WithChoices takes an evaluator E and a default value B that agrees with the
-/// return type of the Evaluator. This default is meant to wrap the enclosed
-/// evaluator, returning the A success with the default value for any
-/// evaluation that fails.
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], String> for SecretFileValue {}
+
+impl TakesValue for SecretFileValue {}
+
+/// RangeListValue represents a terminal flag type, that parses a comma or
+/// space separated list of numbers and inclusive ranges, such as `1-3,5,7-9`,
+/// into the expanded list of numbers. A range with equal bounds, `3-3`, yields
+/// a single number, while a reversed range, `5-3`, fails to evaluate. To bound
+/// the memory an argument can claim, a list expanding to more than
+/// `RangeListValue::MAX_LEN` numbers fails to evaluate.
 ///
 /// # Example
 ///
@@ -2037,180 +9224,199 @@ where
 /// use scrap::prelude::v1::*;
 /// use scrap::*;
 ///
-/// let input = ["hello", "--log-level", "info"];
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), vec![1, 2, 3, 5, 7, 8, 9])),
+///     FlagWithValue::new("pages", "p", "pages to print.", RangeListValue)
+///         .evaluate(&["hello", "--pages", "1-3,5,7-9"][..])
+/// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
-///     Flag::with_choices(
-///         "log-level", "l", "logging level",
-///         ["info".to_string(), "warn".to_string()],
-///         StringValue
-///     )
-///     .evaluate(&input[..])
+///     Ok(Value::new(Span::from_range(1..3), vec![3])),
+///     FlagWithValue::new("pages", "p", "pages to print.", RangeListValue)
+///         .evaluate(&["hello", "--pages", "3-3"][..])
 /// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..3), "info".to_string())),
-///     WithChoices::new(
-///         ["info".to_string(), "warn".to_string()],
-///         FlagWithValue::new("log-level", "l", "logging level", StringValue)
-///     )
-///     .evaluate(&input[..])
+///     Err(CliError::ValueEvaluation),
+///     RangeListValue.evaluate(&["5-3"][..])
 /// );
 ///
-/// assert!(
-///     WithChoices::new(
-///         ["error".to_string()],
-///         FlagWithValue::new("log-level", "l", "logging level", StringValue)
-///     )
-///     .evaluate(&input[..]).is_err()
+/// assert_eq!(
+///     Err(CliError::ValueEvaluation),
+///     RangeListValue.evaluate(&["1-"][..])
 /// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::default(), "debug".to_string())),
-///     WithDefault::new(
-///         "debug".to_string(),
-///         Optional::new(WithChoices::new(
-///             ["error".to_string()],
-///             FlagWithValue::new("log-level", "l", "logging level", StringValue)
-///         ))
-///     )
-///     .evaluate(&input[..])
+///     Err(CliError::ValueEvaluation),
+///     RangeListValue.evaluate(&["0-100000000000000"][..])
 /// );
 /// ```
-#[derive(Debug)]
-pub struct WithChoices<B, E, const N: usize> {
-    choices: [B; N],
-    evaluator: E,
-}
+#[derive(Debug, Clone, Copy)]
+pub struct RangeListValue;
 
-impl<B, E, const N: usize> IsFlag for WithChoices<B, E, N> {}
+impl RangeListValue {
+    /// The maximum count of numbers a list may expand to.
+    pub const MAX_LEN: usize = 1 << 16;
 
-#[allow(deprecated)]
-impl<B, E, const N: usize> Defaultable for WithChoices<B, E, N> where E: Defaultable {}
+    /// Parses a single number or range into its inclusive bounds.
+    fn parse_range(range: &str) -> Option<std::ops::RangeInclusive<usize>> {
+        match range.split_once('-') {
+            Some((start, end)) => {
+                let start = start.parse::<usize>().ok()?;
+                let end = end.parse::<usize>().ok()?;
 
-impl<B, E, const N: usize> WithChoices<B, E, N> {
-    /// Instantiates a new choices wrapper on an evaluator.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// WithChoices::new(
-    ///     ["foo".to_string(), "bar".to_string()],
-    ///     Optional::new(FlagWithValue::new("name", "n", "A name.", StringValue))
-    /// );
-    /// ```
-    pub fn new(choices: [B; N], evaluator: E) -> Self {
-        Self { choices, evaluator }
+                (start <= end).then_some(start..=end)
+            }
+            None => range.parse::<usize>().ok().map(|v| v..=v),
+        }
     }
 }
 
-impl<'a, E, A, B, const N: usize> Evaluatable<'a, A, B> for WithChoices<B, E, N>
-where
-    A: 'a,
-    B: Clone + PartialEq,
-    E: Evaluatable<'a, A, B>,
-{
-    fn evaluate(&self, input: A) -> EvaluateResult<'a, B> {
-        self.evaluator.evaluate(input).and_then(|op| {
-            self.choices
-                .iter()
-                .any(|choice| choice == &op.value)
-                .then_some(op)
-                .ok_or(CliError::ValueEvaluation)
-        })
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], Vec<usize>> for RangeListValue {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, Vec<usize>> {
+        self.evaluate(&input[pos..])
     }
 }
 
-impl<B, E, const N: usize> ShortHelpable for WithChoices<B, E, N>
-where
-    B: Clone + std::fmt::Debug,
-    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
-{
-    type Output = FlagHelpCollector;
-
-    fn short_help(&self) -> Self::Output {
-        match self.evaluator.short_help() {
-            FlagHelpCollector::Single(fhc) => {
-                FlagHelpCollector::Single(fhc.with_modifier(format!("choices: {:?}", self.choices)))
-            }
-            // this case should never be hit as joined is not defaultable
-            fhcj @ FlagHelpCollector::Joined(_, _) => fhcj,
-        }
+impl<'a> Evaluatable<'a, &'a [&'a str], Vec<usize>> for RangeListValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, Vec<usize>> {
+        input
+            .first()
+            .and_then(|&v| {
+                v.split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|range| !range.is_empty())
+                    .map(Self::parse_range)
+                    .collect::<Option<Vec<std::ops::RangeInclusive<usize>>>>()
+            })
+            .filter(|ranges| !ranges.is_empty())
+            // bound the expansion before allocating any of it.
+            .filter(|ranges| {
+                ranges
+                    .iter()
+                    .try_fold(0usize, |len, range| {
+                        (range.end() - range.start())
+                            .checked_add(1)
+                            .and_then(|width| len.checked_add(width))
+                    })
+                    .is_some_and(|len| len <= Self::MAX_LEN)
+            })
+            .map(|ranges| {
+                let expanded = ranges.into_iter().flatten().collect();
+                Value::new(Span::from_range(0..1), expanded)
+            })
+            .ok_or(CliError::ValueEvaluation)
     }
 }
 
-/// ExpectStringValue represents a terminal flag type, returning the next string value passed.
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], Vec<usize>> for RangeListValue {}
+
+impl TakesValue for RangeListValue {}
+
+/// MapValue represents a terminal flag type, that parses a comma separated
+/// list of `key=value` pairs from a single argument into a HashMap, with each
+/// value evaluated by the enclosed value type. Duplicate keys are resolved
+/// with the last occurrence winning, unless `strict_keys` is set, in which
+/// case they fail to evaluate.
 ///
 /// # Example
 ///
 /// ```
 /// use scrap::prelude::v1::*;
 /// use scrap::*;
+/// use std::collections::HashMap;
+///
+/// let expected: HashMap<String, u64> = vec![("a".to_string(), 1), ("b".to_string(), 3)]
+///     .into_iter()
+///     .collect();
 ///
 /// assert_eq!(
-///    Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
-///    ExpectStringValue::new("name", "n", "A name.").evaluate(&["hello", "--name", "foo"][..])
+///     Ok(Value::new(Span::from_range(1..3), expected)),
+///     FlagWithValue::new("limits", "l", "resource limits.", MapValue::new(U64Value))
+///         .evaluate(&["hello", "--limits", "a=1,b=2,b=3"][..])
 /// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
-///     ExpectStringValue::new("name", "n", "A name.").evaluate(&["hello", "-n", "foo"][..])
+///     Err(CliError::ValueEvaluation),
+///     MapValue::new(U64Value).strict_keys().evaluate(&["a=1,a=2"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::ValueEvaluation),
+///     MapValue::new(U64Value).evaluate(&["a=one"][..])
 /// );
 /// ```
-#[deprecated]
-#[derive(Debug)]
-pub struct ExpectStringValue {
-    inner: FlagWithValue<StringValue>,
+#[derive(Debug, Clone, Copy)]
+pub struct MapValue<V> {
+    value: V,
+    strict_keys: bool,
 }
 
-#[allow(deprecated)]
-impl IsFlag for ExpectStringValue {}
-
-#[allow(deprecated)]
-impl ExpectStringValue {
-    /// Instantiates a new instance of ExpectStringValue with a given flag name,
-    /// shortcode and description.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// ExpectStringValue::new("name", "n", "A name.");
-    /// ```
-    #[allow(dead_code)]
-    pub fn new(name: &'static str, short_code: &'static str, description: &'static str) -> Self {
+impl<V> MapValue<V> {
+    pub fn new(value: V) -> Self {
         Self {
-            inner: FlagWithValue::new(name, short_code, description, StringValue),
+            value,
+            strict_keys: false,
         }
     }
-}
 
-#[allow(deprecated)]
-impl Defaultable for ExpectStringValue {}
+    /// Returns MapValue configured to fail evaluation on duplicate keys
+    /// rather than overwriting earlier values.
+    pub fn strict_keys(mut self) -> Self {
+        self.strict_keys = true;
+        self
+    }
+}
 
-#[allow(deprecated)]
-impl<'a> Evaluatable<'a, &'a [&'a str], String> for ExpectStringValue {
-    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
-        self.inner.evaluate(input)
+impl<'a, V, B> PositionalArgumentValue<'a, &'a [&'a str], std::collections::HashMap<String, B>>
+    for MapValue<V>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate_at(
+        &self,
+        input: &'a [&'a str],
+        pos: usize,
+    ) -> EvaluateResult<'a, std::collections::HashMap<String, B>> {
+        self.evaluate(&input[pos..])
     }
 }
 
-#[allow(deprecated)]
-impl ShortHelpable for ExpectStringValue {
-    type Output = FlagHelpCollector;
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], std::collections::HashMap<String, B>> for MapValue<V>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+    fn evaluate(
+        &self,
+        input: &'a [&'a str],
+    ) -> EvaluateResult<'a, std::collections::HashMap<String, B>> {
+        let arg = input.first().ok_or(CliError::ValueEvaluation)?;
+        let mut map = std::collections::HashMap::new();
+
+        for pair in arg.split(',').filter(|pair| !pair.is_empty()) {
+            let (key, raw_value) = pair.split_once('=').ok_or(CliError::ValueEvaluation)?;
+            let value = self.value.evaluate_at(&[raw_value][..], 0)?.unwrap();
+
+            if map.insert(key.to_string(), value).is_some() && self.strict_keys {
+                return Err(CliError::ValueEvaluation);
+            }
+        }
 
-    fn short_help(&self) -> Self::Output {
-        self.inner.short_help()
+        Ok(Value::new(Span::from_range(0..1), map))
     }
 }
 
-/// StoreTrue represents a terminal flag type, returning a boolean set to true if set.
+impl<'a, V, B> TerminalEvaluatable<'a, &'a [&'a str], std::collections::HashMap<String, B>>
+    for MapValue<V>
+where
+    V: for<'b> PositionalArgumentValue<'b, &'b [&'b str], B>,
+{
+}
+
+impl<V> TakesValue for MapValue<V> {}
+
+/// Positional represents an evaluator for a named positional argument at a
+/// fixed index of its input, with the value at that index evaluated by the
+/// enclosed value type.
 ///
 /// # Example
 ///
@@ -2219,74 +9425,145 @@ impl ShortHelpable for ExpectStringValue {
 /// use scrap::*;
 ///
 /// assert_eq!(
-///    Ok(Value::new(Span::from_range(1..2), true)),
-///    StoreTrue::new("debug", "d", "Run in debug mode.").evaluate(&["hello", "--debug"][..])
-/// );
-///
-/// assert_eq!(
-///    Ok(Value::new(Span::from_range(1..2), true)),
-///    StoreTrue::new("debug", "d", "Run in debug mode.").evaluate(&["hello", "-d"][..])
+///     Ok(Value::new(Span::from_range(1..2), 5)),
+///     Positional::new("count", 1, U8Value).evaluate(&["a.txt", "5"][..])
 /// );
 ///
 /// assert_eq!(
-///    Ok(Value::new(Span::empty(), false)),
-///    WithDefault::new(
-///        false,
-///        Optional::new(StoreTrue::new("debug", "d", "Run in debug mode."))
-///    )
-///    .evaluate(&["hello"][..])
+///     Err(CliError::MissingValue("count".to_string())),
+///     Positional::new("count", 1, U8Value).evaluate(&["a.txt"][..])
 /// );
 /// ```
-#[deprecated]
-#[derive(Debug)]
-pub struct StoreTrue {
-    inner: FlagWithValue<ValueOnMatch<bool>>,
+#[derive(Debug, Clone, Copy)]
+pub struct Positional<V> {
+    name: &'static str,
+    index: usize,
+    value: V,
 }
 
-#[allow(deprecated)]
-impl IsFlag for StoreTrue {}
+impl<V> IsFlag for Positional<V> {}
 
-#[allow(deprecated)]
-impl Defaultable for StoreTrue {}
+impl<V> Positional<V> {
+    pub fn new(name: &'static str, index: usize, value: V) -> Self {
+        Self { name, index, value }
+    }
+}
 
-#[allow(deprecated)]
-impl StoreTrue {
-    /// Instantiates a new instance of StoreTrue with a given flag name,
-    /// shortcode and description.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// StoreTrue::new("debug", "d", "Run a command in debug mode.");
-    /// ```
-    #[allow(dead_code)]
-    pub fn new(name: &'static str, short_code: &'static str, description: &'static str) -> Self {
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], B> for Positional<V>
+where
+    V: PositionalArgumentValue<'a, &'a [&'a str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
+        if self.index >= input.len() {
+            return Err(CliError::MissingValue(self.name.to_string()));
+        }
+
+        self.value
+            .evaluate_at(input, self.index)
+            .map(|v| v.from_offset(self.index))
+            .map_err(|e| self.value_error(input[self.index], e))
+    }
+}
+
+impl<V> Positional<V> {
+    /// Evaluates the positional against only the arguments left unconsumed
+    /// by the `consumed` span, skipping over the positions claimed by the
+    /// `preceding` positionals already enclosed in that span.
+    fn evaluate_unconsumed<'a, B>(
+        &self,
+        input: &'a [&'a str],
+        consumed: &Span,
+        preceding: &[usize],
+    ) -> EvaluateResult<'a, B>
+    where
+        V: PositionalArgumentValue<'a, &'a [&'a str], B>,
+    {
+        let claimed = preceding.iter().filter(|&&idx| idx < self.index).count();
+        let pos = (0..input.len())
+            .filter(|idx| !consumed.0.contains(idx))
+            .nth(self.index.saturating_sub(claimed))
+            .ok_or_else(|| CliError::MissingValue(self.name.to_string()))?;
+
+        self.value
+            .evaluate_at(input, pos)
+            .map(|v| v.from_offset(pos))
+            .map_err(|e| self.value_error(input[pos], e))
+    }
+
+    /// Returns the error for the value at the positional's index failing to
+    /// evaluate, carrying the reason reported by the value type if any.
+    fn value_error(&self, value: &str, e: CliError) -> CliError {
+        CliError::InvalidValue {
+            flag: self.name.to_string(),
+            value: value.to_string(),
+            message: match e {
+                CliError::ValueEvaluationMessage(reason) => Some(reason),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// WithPositional joins an evaluator with a positional argument evaluator,
+/// like `Join`, while only the enclosed evaluator contributes to flag help.
+/// Positionals are instead documented by the enclosing `Cmd`. The
+/// positional's index counts only the arguments left unconsumed by the
+/// enclosed evaluator's flags.
+#[derive(Debug, Clone)]
+pub struct WithPositional<E, P> {
+    evaluator: E,
+    positional: P,
+    /// The indices of positionals already enclosed by the evaluator.
+    preceding: Vec<usize>,
+}
+
+impl<E, P> IsFlag for WithPositional<E, P> {}
+
+impl<E, P> WithPositional<E, P> {
+    pub fn new(evaluator: E, positional: P) -> Self {
         Self {
-            inner: FlagWithValue::new(name, short_code, description, ValueOnMatch::new(true)),
+            evaluator,
+            positional,
+            preceding: Vec::new(),
         }
     }
 }
 
-#[allow(deprecated)]
-impl<'a> Evaluatable<'a, &'a [&'a str], bool> for StoreTrue {
-    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, bool> {
-        self.inner.evaluate(input)
+impl<'a, E, V, B, C> Evaluatable<'a, &'a [&'a str], (B, C)> for WithPositional<E, Positional<V>>
+where
+    E: Evaluatable<'a, &'a [&'a str], B>,
+    V: PositionalArgumentValue<'a, &'a [&'a str], C>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, (B, C)> {
+        let Value { span, value: b } = self.evaluator.evaluate(input)?;
+        let Value {
+            span: positional_span,
+            value: c,
+        } = self
+            .positional
+            .evaluate_unconsumed(input, &span, &self.preceding)?;
+
+        Ok(Value::new(span.join(positional_span), (b, c)))
     }
 }
 
-#[allow(deprecated)]
-impl ShortHelpable for StoreTrue {
+impl<E, P> ShortHelpable for WithPositional<E, P>
+where
+    E: ShortHelpable<Output = FlagHelpCollector>,
+{
     type Output = FlagHelpCollector;
 
     fn short_help(&self) -> Self::Output {
-        self.inner.short_help()
+        self.evaluator.short_help()
     }
 }
 
-/// StoreFalse represents a terminal flag type, returning a boolean set to false if set.
+/// PositionalsUntilFlag represents an evaluator that collects the leading
+/// positional arguments of an input, up to the first dash-prefixed token, for
+/// commands laid out as "files first, then options". A lone `-`, commonly
+/// used to denote stdin, is treated as a positional argument. Unlike
+/// `return_unused_args`, which collects whatever arguments remain after all
+/// flags are evaluated, only the arguments preceding any flag are collected.
 ///
 /// # Example
 ///
@@ -2295,436 +9572,465 @@ impl ShortHelpable for StoreTrue {
 /// use scrap::*;
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..2), false)),
-///     StoreFalse::new("no-wait", "n", "don't wait for a response.").evaluate(&["hello", "--no-wait"][..])
-/// );
-///
-/// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..2), false)),
-///     StoreFalse::new("no-wait", "n", "don't wait for a response.").evaluate(&["hello", "-n"][..])
+///     Ok(Value::new(
+///         Span::from_range(0..2),
+///         vec!["a.txt".to_string(), "-".to_string()]
+///     )),
+///     PositionalsUntilFlag.evaluate(&["a.txt", "-", "--verbose", "b.txt"][..])
 /// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::empty(), true)),
-///     WithDefault::new(
-///         true,
-///         Optional::new(StoreFalse::new("no-wait", "n", "don't wait for a response."))
-///     )
-///     .evaluate(&["hello"][..])
+///     Ok(Value::new(Span::empty(), vec![])),
+///     PositionalsUntilFlag.evaluate(&["--verbose"][..])
 /// );
 /// ```
-#[deprecated]
-#[derive(Debug)]
-pub struct StoreFalse {
-    inner: FlagWithValue<ValueOnMatch<bool>>,
-}
+#[derive(Debug, Clone, Copy)]
+pub struct PositionalsUntilFlag;
 
-#[allow(deprecated)]
-impl IsFlag for StoreFalse {}
+impl IsFlag for PositionalsUntilFlag {}
 
-#[allow(deprecated)]
-impl Defaultable for StoreFalse {}
+impl<'a> Evaluatable<'a, &'a [&'a str], Vec<String>> for PositionalsUntilFlag {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, Vec<String>> {
+        let positionals = input
+            .iter()
+            .take_while(|&&arg| arg == "-" || !arg.starts_with('-'))
+            .map(|&arg| arg.to_string())
+            .collect::<Vec<String>>();
 
-#[allow(deprecated)]
-impl StoreFalse {
-    /// Instantiates a new instance of StoreFalse with a given flag name,
-    /// shortcode and description.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// StoreFalse::new("no-wait", "n", "don't wait for a response.");
-    /// ```
-    #[allow(dead_code)]
-    pub fn new(name: &'static str, short_code: &'static str, description: &'static str) -> Self {
-        Self {
-            inner: FlagWithValue::new(name, short_code, description, ValueOnMatch::new(false)),
-        }
+        Ok(Value::new(
+            Span::from_range(0..positionals.len()),
+            positionals,
+        ))
     }
 }
 
-#[allow(deprecated)]
-impl<'a> Evaluatable<'a, &'a [&'a str], bool> for StoreFalse {
-    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, bool> {
-        self.inner.evaluate(input)
-    }
+/// Greedy represents a terminal flag type, that consumes each consecutive
+/// argument following a flag, up to the next dash-prefixed argument or the
+/// end of input, evaluating each with the enclosed value type. At least one
+/// value must be present. As with `PositionalsUntilFlag`, a lone `-` is
+/// treated as a value, while a negative number is treated as a flag.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..4), vec![1, 2])),
+///     FlagWithValue::new("ids", "i", "ids to select.", Greedy::new(U8Value))
+///         .evaluate(&["hello", "--ids", "1", "2", "--verbose"][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::ValueEvaluation),
+///     Greedy::new(U8Value).evaluate(&["--verbose"][..])
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Greedy<V> {
+    value: V,
 }
 
-#[allow(deprecated)]
-impl ShortHelpable for StoreFalse {
-    type Output = FlagHelpCollector;
-
-    fn short_help(&self) -> Self::Output {
-        self.inner.short_help()
+impl<V> Greedy<V> {
+    /// Instantiates a new instance of Greedy evaluating each collected
+    /// argument with the given value type.
+    pub fn new(value: V) -> Self {
+        Self { value }
     }
 }
 
-// Integer types
-
-macro_rules! generate_integer_evaluators {
-    ($($name:tt, $value_name:tt, $primitive:ty,)*) => {
-        $(
-        #[deprecated]
-        #[derive(Debug)]
-        pub struct $name {
-            inner: FlagWithValue<$value_name>,
-        }
-
-        #[allow(deprecated)]
-        impl IsFlag for $name {}
-
-        #[allow(deprecated)]
-        impl Defaultable for $name {}
-
-        #[allow(deprecated)]
-        impl $name {
-            #[allow(dead_code)]
-            pub fn new(
-                name: &'static str,
-                short_code: &'static str,
-                description: &'static str,
-            ) -> Self {
-                Self {
-                    inner:FlagWithValue::new(name, short_code, description, $value_name),
-                }
-            }
-        }
-
-        #[allow(deprecated)]
-        impl<'a> Evaluatable<'a, &'a [&'a str], $primitive> for $name {
-            fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, $primitive> {
-                self.inner.evaluate(input)
-            }
-        }
-
-        #[allow(deprecated)]
-        impl ShortHelpable for $name {
-            type Output = FlagHelpCollector;
-
-            fn short_help(&self) -> Self::Output {
-                self.inner.short_help()
-            }
-        }
-
-        /// Represents a Numeric argument
-        #[derive(Debug, Clone, Copy)]
-        pub struct $value_name;
-
-        impl<'a> PositionalArgumentValue<'a, &'a [&'a str], $primitive> for $value_name {
-            fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, $primitive> {
-                self.evaluate(&input[pos..])
-            }
-        }
+impl<'a, V, B> PositionalArgumentValue<'a, &'a [&'a str], Vec<B>> for Greedy<V>
+where
+    V: PositionalArgumentValue<'a, &'a [&'a str], B>,
+{
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, Vec<B>> {
+        self.evaluate(&input[pos..])
+    }
+}
 
-        impl<'a> Evaluatable<'a, &'a [&'a str], $primitive> for $value_name {
-            fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, $primitive> {
-                let result = input
-                    .get(0)
-                    .and_then(|&v| v.parse::<$primitive>().ok())
-                    .ok_or(CliError::ValueEvaluation);
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], Vec<B>> for Greedy<V>
+where
+    V: PositionalArgumentValue<'a, &'a [&'a str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, Vec<B>> {
+        let len = input
+            .iter()
+            .take_while(|&&arg| arg == "-" || !arg.starts_with('-'))
+            .count();
 
-               result.map(|matching_int| Value::new(Span::from_range(0..1), matching_int))
-            }
+        if len == 0 {
+            return Err(CliError::ValueEvaluation);
         }
 
-        impl<'a> TerminalEvaluatable<'a, &'a [&'a str], $primitive> for $value_name {}
-    )*
-    };
+        (0..len)
+            .map(|idx| {
+                self.value
+                    .evaluate_at(input, idx)
+                    .map(|v| v.from_offset(idx))
+            })
+            .try_fold(Value::new(Span::empty(), Vec::new()), |mut acc, res| {
+                let Value { span, value } = res?;
+                acc.span = acc.span.join(span);
+                acc.value.push(value);
+                Ok(acc)
+            })
+    }
 }
 
-#[rustfmt::skip]
-generate_integer_evaluators!(
-    ExpectI8Value, I8Value, i8,
-    ExpectI16Value, I16Value, i16,
-    ExpectI32Value, I32Value, i32,
-    ExpectI64Value, I64Value, i64,
-    ExpectU8Value, U8Value, u8,
-    ExpectU16Value, U16Value, u16,
-    ExpectU32Value, U32Value, u32,
-    ExpectU64Value, U64Value, u64,
-);
+impl<'a, V, B> TerminalEvaluatable<'a, &'a [&'a str], Vec<B>> for Greedy<V> where
+    V: PositionalArgumentValue<'a, &'a [&'a str], B>
+{
+}
 
-/// Defines a marker trait for types that can be opened via the WithOpen
-/// evaluator.
-pub trait Openable {}
+impl<V> TakesValue for Greedy<V> {}
 
-/// WithOpen represents an evaluator that can take a filepath as parsed by
-/// `ExpectFilePath` and return an opened file handler for said path. Function
-/// this works much like `WithDefault` in that it is an optional augmentation
-/// for an existing evaluator.
+/// SignedRequiredValue wraps a signed numeric value type, requiring that the
+/// value is written with an explicit leading `+` or `-` sign before it's
+/// evaluated by the enclosed value type.
 ///
 /// # Example
 ///
 /// ```
 /// use scrap::prelude::v1::*;
 /// use scrap::*;
-/// use std::fs::File;
 ///
-/// assert!(
-///     WithOpen::new(
-///         ExpectFilePath::new("file", "f", "A file to open", true, false, true)
-///     ).evaluate(&["hello", "--file", "/etc/hostname"][..]).is_ok()
+/// let offset = || FlagWithValue::new(
+///     "offset",
+///     "o",
+///     "A temperature offset.",
+///     SignedRequiredValue::new(I8Value),
 /// );
 ///
-/// assert!(
-///     WithOpen::new(
-///         ExpectFilePath::new("file", "f", "A file to open", true, false, true)
-///     ).evaluate(&["hello", "-f", "/etc/hostname"][..]).is_ok()
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), 5)),
+///     offset().evaluate(&["hello", "--offset", "+5"][..])
 /// );
 ///
-/// assert!(
-///     WithOpen::new(
-///         ExpectFilePath::new("file", "f", "A file to open", true, false, true)
-///     ).evaluate(&["hello"][..]).is_err()
+/// assert_eq!(
+///     Err(CliError::InvalidValue {
+///         flag: "offset".to_string(),
+///         value: "5".to_string(),
+///         message: None,
+///     }),
+///     offset().evaluate(&["hello", "--offset", "5"][..])
 /// );
 /// ```
-#[derive(Debug)]
-pub struct WithOpen<E> {
-    evaluator: E,
-}
-
-impl<E> IsFlag for WithOpen<E> {}
-
-impl<E> WithOpen<E> {
-    /// Instantiates a new of WithOpen for a given type
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// WithOpen::new(
-    ///     ExpectFilePath::new("file", "f", "A file to open", true, false, true)
-    /// );
-    /// ```
-    pub fn new(evaluator: E) -> Self {
-        Self { evaluator }
-    }
+#[derive(Debug, Clone, Copy)]
+pub struct SignedRequiredValue<V> {
+    value: V,
 }
 
-impl<'a, E> Evaluatable<'a, &'a [&'a str], std::fs::File> for WithOpen<E>
-where
-    E: Evaluatable<'a, &'a [&'a str], String> + Openable,
-{
-    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, std::fs::File> {
-        self.evaluator.evaluate(input).and_then(|vfp| {
-            std::fs::File::open(&vfp.value)
-                .map_err(|e| {
-                    CliError::FlagEvaluation(format!("unable to open file evaluator: {}", e))
-                })
-                .map(|f| Value::new(vfp.span, f))
-        })
+impl<V> SignedRequiredValue<V> {
+    /// Instantiates a new instance of SignedRequiredValue evaluating an
+    /// explicitly signed argument with the given value type.
+    pub fn new(value: V) -> Self {
+        Self { value }
     }
 }
 
-impl<E> ShortHelpable for WithOpen<E>
+impl<'a, V, B> PositionalArgumentValue<'a, &'a [&'a str], B> for SignedRequiredValue<V>
 where
-    E: ShortHelpable<Output = FlagHelpCollector> + Defaultable,
+    V: Evaluatable<'a, &'a [&'a str], B>,
 {
-    type Output = FlagHelpCollector;
-
-    fn short_help(&self) -> Self::Output {
-        match self.evaluator.short_help() {
-            FlagHelpCollector::Single(fhc) => {
-                FlagHelpCollector::Single(fhc.with_modifier("will_open".to_string()))
-            }
-            // this case should never be hit as joined is not defaultable
-            fhcj => fhcj,
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, B> {
+        self.evaluate(&input[pos..])
+    }
+}
+
+impl<'a, V, B> Evaluatable<'a, &'a [&'a str], B> for SignedRequiredValue<V>
+where
+    V: Evaluatable<'a, &'a [&'a str], B>,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
+        match input.first() {
+            Some(v) if v.starts_with('+') || v.starts_with('-') => self.value.evaluate(input),
+            _ => Err(CliError::ValueEvaluation),
         }
     }
 }
 
-/// ExpectFilePath represents a terminal flag type, that parses and validates a
-/// file exists in a path. Returning the file path as a String.
+impl<'a, V, B> TerminalEvaluatable<'a, &'a [&'a str], B> for SignedRequiredValue<V> where
+    V: Evaluatable<'a, &'a [&'a str], B>
+{
+}
+
+impl<V> TakesValue for SignedRequiredValue<V> {}
+
+/// TimestampValue represents a terminal flag type, that parses a
+/// conservative subset of RFC 3339 timestamps into a `SystemTime`. A value
+/// consists of a `YYYY-MM-DD` date, optionally followed by a `T` and an
+/// `HH:MM:SS` time with optional fractional seconds, and an optional trailing
+/// `Z`. All timestamps are interpreted as UTC, with a date alone representing
+/// midnight.
 ///
 /// # Example
 ///
 /// ```
 /// use scrap::prelude::v1::*;
 /// use scrap::*;
+/// use std::time::{Duration, UNIX_EPOCH};
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..3), "/etc/hostname".to_string())),
-///     ExpectFilePath::new("file", "f", "A filepath to read", true, false, true).evaluate(&["hello", "--file", "/etc/hostname"][..])
+///     Ok(Value::new(
+///         Span::from_range(1..3),
+///         UNIX_EPOCH + Duration::from_secs(1_672_628_645)
+///     )),
+///     FlagWithValue::new("since", "s", "A start time.", TimestampValue)
+///         .evaluate(&["hello", "--since", "2023-01-02T03:04:05Z"][..])
 /// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::empty(), "/etc/hostname".to_string())),
-///     WithDefault::new(
-///         "/etc/hostname".to_string(),
-///         Optional::new(ExpectFilePath::new("file", "f", "A filepath to read", true, false, true))
-///     )
-///     .evaluate(&["hello"][..])
+///     Err(CliError::ValueEvaluation),
+///     TimestampValue.evaluate(&["2023-02-29"][..])
 /// );
 /// ```
-#[deprecated]
-#[derive(Debug)]
-pub struct ExpectFilePath {
-    inner: FlagWithValue<FileValue>,
-}
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampValue;
+
+impl TimestampValue {
+    fn parse(timestamp: &str) -> Option<std::time::SystemTime> {
+        let timestamp = timestamp.strip_suffix('Z').unwrap_or(timestamp);
+        let (date, time) = match timestamp.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (timestamp, None),
+        };
+
+        let (year, month, day) = Self::parse_date(date)?;
+        let (seconds, nanos) = match time {
+            Some(time) => Self::parse_time(time)?,
+            None => (0, 0),
+        };
+
+        let days = Self::days_from_civil(year, month, day);
+        let total_seconds = days * 86_400 + seconds;
+        let offset = std::time::Duration::new(total_seconds.unsigned_abs(), 0);
+
+        let whole_seconds = if total_seconds >= 0 {
+            std::time::UNIX_EPOCH.checked_add(offset)?
+        } else {
+            std::time::UNIX_EPOCH.checked_sub(offset)?
+        };
+        whole_seconds.checked_add(std::time::Duration::new(0, nanos))
+    }
 
-#[allow(deprecated)]
-impl IsFlag for ExpectFilePath {}
+    /// Parses exactly `len` ascii digits into a number.
+    fn parse_digits(digits: &str, len: usize) -> Option<i64> {
+        (digits.len() == len && digits.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| digits.parse::<i64>().ok())
+            .flatten()
+    }
 
-#[allow(deprecated)]
-impl ExpectFilePath {
-    /// Instantiates a new instance of ExpectFilePath with a given flag name,
-    /// shortcode and description.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// ExpectFilePath::new("file", "f", "A file name.", true, false, true);
-    /// ```
-    #[allow(dead_code)]
-    pub fn new(
-        name: &'static str,
-        short_code: &'static str,
-        description: &'static str,
-        readable: bool,
-        writable: bool,
-        exists: bool,
-    ) -> Self {
-        Self {
-            inner: FlagWithValue::new(
-                name,
-                short_code,
-                description,
-                FileValue::new(readable, writable, exists),
-            ),
+    fn parse_date(date: &str) -> Option<(i64, i64, i64)> {
+        let mut parts = date.split('-');
+        let year = Self::parse_digits(parts.next()?, 4)?;
+        let month = Self::parse_digits(parts.next()?, 2)?;
+        let day = Self::parse_digits(parts.next()?, 2)?;
+        if parts.next().is_some() {
+            return None;
         }
+
+        let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return None,
+        };
+
+        (1..=days_in_month)
+            .contains(&day)
+            .then_some((year, month, day))
     }
-}
 
-#[allow(deprecated)]
-impl Openable for ExpectFilePath {}
+    /// Parses a time of day into seconds since midnight and any fractional
+    /// nanoseconds.
+    fn parse_time(time: &str) -> Option<(i64, u32)> {
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (time, None),
+        };
+
+        let mut parts = time.split(':');
+        let hour = Self::parse_digits(parts.next()?, 2)?;
+        let minute = Self::parse_digits(parts.next()?, 2)?;
+        let second = Self::parse_digits(parts.next()?, 2)?;
+        if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
 
-#[allow(deprecated)]
-impl Defaultable for ExpectFilePath {}
+        let nanos = match fraction {
+            Some(fraction)
+                if !fraction.is_empty()
+                    && fraction.len() <= 9
+                    && fraction.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                // right-pad to nanosecond precision.
+                format!("{:0<9}", fraction).parse::<u32>().ok()?
+            }
+            Some(_) => return None,
+            None => 0,
+        };
 
-#[allow(deprecated)]
-impl<'a> Evaluatable<'a, &'a [&'a str], String> for ExpectFilePath {
-    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
-        self.inner.evaluate(input)
+        Some((hour * 3600 + minute * 60 + second, nanos))
     }
-}
-
-#[allow(deprecated)]
-impl ShortHelpable for ExpectFilePath {
-    type Output = FlagHelpCollector;
 
-    fn short_help(&self) -> Self::Output {
-        self.inner.short_help()
+    /// Returns the number of days since the unix epoch for a proleptic
+    /// Gregorian calendar date.
+    fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+        // shift the year to begin in March so that leap days fall last.
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month_index = (month + 9) % 12;
+        let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
     }
 }
 
-// Unit type
-
-// This implementation exists mostly for cases where a Cmd, or SubCommands
-// object has no flags associated with it.
-impl<'a> Evaluatable<'a, &'a [&'a str], ()> for () {
-    fn evaluate(&self, _: &'a [&'a str]) -> EvaluateResult<'a, ()> {
-        Ok(Value::new(Span::from_range(0..1), ()))
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], std::time::SystemTime> for TimestampValue {
+    fn evaluate_at(
+        &self,
+        input: &'a [&'a str],
+        pos: usize,
+    ) -> EvaluateResult<'a, std::time::SystemTime> {
+        self.evaluate(&input[pos..])
     }
 }
 
-#[derive(Debug)]
-pub struct FlagWithValue<V> {
-    name: &'static str,
-    short_code: &'static str,
-    description: &'static str,
-    value: V,
+impl<'a> Evaluatable<'a, &'a [&'a str], std::time::SystemTime> for TimestampValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, std::time::SystemTime> {
+        input
+            .first()
+            .and_then(|&v| Self::parse(v))
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
+    }
 }
 
-impl<V> IsFlag for FlagWithValue<V> {}
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], std::time::SystemTime> for TimestampValue {}
 
-impl<V> FlagWithValue<V> {
-    /// Instantiates a new instance of FlagWithValue with a given flag name,
-    /// shortcode and description.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use scrap::prelude::v1::*;
-    /// use scrap::*;
-    ///
-    /// FlagWithValue::new("name", "n", "A name.", StringValue);
-    /// ```
-    #[allow(dead_code)]
-    pub fn new(
-        name: &'static str,
-        short_code: &'static str,
-        description: &'static str,
-        value: V,
-    ) -> Self {
-        Self {
-            name,
-            short_code,
-            description,
-            value,
+impl TakesValue for TimestampValue {}
+
+/// DurationValue represents a terminal flag type, parsing an integer with a
+/// trailing unit suffix of `ns`, `us`, `ms`, `s`, `m`, `h` or `d` into a
+/// `Duration`. Components may be combined, like `1h30m`, provided their units
+/// are in descending order, and are summed into a single `Duration`. To avoid
+/// ambiguity, bare integers are rejected.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), std::time::Duration::from_secs(300))),
+///     FlagWithValue::new("timeout", "t", "A timeout.", DurationValue)
+///         .evaluate(&["hello", "--timeout", "5m"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), std::time::Duration::from_secs(5400))),
+///     FlagWithValue::new("timeout", "t", "A timeout.", DurationValue)
+///         .evaluate(&["hello", "--timeout", "1h30m"][..])
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DurationValue;
+
+impl DurationValue {
+    /// Supported units, from largest to smallest.
+    const UNITS: [&'static str; 7] = ["d", "h", "m", "s", "ms", "us", "ns"];
+
+    /// Parses a single suffixed component into a Duration, returning `None`
+    /// if it overflows.
+    fn component(magnitude: u64, unit: &str) -> Option<std::time::Duration> {
+        use std::time::Duration;
+
+        match unit {
+            "ns" => Some(Duration::from_nanos(magnitude)),
+            "us" => Some(Duration::from_micros(magnitude)),
+            "ms" => Some(Duration::from_millis(magnitude)),
+            "s" => Some(Duration::from_secs(magnitude)),
+            "m" => magnitude.checked_mul(60).map(Duration::from_secs),
+            "h" => magnitude.checked_mul(60 * 60).map(Duration::from_secs),
+            "d" => magnitude.checked_mul(24 * 60 * 60).map(Duration::from_secs),
+            _ => None,
         }
     }
-}
 
-impl<V> Defaultable for FlagWithValue<V> {}
+    /// Parses a possibly compound suffixed duration, returning `None` if it
+    /// is malformed, has units out of descending order, or overflows.
+    fn parse(arg: &str) -> Option<std::time::Duration> {
+        let mut remaining = arg;
+        let mut total = std::time::Duration::ZERO;
+        // the index into UNITS that the next component's unit must follow.
+        let mut next_unit = 0;
 
-impl<V> Openable for FlagWithValue<V> where V: Openable {}
+        if remaining.is_empty() {
+            return None;
+        }
 
-impl<'a, V, B> Evaluatable<'a, &'a [&'a str], B> for FlagWithValue<V>
-where
-    V: PositionalArgumentValue<'a, &'a [&'a str], B>,
-{
-    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, B> {
-        input[..]
-            .iter()
-            .enumerate()
-            .find(|(_, &arg)| {
-                (arg == format!("{}{}", "--", self.name))
-                    || (arg == format!("{}{}", "-", self.short_code))
-            })
-            // Only need the index.
-            .map(|(idx, _)| idx)
-            .and_then(|idx| {
-                self.value
-                    .evaluate_at(input, idx + 1)
-                    .map(|val| val.from_offset(idx + 1))
-                    .map(|v| {
-                        let span = v.span;
-                        let adjusted = Span::from_range(idx..idx + 1).join(span);
-                        Value::new(adjusted, v.value)
-                    })
-                    .ok()
-            })
-            .ok_or_else(|| CliError::FlagEvaluation(self.name.to_string()))
+        while !remaining.is_empty() {
+            let split = remaining
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(remaining.len());
+            let (digits, rest) = remaining.split_at(split);
+            let unit_len = rest
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (unit, rest) = rest.split_at(unit_len);
+
+            if digits.is_empty() {
+                return None;
+            }
+            let magnitude = digits.parse::<u64>().ok()?;
+            let rank = Self::UNITS
+                .iter()
+                .position(|&candidate| candidate == unit)
+                .filter(|&rank| rank >= next_unit)?;
+
+            total = total.checked_add(Self::component(magnitude, unit)?)?;
+            next_unit = rank + 1;
+            remaining = rest;
+        }
+
+        Some(total)
     }
 }
 
-impl<V> ShortHelpable for FlagWithValue<V> {
-    type Output = FlagHelpCollector;
-
-    fn short_help(&self) -> Self::Output {
-        FlagHelpCollector::Single(FlagHelpContext::new(
-            self.name,
-            self.short_code,
-            self.description,
-            Vec::new(),
-        ))
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], std::time::Duration> for DurationValue {
+    fn evaluate_at(
+        &self,
+        input: &'a [&'a str],
+        pos: usize,
+    ) -> EvaluateResult<'a, std::time::Duration> {
+        self.evaluate(&input[pos..])
     }
 }
 
-/// PositionalArgumentValue Provides a value type for evaluating positionally.
-pub trait PositionalArgumentValue<'a, A, B>: Evaluatable<'a, A, B> {
-    fn evaluate_at(&self, input: A, pos: usize) -> EvaluateResult<'a, B>;
+impl<'a> Evaluatable<'a, &'a [&'a str], std::time::Duration> for DurationValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, std::time::Duration> {
+        input
+            .first()
+            .and_then(|v| Self::parse(v))
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
+    }
 }
 
-/// Represents a String argument
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], std::time::Duration> for DurationValue {}
+
+impl TakesValue for DurationValue {}
+
+/// ByteSizeValue represents a terminal flag type, parsing a size in bytes as
+/// a `u64`. An integer may carry an SI suffix of `k`, `M` or `G`, or a binary
+/// suffix of `KiB`, `MiB` or `GiB`, multiplying it accordingly, while a plain
+/// integer is taken as a count of bytes.
 ///
 /// # Example
 ///
@@ -2733,36 +10039,64 @@ pub trait PositionalArgumentValue<'a, A, B>: Evaluatable<'a, A, B> {
 /// use scrap::*;
 ///
 /// assert_eq!(
-///    Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
-///    FlagWithValue::new("name", "n", "A name.", StringValue).evaluate(&["hello", "--name", "foo"][..])
-/// );
-///
-/// assert_eq!(
-///    Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
-///    FlagWithValue::new("name", "n", "A name.", StringValue).evaluate(&["hello", "-n", "foo"][..])
+///     Ok(Value::new(Span::from_range(1..3), 4 * 1024 * 1024)),
+///     FlagWithValue::new("max-size", "m", "A maximum size.", ByteSizeValue)
+///         .evaluate(&["hello", "--max-size", "4MiB"][..])
 /// );
 /// ```
 #[derive(Debug, Clone, Copy)]
-pub struct StringValue;
+pub struct ByteSizeValue;
+
+impl ByteSizeValue {
+    /// Parses a size, returning `None` if it is malformed or overflows.
+    fn parse(arg: &str) -> Option<u64> {
+        let split = arg.find(|c: char| !c.is_ascii_digit()).unwrap_or(arg.len());
+        let (digits, unit) = arg.split_at(split);
+        if digits.is_empty() {
+            return None;
+        }
+        let magnitude = digits.parse::<u64>().ok()?;
+
+        let multiplier: u64 = match unit {
+            "" => 1,
+            "k" => 1000,
+            "M" => 1000 * 1000,
+            "G" => 1000 * 1000 * 1000,
+            "KiB" => 1 << 10,
+            "MiB" => 1 << 20,
+            "GiB" => 1 << 30,
+            _ => return None,
+        };
+
+        magnitude.checked_mul(multiplier)
+    }
+}
 
-impl<'a> PositionalArgumentValue<'a, &'a [&'a str], String> for StringValue {
-    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, String> {
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], u64> for ByteSizeValue {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, u64> {
         self.evaluate(&input[pos..])
     }
 }
 
-impl<'a> Evaluatable<'a, &'a [&'a str], String> for StringValue {
-    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
+impl<'a> Evaluatable<'a, &'a [&'a str], u64> for ByteSizeValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, u64> {
         input
             .first()
-            .map(|v| Value::new(Span::from_range(0..1), v.to_string()))
+            .and_then(|v| Self::parse(v))
+            .map(|v| Value::new(Span::from_range(0..1), v))
             .ok_or(CliError::ValueEvaluation)
     }
 }
 
-impl<'a> TerminalEvaluatable<'a, &'a [&'a str], String> for StringValue {}
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], u64> for ByteSizeValue {}
 
-/// ValueOnMatch represents a terminal flag type, returning a given value on a match.
+impl TakesValue for ByteSizeValue {}
+
+/// IntToEnum represents a terminal flag type, parsing an integer argument and
+/// mapping it to its corresponding value, via a table of discriminant and
+/// value pairs. Arguments that fail to parse as an integer fail evaluation
+/// with a `ValueEvaluation` error, while integers not found in the table fail
+/// with an `InvalidChoice` error.
 ///
 /// # Example
 ///
@@ -2770,54 +10104,139 @@ impl<'a> TerminalEvaluatable<'a, &'a [&'a str], String> for StringValue {}
 /// use scrap::prelude::v1::*;
 /// use scrap::*;
 ///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Level {
+///     Low,
+///     High,
+/// }
+///
+/// let flag = FlagWithValue::new(
+///     "level",
+///     "l",
+///     "A level.",
+///     IntToEnum::new([(0, Level::Low), (1, Level::High)]),
+/// );
+///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..2), false)),
-///     FlagWithValue::new("no-wait", "n", "don't wait for a response.", ValueOnMatch::new(false))
-///         .evaluate(&["hello", "--no-wait"][..])
+///     Ok(Value::new(Span::from_range(1..3), Level::High)),
+///     flag.evaluate(&["hello", "--level", "1"][..])
 /// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..2), false)),
-///     FlagWithValue::new("no-wait", "n", "don't wait for a response.", ValueOnMatch::new(false))
-///         .evaluate(&["hello", "-n"][..])
+///     Err(CliError::InvalidChoice("2".to_string())),
+///     flag.evaluate(&["hello", "--level", "2"][..])
 /// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntToEnum<T, const N: usize> {
+    choices: [(i64, T); N],
+}
+
+impl<T, const N: usize> IntToEnum<T, N> {
+    /// Instantiates a new integer to value mapping from a table of
+    /// discriminant and value pairs.
+    pub fn new(choices: [(i64, T); N]) -> Self {
+        Self { choices }
+    }
+}
+
+impl<'a, T, const N: usize> PositionalArgumentValue<'a, &'a [&'a str], T> for IntToEnum<T, N>
+where
+    T: Clone,
+{
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, T> {
+        self.evaluate(&input[pos..])
+    }
+}
+
+impl<'a, T, const N: usize> Evaluatable<'a, &'a [&'a str], T> for IntToEnum<T, N>
+where
+    T: Clone,
+{
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, T> {
+        let &arg = input.first().ok_or(CliError::ValueEvaluation)?;
+        let discriminant = arg.parse::<i64>().map_err(|_| CliError::ValueEvaluation)?;
+
+        self.choices
+            .iter()
+            .find(|(choice, _)| *choice == discriminant)
+            .map(|(_, value)| Value::new(Span::from_range(0..1), value.clone()))
+            .ok_or_else(|| CliError::InvalidChoice(arg.to_string()))
+    }
+}
+
+impl<'a, T, const N: usize> TerminalEvaluatable<'a, &'a [&'a str], T> for IntToEnum<T, N> where
+    T: Clone
+{
+}
+
+impl<T, const N: usize> TakesValue for IntToEnum<T, N> {}
+
+/// IntOrString represents a value that may be either an integer or an
+/// arbitrary string, as returned by `IntOrStringValue`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntOrString {
+    Int(i64),
+    Str(String),
+}
+
+/// IntOrStringValue represents a terminal flag type for arguments that accept
+/// either an integer or a name, such as an id, to be resolved later. The
+/// argument is parsed as an integer first, falling back to a string if
+/// it isn't one.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let flag = FlagWithValue::new("id", "i", "An id or name.", IntOrStringValue);
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::empty(), true)),
-///     WithDefault::new(
-///         true,
-///         Optional::new(FlagWithValue::new("no-wait", "n", "don't wait for a response.", ValueOnMatch::new(false)))
-///     )
-///     .evaluate(&["hello"][..])
+///     Ok(Value::new(Span::from_range(1..3), IntOrString::Int(42))),
+///     flag.evaluate(&["hello", "--id", "42"][..])
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), IntOrString::Str("foo".to_string()))),
+///     flag.evaluate(&["hello", "--id", "foo"][..])
 /// );
 /// ```
-#[derive(Debug)]
-pub struct ValueOnMatch<V> {
-    value: V,
-}
-
-impl<V> ValueOnMatch<V> {
-    pub fn new(value: V) -> Self {
-        Self { value }
-    }
-}
+#[derive(Debug, Clone, Copy)]
+pub struct IntOrStringValue;
 
-impl<'a, V: Clone> PositionalArgumentValue<'a, &'a [&'a str], V> for ValueOnMatch<V> {
-    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, V> {
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], IntOrString> for IntOrStringValue {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, IntOrString> {
         self.evaluate(&input[pos..])
     }
 }
 
-impl<'a, V: Clone> Evaluatable<'a, &'a [&'a str], V> for ValueOnMatch<V> {
-    fn evaluate(&self, _: &'a [&'a str]) -> EvaluateResult<'a, V> {
-        Ok(Value::new(Span::empty(), self.value.clone()))
+impl<'a> Evaluatable<'a, &'a [&'a str], IntOrString> for IntOrStringValue {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, IntOrString> {
+        input
+            .first()
+            .map(|&v| {
+                v.parse::<i64>()
+                    .map(IntOrString::Int)
+                    .unwrap_or_else(|_| IntOrString::Str(v.to_string()))
+            })
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
     }
 }
 
-impl<'a, V: Clone> TerminalEvaluatable<'a, &'a [&'a str], V> for ValueOnMatch<V> {}
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], IntOrString> for IntOrStringValue {}
 
-/// FileValue represents a terminal flag type, that parses and validates a
-/// file exists in a path. Returning the file path as a String.
+impl TakesValue for IntOrStringValue {}
+
+/// StrictF64Value represents a terminal flag type for strict decimal numbers,
+/// in place of the permissive `F64Value`. A value consists of an
+/// optional sign, at least one digit, an optional fractional part of at least
+/// one digit and an optional exponent, such as `-1.5` or `1e3`. Special
+/// values like `inf` and `nan`, numbers without a leading digit like `.5` and
+/// locale-specific forms like `1,5` are rejected, as is any number too large
+/// to be represented.
 ///
 /// # Example
 ///
@@ -2826,82 +10245,445 @@ impl<'a, V: Clone> TerminalEvaluatable<'a, &'a [&'a str], V> for ValueOnMatch<V>
 /// use scrap::*;
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::from_range(1..3), "/etc/hostname".to_string())),
-///     FlagWithValue::new("file", "f", "A filepath to read", FileValue::new(true, false, true))
-///         .evaluate(&["hello", "--file", "/etc/hostname"][..])
+///     Ok(Value::new(Span::from_range(1..3), 1.5)),
+///     FlagWithValue::new("ratio", "r", "A ratio.", StrictF64Value)
+///         .evaluate(&["hello", "--ratio", "1.5"][..])
 /// );
 ///
 /// assert_eq!(
-///     Ok(Value::new(Span::empty(), "/etc/hostname".to_string())),
-///     WithDefault::new(
-///         "/etc/hostname".to_string(),
-///         Optional::new(FlagWithValue::new("file", "f", "A filepath to read", FileValue::new(true, false, true)))
-///     )
-///     .evaluate(&["hello"][..])
+///     Err(CliError::ValueEvaluation),
+///     StrictF64Value.evaluate(&["nan"][..])
 /// );
 /// ```
 #[derive(Debug, Clone, Copy)]
-pub struct FileValue {
-    readable: bool,
-    writable: bool,
-    exists: bool,
+pub struct StrictF64Value;
+
+impl StrictF64Value {
+    fn parse(number: &str) -> Option<f64> {
+        let (mantissa, exponent) = match number.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (number, None),
+        };
+        let (integer, fraction) = match Self::unsigned(mantissa).split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (Self::unsigned(mantissa), None),
+        };
+
+        let valid = Self::is_digits(integer)
+            && fraction.is_none_or(Self::is_digits)
+            && exponent.is_none_or(|exponent| Self::is_digits(Self::unsigned(exponent)));
+
+        valid
+            .then(|| number.parse::<f64>().ok())
+            .flatten()
+            .filter(|v| v.is_finite())
+    }
+
+    /// Strips a single leading sign from a number.
+    fn unsigned(number: &str) -> &str {
+        number.strip_prefix(['+', '-']).unwrap_or(number)
+    }
+
+    /// Returns true if the passed string consists of one or more ascii
+    /// digits.
+    fn is_digits(digits: &str) -> bool {
+        !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+    }
 }
 
-impl IsFlag for FileValue {}
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], f64> for StrictF64Value {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, f64> {
+        self.evaluate(&input[pos..])
+    }
+}
 
-impl FileValue {
-    /// Instantiates a new instance of FileArgument.
+impl<'a> Evaluatable<'a, &'a [&'a str], f64> for StrictF64Value {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, f64> {
+        input
+            .first()
+            .and_then(|&v| Self::parse(v))
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .ok_or(CliError::ValueEvaluation)
+    }
+}
+
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], f64> for StrictF64Value {}
+
+impl TakesValue for StrictF64Value {}
+
+/// JsonValue represents a parsed JSON document, as returned by
+/// `JsonValueEvaluator`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(std::collections::BTreeMap<String, JsonValue>),
+}
+
+impl JsonValue {
+    /// The maximum depth to which arrays and objects may nest.
+    pub const MAX_DEPTH: usize = 128;
+
+    /// Parses a complete JSON document, returning the character position at
+    /// which parsing failed for malformed input, or for an array or object
+    /// nested deeper than `JsonValue::MAX_DEPTH`.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```
     /// use scrap::prelude::v1::*;
     /// use scrap::*;
     ///
-    /// FileValue::new(true, false, true);
+    /// assert_eq!(
+    ///     Ok(JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Null])),
+    ///     JsonValue::parse("[1, null]")
+    /// );
+    ///
+    /// assert_eq!(Err(4), JsonValue::parse("[1, ]"));
+    ///
+    /// let nested = "[".repeat(JsonValue::MAX_DEPTH + 1);
+    /// assert_eq!(Err(JsonValue::MAX_DEPTH), JsonValue::parse(&nested));
     /// ```
-    #[allow(dead_code)]
-    pub fn new(readable: bool, writable: bool, exists: bool) -> Self {
-        Self {
-            readable,
-            writable,
-            exists,
-        }
+    pub fn parse(input: &str) -> Result<Self, usize> {
+        JsonParser::parse(input).map_err(|e| e.pos)
     }
 }
 
-impl Openable for FileValue {}
+/// JsonError describes the position of a syntax error in a JSON document and
+/// the reason it was rejected.
+struct JsonError {
+    pos: usize,
+    reason: String,
+}
 
-impl Defaultable for FileValue {}
+impl std::fmt::Display for JsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid JSON at position {}: {}", self.pos, self.reason)
+    }
+}
 
-impl<'a> PositionalArgumentValue<'a, &'a [&'a str], String> for FileValue {
-    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, String> {
+/// A minimal recursive descent parser over the characters of a JSON document.
+struct JsonParser {
+    input: Vec<char>,
+    pos: usize,
+    /// The count of arrays and objects enclosing the current position.
+    depth: usize,
+}
+
+impl JsonParser {
+    fn parse(input: &str) -> Result<JsonValue, JsonError> {
+        let mut parser = Self {
+            input: input.chars().collect(),
+            pos: 0,
+            depth: 0,
+        };
+
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos == parser.input.len() {
+            Ok(value)
+        } else {
+            Err(parser.unexpected(parser.pos))
+        }
+    }
+
+    fn error(&self, pos: usize, reason: &str) -> JsonError {
+        JsonError {
+            pos,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Returns the error for the character at `pos`, or the end of the
+    /// input, not being valid at that position.
+    fn unexpected(&self, pos: usize) -> JsonError {
+        match self.input.get(pos) {
+            Some(c) => self.error(pos, &format!("unexpected character {:?}", c)),
+            None => self.error(pos, "unexpected end of input"),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            _ => Err(self.unexpected(self.pos)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(
+            self.peek(),
+            Some(' ') | Some('\t') | Some('\n') | Some('\r')
+        ) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, JsonError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') | Some('[') if self.depth >= JsonValue::MAX_DEPTH => Err(self.error(
+                self.pos,
+                &format!("nested deeper than {} levels", JsonValue::MAX_DEPTH),
+            )),
+            Some('{') => self.parse_nested(Self::parse_object),
+            Some('[') => self.parse_nested(Self::parse_array),
+            Some('"') => self.parse_string().map(JsonValue::String),
+            Some('t') => self.parse_literal("true", JsonValue::Bool(true)),
+            Some('f') => self.parse_literal("false", JsonValue::Bool(false)),
+            Some('n') => self.parse_literal("null", JsonValue::Null),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            _ => Err(self.unexpected(self.pos)),
+        }
+    }
+
+    /// Parses an array or object one level deeper than the current position.
+    fn parse_nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<JsonValue, JsonError>,
+    ) -> Result<JsonValue, JsonError> {
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, JsonError> {
+        for expected in literal.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, JsonError> {
+        let mut members = std::collections::BTreeMap::new();
+        self.expect('{')?;
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            members.insert(key, value);
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(JsonValue::Object(members)),
+                _ => return Err(self.unexpected(self.pos - 1)),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, JsonError> {
+        let mut elements = Vec::new();
+        self.expect('[')?;
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(elements));
+        }
+
+        loop {
+            elements.push(self.parse_value()?);
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(JsonValue::Array(elements)),
+                _ => return Err(self.unexpected(self.pos - 1)),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        let mut value = String::new();
+        self.expect('"')?;
+
+        loop {
+            let pos = self.pos;
+            match self
+                .next()
+                .ok_or_else(|| self.error(pos, "unterminated string"))?
+            {
+                '"' => return Ok(value),
+                '\\' => {
+                    let escaped = match self
+                        .next()
+                        .ok_or_else(|| self.error(pos, "unterminated string"))?
+                    {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => self.parse_unicode_escape(pos)?,
+                        _ => return Err(self.error(pos, "invalid escape sequence")),
+                    };
+                    value.push(escaped);
+                }
+                c if c.is_control() => {
+                    return Err(self.error(pos, "unescaped control character in string"))
+                }
+                c => value.push(c),
+            }
+        }
+    }
+
+    /// Parses the hex digits of a `\u` escape, combining a surrogate pair
+    /// into a single character.
+    fn parse_unicode_escape(&mut self, escape_pos: usize) -> Result<char, JsonError> {
+        let high = self.parse_hex4()?;
+        let code_point = if (0xD800..0xDC00).contains(&high) {
+            self.expect('\\')?;
+            self.expect('u')?;
+            let low = self.parse_hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error(escape_pos, "invalid unicode escape"));
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        std::char::from_u32(code_point)
+            .ok_or_else(|| self.error(escape_pos, "invalid unicode escape"))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, JsonError> {
+        let start = self.pos;
+        let digits: String = self.input.iter().skip(start).take(4).collect();
+        if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(self.error(start, "invalid unicode escape"));
+        }
+
+        self.pos += 4;
+        u32::from_str_radix(&digits, 16).map_err(|_| self.error(start, "invalid unicode escape"))
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, JsonError> {
+        let start = self.pos;
+        let consume_digits = |parser: &mut Self| {
+            let digits_start = parser.pos;
+            while parser.peek().is_some_and(|c| c.is_ascii_digit()) {
+                parser.pos += 1;
+            }
+            parser.pos > digits_start
+        };
+
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+
+        // leading zeros are only valid as the sole integer digit.
+        if self.peek() == Some('0') {
+            self.pos += 1;
+        } else if !consume_digits(self) {
+            return Err(self.unexpected(self.pos));
+        }
+
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            if !consume_digits(self) {
+                return Err(self.unexpected(self.pos));
+            }
+        }
+
+        if let Some('e') | Some('E') = self.peek() {
+            self.pos += 1;
+            if let Some('+') | Some('-') = self.peek() {
+                self.pos += 1;
+            }
+            if !consume_digits(self) {
+                return Err(self.unexpected(self.pos));
+            }
+        }
+
+        self.input[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|_| self.error(start, "invalid number"))
+    }
+}
+
+/// JsonValueEvaluator represents a terminal flag type, that parses a single
+/// argument as an inline JSON document into a `JsonValue`, without requiring
+/// any external dependencies. Malformed documents fail to evaluate with a
+/// `CliError::ValueEvaluationMessage` giving the position of the error and
+/// the reason it was rejected.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let expected = JsonValue::Object(
+///     vec![("a".to_string(), JsonValue::Number(1.0))].into_iter().collect(),
+/// );
+///
+/// assert_eq!(
+///     Ok(Value::new(Span::from_range(1..3), expected)),
+///     FlagWithValue::new("meta", "m", "Inline metadata.", JsonValueEvaluator)
+///         .evaluate(&["hello", "--meta", r#"{"a": 1}"#][..])
+/// );
+///
+/// assert_eq!(
+///     Err(CliError::ValueEvaluationMessage(
+///         "invalid JSON at position 6: unexpected character '}'".to_string()
+///     )),
+///     JsonValueEvaluator.evaluate(&[r#"{"a": }"#][..])
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct JsonValueEvaluator;
+
+impl<'a> PositionalArgumentValue<'a, &'a [&'a str], JsonValue> for JsonValueEvaluator {
+    fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, JsonValue> {
         self.evaluate(&input[pos..])
     }
 }
 
-impl<'a> Evaluatable<'a, &'a [&'a str], String> for FileValue {
-    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, String> {
-        use std::fs::OpenOptions;
+impl<'a> Evaluatable<'a, &'a [&'a str], JsonValue> for JsonValueEvaluator {
+    fn evaluate(&self, input: &'a [&'a str]) -> EvaluateResult<'a, JsonValue> {
+        let arg = input.first().ok_or(CliError::ValueEvaluation)?;
 
-        input
-            .first()
-            // check if the file exists with the corresponding flags.
-            .and_then(|p| {
-                OpenOptions::new()
-                    .read(self.readable)
-                    .write(self.writable)
-                    .create(!self.exists)
-                    .open(p)
-                    .ok()
-                    .map(|_| p)
-            })
-            .map(|&v| Value::new(Span::from_range(0..1), v.to_owned()))
-            .ok_or(CliError::ValueEvaluation)
+        JsonParser::parse(arg)
+            .map(|v| Value::new(Span::from_range(0..1), v))
+            .map_err(|e| CliError::ValueEvaluationMessage(e.to_string()))
     }
 }
 
-impl<'a> TerminalEvaluatable<'a, &'a [&'a str], String> for FileValue {}
+impl<'a> TerminalEvaluatable<'a, &'a [&'a str], JsonValue> for JsonValueEvaluator {}
+
+impl TakesValue for JsonValueEvaluator {}
 
 /// Returns all unused args from an input source as identified by a given Span.
 ///
@@ -2933,12 +10715,127 @@ impl<'a> TerminalEvaluatable<'a, &'a [&'a str], String> for FileValue {}
 ///     val_with_args
 /// );
 /// ```
+///
+/// Unknown flags are never dropped. Any dash-prefixed token that doesn't
+/// match a defined flag is returned in its original position, along with any
+/// token that follows it. Because an unknown flag carries no definition, there
+/// is no way to tell whether the following token is its value or an unrelated
+/// positional argument, so both are returned as separate, ordered, arguments
+/// and it is left to the handler to pair them. See `Cmd::keep_unknown_flags`
+/// for treating unknown flags as positional values.
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let input = ["hello", "--unknown", "x", "-n", "foo", "y"];
+///
+/// let flags = Cmd::new("hello")
+///     .with_flag(FlagWithValue::new("name", "n", "A name.", StringValue))
+///     .evaluate(&input[..])
+///     .unwrap();
+///
+/// let args: Vec<String> = return_unused_args(&input[..], &flags.span)
+///     .into_iter()
+///     .map(|arg| arg.unwrap())
+///     .collect();
+///
+/// assert_eq!(vec!["--unknown", "x", "y"], args);
+/// ```
+///
+/// A standalone `-`, conventionally used to denote stdin, is never treated as
+/// a flag and is always returned as an unused argument.
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let input = ["cat", "-", "-n", "foo"];
+///
+/// let flags = Cmd::new("cat")
+///     .with_flag(FlagWithValue::new("name", "n", "A name.", StringValue))
+///     .evaluate(&input[..])
+///     .unwrap();
+///
+/// assert_eq!(
+///     vec![Value::new(Span::from_range(1..2), "-".to_string())],
+///     return_unused_args(&input[..], &flags.span)
+/// );
+/// ```
 pub fn return_unused_args<'a>(input: &'a [&'a str], matched_span: &Span) -> StringArgs {
+    return_unused_args_with_positions(input, matched_span)
+        .into_iter()
+        .map(|(offset, v)| Value::new(Span::from_range(offset..(offset + 1)), v))
+        .collect()
+}
+
+/// Functions identically to `return_unused_args`, returning each unused
+/// argument paired with its index in the original input, in place of a
+/// single-argument span, for correlating leftovers with their original
+/// positions in diagnostics.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let input = ["hello", "--unknown", "x", "-n", "foo", "y"];
+///
+/// let flags = Cmd::new("hello")
+///     .with_flag(FlagWithValue::new("name", "n", "A name.", StringValue))
+///     .evaluate(&input[..])
+///     .unwrap();
+///
+/// assert_eq!(
+///     vec![
+///         (1, "--unknown".to_string()),
+///         (2, "x".to_string()),
+///         (5, "y".to_string())
+///     ],
+///     return_unused_args_with_positions(&input[..], &flags.span)
+/// );
+/// ```
+pub fn return_unused_args_with_positions(
+    input: &[&str],
+    matched_span: &Span,
+) -> Vec<(usize, String)> {
     let span = &matched_span.0;
     input
         .iter()
         .enumerate()
         .filter(|(offset, _)| !span.contains(offset))
-        .map(|(offset, v)| Value::new(Span::from_range(offset..(offset + 1)), v.to_string()))
+        .map(|(offset, v)| (offset, v.to_string()))
         .collect()
 }
+
+/// Renders the input as a single line of arguments followed by a line of
+/// carets underlining each argument included in the passed Span, similar to
+/// a compiler diagnostic.
+///
+/// # Example
+///
+/// ```
+/// use scrap::prelude::v1::*;
+/// use scrap::*;
+///
+/// let input = ["hello", "-n", "foo", "--port", "abc"];
+///
+/// assert_eq!(
+///     "hello -n foo --port abc\n             ^^^^^^ ^^^",
+///     render_highlight(&input[..], &Span::from_range(3..5))
+/// );
+/// ```
+pub fn render_highlight(input: &[&str], span: &Span) -> String {
+    let underline = input
+        .iter()
+        .enumerate()
+        .map(|(offset, arg)| {
+            let marker = if span.0.contains(&offset) { "^" } else { " " };
+            marker.repeat(arg.chars().count())
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    format!("{}\n{}", input.join(" "), underline.trim_end())
+}
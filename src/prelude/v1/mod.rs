@@ -1,6 +1,9 @@
 /// Provides a wrapper around spanned matching argument values.
 pub use crate::Value;
 
+/// Defines behaviors for acquiring raw arguments from a source.
+pub use crate::ArgSource;
+
 /// Defines behaviors for traits that can default to a if not specified value.
 pub use crate::Defaultable;
 
@@ -11,6 +14,14 @@ pub use crate::Dispatchable;
 /// with passed arguments.
 pub use crate::DispatchableWithArgs;
 
+/// Defines behaviors for types that can dispatch an evaluator to a function
+/// with the complete original arguments.
+pub use crate::DispatchableWithRawArgs;
+
+/// Defines behaviors for types that can dispatch an evaluator to a
+/// single-use function.
+pub use crate::DispatchableOnce;
+
 /// Defines behaviors for types that can dispatch an evaluator to a function
 /// with additional help documentation.
 pub use crate::DispatchableWithHelpString;
@@ -22,9 +33,20 @@ pub use crate::DispatchableWithHelpStringAndArgs;
 /// Defines behaviors for evaluating an input to a given type.
 pub use crate::Evaluatable;
 
+/// Defines behaviors for evaluating an input while reporting the source of
+/// the evaluated value.
+pub use crate::ProvenanceEvaluatable;
+
 /// Defines a marker trait for denoting Cmd-like types.
 pub use crate::IsCmd;
 
+/// Defines behaviors for listing the subcommands of Cmd-like types.
+pub use crate::SubcommandNames;
+
+/// Defines behaviors for copying the structure of Cmd-like types without
+/// their handlers.
+pub use crate::StructureCloneable;
+
 /// Defines a marker trait for denoting flag-like types from non-flag types.
 pub use crate::IsFlag;
 
@@ -32,3 +54,6 @@ pub use crate::IsFlag;
 pub use crate::{Helpable, ShortHelpable};
 
 pub use crate::PositionalArgumentValue;
+
+/// Defines behaviors for value types that may be set by a flag's presence.
+pub use crate::TakesValue;
@@ -0,0 +1,58 @@
+//! Helpers for exercising commands in tests without hand-writing argument
+//! slices. Available to the crate's own tests and, for downstream crates,
+//! behind the `testing` feature.
+
+/// Tokenizes a shell-like command line into its individual arguments.
+/// Whitespace separates arguments, single quotes preserve their contents
+/// literally and double quotes preserve their contents while still honoring
+/// backslash escapes.
+///
+/// Unterminated quotes are closed at the end of the line.
+pub fn args_from_str(line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    // tracks whether an argument has begun, allowing for empty quoted args.
+    let mut in_arg = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_arg = true;
+                for c in chars.by_ref().take_while(|&c| c != '\'') {
+                    current.push(c);
+                }
+            }
+            '"' => {
+                in_arg = true;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => current.extend(chars.next()),
+                        c => current.push(c),
+                    }
+                }
+            }
+            '\\' => {
+                in_arg = true;
+                current.extend(chars.next());
+            }
+            c if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            c => {
+                in_arg = true;
+                current.push(c);
+            }
+        }
+    }
+
+    if in_arg {
+        args.push(current);
+    }
+
+    args
+}
@@ -1,6 +1,7 @@
 use super::*;
 
 #[test]
+#[allow(unused_must_use)]
 fn cmd_should_dispatch_a_valid_handler() {
     let cmd = Cmd::new("test")
         .description("a test cmd")
@@ -90,3 +91,3361 @@ fn should_generate_expected_helpstring_for_with_choices_flag() {
         .to_string()
     )
 }
+
+#[test]
+fn should_find_occurrences_across_long_and_short_forms() {
+    let flag = Flag::store_true("verbose", "v", "verbose output.");
+
+    assert_eq!(
+        vec![1, 2],
+        flag.occurrences(&["test", "--verbose", "-v", "info"][..])
+    );
+}
+
+#[test]
+fn should_evaluate_a_three_tuple_of_mixed_flags() {
+    let flags = (
+        Flag::expect_string("name", "n", "A name."),
+        Flag::expect_u16("port", "p", "A port."),
+        Flag::store_true("debug", "d", "Run command in debug mode.")
+            .optional()
+            .with_default(false),
+    );
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::new(vec![1, 2, 3, 4]),
+            ("foo".to_string(), 8080, false)
+        )),
+        flags.evaluate(&["test", "-n", "foo", "-p", "8080"][..])
+    );
+}
+
+#[test]
+fn should_evaluate_a_five_tuple_of_mixed_flags() {
+    let flags = (
+        Flag::expect_string("name", "n", "A name."),
+        Flag::expect_u16("port", "p", "A port."),
+        Flag::expect_i8("offset", "o", "An offset."),
+        Flag::store_false("no-wait", "w", "don't wait for a response."),
+        Flag::with_choices(
+            "log-level",
+            "l",
+            "A log level.",
+            ["info".to_string(), "warn".to_string()],
+            StringValue,
+        ),
+    );
+
+    let input = [
+        "test", "-l", "warn", "-w", "-o", "-5", "-p", "22", "-n", "foo",
+    ];
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::new(vec![8, 9, 6, 7, 4, 5, 3, 1, 2]),
+            ("foo".to_string(), 22, -5, false, "warn".to_string())
+        )),
+        flags.evaluate(&input[..])
+    );
+
+    // a single missing member fails the whole tuple.
+    assert!(flags.evaluate(&input[..8]).is_err());
+}
+
+#[test]
+fn should_generate_helpstring_for_tuple_of_flags() {
+    assert_eq!(
+        "Usage: test [OPTIONS]\n\nFlags:\n    --name, -n       A name.                                 \n    --port, -p       A port.                                 "
+            .to_string(),
+        Cmd::new("test")
+            .with_flags((
+                Flag::expect_string("name", "n", "A name."),
+                Flag::expect_u16("port", "p", "A port."),
+            ))
+            .help()
+    )
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "default value is not one of the valid choices")]
+fn should_assert_checked_default_is_a_valid_choice() {
+    WithChoices::new(
+        ["info".to_string(), "warn".to_string()],
+        FlagWithValue::new("log-level", "l", "A log level.", StringValue),
+    )
+    .with_default_checked("debug");
+}
+
+#[test]
+fn should_render_help_to_a_writer() {
+    let cmd = Cmd::new("test")
+        .description("a test cmd")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_flag(Flag::store_true("debug", "d", "Run command in debug mode."));
+    let mut buf: Vec<u8> = Vec::new();
+
+    assert!(cmd.render_to(&mut buf).is_ok());
+    assert_eq!(cmd.help(), String::from_utf8(buf).unwrap());
+
+    let group = CmdGroup::new("testgroup")
+        .description("a test group")
+        .with_command(Cmd::new("test_one"))
+        .with_command(Cmd::new("test_two"));
+    let mut buf: Vec<u8> = Vec::new();
+
+    assert!(group.render_to(&mut buf).is_ok());
+    assert_eq!(group.help(), String::from_utf8(buf).unwrap());
+}
+
+#[test]
+fn should_span_store_true_flag_when_joined() {
+    let flags = Join::new(
+        Flag::store_true("debug", "d", "Run command in debug mode."),
+        Flag::expect_string("name", "n", "A name."),
+    );
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::new(vec![3, 1, 2]),
+            (true, "foo".to_string())
+        )),
+        flags.evaluate(&["test", "-n", "foo", "-d"][..])
+    );
+}
+
+#[test]
+fn should_exclude_matched_store_true_flag_from_unused_args() {
+    let input = ["test", "-d", "a"];
+    let cmd = Cmd::new("test").with_flag(
+        Flag::store_true("debug", "d", "Run command in debug mode.")
+            .optional()
+            .with_default(false),
+    );
+
+    let flags = cmd.evaluate(&input[..]).unwrap();
+    assert_eq!(Span::new(vec![0, 1]), flags.span);
+    assert_eq!(
+        vec![Value::new(Span::from_range(2..3), "a".to_string())],
+        return_unused_args(&input[..], &flags.span)
+    );
+
+    // an unmatched optional flag contributes nothing to the span.
+    let input = ["test", "a"];
+    let flags = cmd.evaluate(&input[..]).unwrap();
+    assert_eq!(Span::new(vec![0]), flags.span);
+    assert_eq!(
+        vec![Value::new(Span::from_range(1..2), "a".to_string())],
+        return_unused_args(&input[..], &flags.span)
+    );
+}
+
+#[test]
+fn should_route_requested_help_to_stdout_and_error_help_to_stderr() {
+    let cmd = || {
+        Cmd::new("test")
+            .description("a test cmd")
+            .with_flag(Flag::expect_string("name", "n", "A name."))
+            .with_handler(|name| name)
+    };
+    let help = format!("{}\n", cmd().help());
+
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    assert_eq!(
+        Ok(None),
+        cmd().run_to(&["test", "--help"][..], &mut out, &mut err)
+    );
+    assert_eq!(help, String::from_utf8(out).unwrap());
+    assert!(err.is_empty());
+
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    assert_eq!(
+        Err(CliError::FlagEvaluation("name".to_string())),
+        cmd().run_to(&["test"][..], &mut out, &mut err)
+    );
+    assert!(out.is_empty());
+    assert_eq!(
+        format!("unable to evaluate flag: name\n\n{}", help),
+        String::from_utf8(err).unwrap()
+    );
+}
+
+#[test]
+fn should_route_help_to_overridden_streams() {
+    let cmd = || {
+        Cmd::new("test")
+            .with_flag(Flag::expect_string("name", "n", "A name."))
+            .with_handler(|name| name)
+            .requested_help_to(HelpStream::Stderr)
+            .error_help_to(HelpStream::Stdout)
+    };
+
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    assert_eq!(
+        Ok(None),
+        cmd().run_to(&["test", "-h"][..], &mut out, &mut err)
+    );
+    assert!(out.is_empty() && !err.is_empty());
+
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    assert!(cmd().run_to(&["test"][..], &mut out, &mut err).is_err());
+    assert!(!out.is_empty() && err.is_empty());
+}
+
+#[test]
+fn should_not_intercept_help_flags_defined_by_the_command() {
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("host", "h", "A host."))
+        .with_handler(|host| host);
+
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    assert_eq!(
+        Ok(Some("localhost".to_string())),
+        cmd.run_to(&["test", "-h", "localhost"][..], &mut out, &mut err)
+    );
+    assert!(out.is_empty() && err.is_empty());
+}
+
+#[test]
+fn should_sort_help_flags_by_long_name_only_when_enabled() {
+    let cmd = |style| {
+        Cmd::new("test")
+            .help_style(style)
+            .with_flag(
+                Flag::store_true("b", "b", "the b flag.")
+                    .optional()
+                    .with_default(false),
+            )
+            .with_flag(
+                Flag::store_true("a", "a", "the a flag.")
+                    .optional()
+                    .with_default(false),
+            )
+            .with_handler(|_| {})
+    };
+
+    let sorted = cmd(HelpStyle::new().sort_flags(true)).help();
+    assert!(sorted.find("--a").unwrap() < sorted.find("--b").unwrap());
+
+    let unsorted = cmd(HelpStyle::new()).help();
+    assert!(unsorted.find("--b").unwrap() < unsorted.find("--a").unwrap());
+
+    // evaluation order is unaffected by sorting.
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..2), (true, false))),
+        cmd(HelpStyle::new().sort_flags(true)).evaluate(&["test", "-b"][..])
+    );
+}
+
+#[test]
+fn should_return_missing_value_for_trailing_flag() {
+    assert_eq!(
+        Err(CliError::MissingValue("name".to_string())),
+        Flag::expect_string("name", "n", "A name.").evaluate(&["x", "--name"][..])
+    );
+}
+
+#[test]
+fn should_tokenize_shell_style_args() {
+    use crate::testing::args_from_str;
+
+    assert_eq!(
+        vec!["bin".to_string(), "-n".to_string(), "a b".to_string()],
+        args_from_str("bin -n 'a b'")
+    );
+    assert_eq!(
+        vec!["bin", "say \"hi\"", "", "x y"],
+        args_from_str(r#"bin "say \"hi\"" '' x\ y"#)
+    );
+
+    let args = args_from_str("test --name 'foo bar'");
+    let args = args.iter().map(String::as_str).collect::<Vec<&str>>();
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), "foo bar".to_string())),
+        Flag::expect_string("name", "n", "A name.").evaluate(&args[..])
+    );
+}
+
+#[test]
+fn should_overwrite_duplicate_map_keys_unless_strict() {
+    let flag = Flag::expect_map("labels", "l", "labels to apply.", StringValue);
+    let input = ["test", "-l", "a=1,a=2"];
+
+    let overwritten = flag.evaluate(&input[..]).map(|v| v.unwrap());
+    assert_eq!(Some("2"), overwritten.unwrap().get("a").map(String::as_str));
+
+    let strict = FlagWithValue::new(
+        "labels",
+        "l",
+        "labels to apply.",
+        MapValue::new(StringValue).strict_keys(),
+    );
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "labels".to_string(),
+            value: "a=1,a=2".to_string(),
+            message: None,
+        }),
+        strict.evaluate(&input[..])
+    );
+}
+
+#[test]
+fn should_count_matched_arguments_in_span() {
+    let input = ["test", "-n", "foo", "extra"];
+    let span = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|name| name)
+        .evaluate(&input[..])
+        .unwrap()
+        .span;
+
+    assert_eq!(3, span.len());
+    assert_eq!(0.75, span.coverage(input.len()));
+
+    let empty = Span::empty();
+    assert_eq!(0, empty.len());
+    assert!(empty.is_empty());
+    assert_eq!(0.0, empty.coverage(input.len()));
+}
+
+#[cfg(unix)]
+#[test]
+fn should_reject_secret_files_accessible_by_others() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let write_secret = |name: &str, mode: u32| {
+        let path = std::env::temp_dir().join(format!("scrap-{}-{}", std::process::id(), name));
+        std::fs::write(&path, "hunter2\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+        path.to_str().unwrap().to_string()
+    };
+
+    let private = write_secret("private-secret", 0o600);
+    let shared = write_secret("shared-secret", 0o644);
+    let flag = FlagWithValue::new("token-file", "t", "A token file.", SecretFileValue);
+
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), "hunter2".to_string())),
+        flag.evaluate(&["test", "-t", private.as_str()][..])
+    );
+
+    let shared_res = flag.evaluate(&["test", "-t", shared.as_str()][..]);
+    assert!(matches!(shared_res, Err(CliError::Io(msg)) if msg.contains("644")));
+
+    let _ = std::fs::remove_file(private);
+    let _ = std::fs::remove_file(shared);
+}
+
+#[test]
+fn should_list_available_subcommands_when_required_subcommand_is_missing() {
+    let group = CmdGroup::new("test_group")
+        .subcommand_required()
+        .with_command(Cmd::new("test_one").with_handler(|_| {}))
+        .with_command(Cmd::new("test_two").with_handler(|_| {}));
+
+    let expected = CliError::MissingSubcommand {
+        available: vec!["test_one".to_string(), "test_two".to_string()],
+    };
+    assert_eq!(
+        Err(expected.clone()),
+        group.evaluate(&["test_group", "unknown"][..])
+    );
+    assert_eq!(Err(expected.clone()), group.evaluate(&["test_group"][..]));
+    assert_eq!(
+        "a subcommand is required, available: test_one, test_two",
+        format!("{}", expected)
+    );
+    assert!(group.evaluate(&["test_group", "test_two"][..]).is_ok());
+}
+
+#[test]
+fn should_tolerate_absent_right_side_of_join_optional() {
+    let flags = JoinOptional::new(
+        Flag::expect_string("name", "n", "A name."),
+        Flag::expect_u8("count", "c", "A count."),
+    );
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..3),
+            ("foo".to_string(), None)
+        )),
+        flags.evaluate(&["test", "-n", "foo"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..5),
+            ("foo".to_string(), Some(3))
+        )),
+        flags.evaluate(&["test", "-n", "foo", "-c", "3"][..])
+    );
+    assert!(flags.short_help().to_string().contains("(optional)"));
+}
+
+#[test]
+fn should_render_env_and_default_modifiers_innermost_first() {
+    let flag = Flag::expect_string("name", "n", "A name.")
+        .with_env("MYAPP_NAME")
+        .optional()
+        .with_default("foo".to_string());
+
+    let help = flag.short_help().to_string();
+    assert!(help.ends_with("[(env: MYAPP_NAME), (optional), (default: \"foo\")]"));
+}
+
+#[test]
+fn should_parse_inline_json_values() {
+    let flag = Flag::expect_json("meta", "m", "Inline metadata.");
+
+    let object = flag
+        .evaluate(
+            &[
+                "test",
+                "-m",
+                r#"{"name": "a\"b", "tags": [], "nested": {"ok": false}}"#,
+            ][..],
+        )
+        .map(|v| v.unwrap());
+    let expected_object: std::collections::BTreeMap<String, JsonValue> = vec![
+        ("name".to_string(), JsonValue::String("a\"b".to_string())),
+        ("tags".to_string(), JsonValue::Array(vec![])),
+        (
+            "nested".to_string(),
+            JsonValue::Object(
+                vec![("ok".to_string(), JsonValue::Bool(false))]
+                    .into_iter()
+                    .collect(),
+            ),
+        ),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(Ok(JsonValue::Object(expected_object)), object);
+
+    let array = flag
+        .evaluate(&["test", "-m", r#"[1, -2.5e1, "é", null]"#][..])
+        .map(|v| v.unwrap());
+    assert_eq!(
+        Ok(JsonValue::Array(vec![
+            JsonValue::Number(1.0),
+            JsonValue::Number(-25.0),
+            JsonValue::String("é".to_string()),
+            JsonValue::Null,
+        ])),
+        array
+    );
+}
+
+#[test]
+fn should_reject_malformed_json_with_position() {
+    assert_eq!(Err(7), JsonValue::parse(r#"{"a": 1"#));
+    assert_eq!(Err(1), JsonValue::parse("01"));
+    assert_eq!(Err(3), JsonValue::parse("[1,]"));
+
+    let meta = |arg| {
+        Flag::expect_json("meta", "m", "Inline metadata.")
+            .evaluate(&["test", "-m", arg][..])
+            .map_err(|e| e.to_string())
+    };
+    assert_eq!(
+        Err(r#"invalid JSON at position 3: unexpected character ']'"#.to_string()),
+        meta("[1,]")
+    );
+    assert_eq!(
+        Err(r#"invalid JSON at position 2: invalid escape sequence"#.to_string()),
+        meta(r#"["\q"]"#)
+    );
+    assert_eq!(
+        Err("invalid JSON at position 5: unterminated string".to_string()),
+        meta(r#"["abc"#)
+    );
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "meta".to_string(),
+            value: "{".to_string(),
+            message: Some("invalid JSON at position 1: unexpected end of input".to_string()),
+        }),
+        Flag::expect_json("meta", "m", "Inline metadata.").evaluate(&["test", "-m", "{"][..])
+    );
+}
+
+#[test]
+fn should_collect_positionals_preceding_first_flag() {
+    let input = ["a", "b", "c", "--flag"];
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..3),
+            vec!["a".to_string(), "b".to_string(), "c".to_string()]
+        )),
+        PositionalsUntilFlag.evaluate(&input[..])
+    );
+
+    let positionals = Cmd::new("tool")
+        .with_flag(PositionalsUntilFlag)
+        .with_handler(|files| files)
+        .evaluate(&["tool", "a", "b", "--flag"][..]);
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..3),
+            vec!["a".to_string(), "b".to_string()]
+        )),
+        positionals
+    );
+}
+
+#[test]
+fn should_document_positionals_in_synopsis_and_arguments_section() {
+    let help = Cmd::new("test")
+        .description("a test cmd")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_positional("file", 0, StringValue)
+        .with_handler(|(name, file)| (name, file))
+        .help();
+
+    let synopsis = help.lines().next().unwrap();
+    assert_eq!("Usage: test [OPTIONS] <file>", synopsis);
+
+    let arguments = help.split("Arguments:\n").nth(1).unwrap();
+    assert!(arguments.starts_with("    <file>\nFlags:\n"));
+}
+
+#[test]
+fn should_require_at_least_one_of_two_flags() {
+    let flags = AtLeastOne::new(
+        Flag::expect_string("input", "i", "An input file."),
+        Flag::store_true("stdin", "s", "Read from stdin."),
+    );
+
+    let neither = flags.evaluate(&["test"][..]);
+    assert_eq!(
+        Err(CliError::MissingRequiredFlag(vec![
+            "input".to_string(),
+            "stdin".to_string()
+        ])),
+        neither
+    );
+    assert_eq!(
+        "at least one of the following flags is required: --input, --stdin",
+        neither.unwrap_err().to_string()
+    );
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..3),
+            (Some("a.txt".to_string()), None)
+        )),
+        flags.evaluate(&["test", "-i", "a.txt"][..])
+    );
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..4),
+            (Some("a.txt".to_string()), Some(true))
+        )),
+        flags.evaluate(&["test", "-i", "a.txt", "-s"][..])
+    );
+}
+
+#[test]
+fn should_leave_flags_after_first_positional_unevaluated_with_trailing_var_arg() {
+    let input = ["run", "cmd", "--not-my-flag"];
+    let cmd = || {
+        Cmd::new("run").with_flag(
+            Flag::store_true("not-my-flag", "n", "A flag of run.")
+                .optional()
+                .with_default(false),
+        )
+    };
+
+    let flags = cmd()
+        .trailing_var_arg()
+        .with_handler(|flag| flag)
+        .evaluate(&input[..])
+        .unwrap();
+    assert!(!flags.value);
+    assert_eq!(
+        vec!["cmd".to_string(), "--not-my-flag".to_string()],
+        return_unused_args(&input[..], &flags.span)
+            .into_iter()
+            .map(|arg| arg.unwrap())
+            .collect::<Vec<String>>()
+    );
+
+    // without trailing_var_arg the flag is evaluated wherever it appears.
+    let flags = cmd()
+        .with_handler(|flag| flag)
+        .evaluate(&input[..])
+        .unwrap();
+    assert!(flags.value);
+}
+
+#[test]
+fn should_validate_across_evaluated_flags() {
+    let cmd = || {
+        Cmd::new("test")
+            .with_flag(Flag::expect_u8("min", "m", "A minimum."))
+            .with_flag(Flag::expect_u8("max", "x", "A maximum."))
+            .with_validator(|(min, max): &(u8, u8)| {
+                if min <= max {
+                    Ok(())
+                } else {
+                    Err("--max must be greater than or equal to --min".to_string())
+                }
+            })
+            .with_handler(|(min, max)| max - min)
+    };
+
+    assert_eq!(
+        Err(CliError::Validation(
+            "--max must be greater than or equal to --min".to_string()
+        )),
+        cmd().evaluate(&["test", "--min", "5", "--max", "3"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..5), (3, 5))),
+        cmd().evaluate(&["test", "--min", "3", "--max", "5"][..])
+    );
+}
+
+#[test]
+fn should_color_help_only_when_enabled() {
+    let cmd = |style| {
+        Cmd::new("test")
+            .help_style(style)
+            .with_flag(Flag::expect_string("name", "n", "A name."))
+    };
+
+    let colored = cmd(HelpStyle::new().color(true)).help_auto();
+    assert!(colored.contains("\x1b[1mFlags:\x1b[0m"));
+
+    let plain = cmd(HelpStyle::new().color(false)).help_auto();
+    assert!(!plain.contains('\x1b'));
+
+    // explicitly rendered help honors the forced setting.
+    assert!(cmd(HelpStyle::new().color(true)).help().contains('\x1b'));
+    assert!(!cmd(HelpStyle::new()).help().contains('\x1b'));
+}
+
+#[test]
+fn should_truncate_long_choice_lists_in_help() {
+    let mut choices = [0u8; 20];
+    for (idx, choice) in choices.iter_mut().enumerate() {
+        *choice = idx as u8;
+    }
+
+    let flag = WithChoices::new(
+        choices,
+        FlagWithValue::new("level", "l", "A level.", U8Value),
+    )
+    .choices_help_limit(3);
+
+    let help = flag.short_help().to_string();
+    assert!(help.contains("(choices: [0, 1, 2, ... (+17 more)])"));
+
+    // every choice remains valid for evaluation.
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), 19)),
+        flag.evaluate(&["test", "-l", "19"][..])
+    );
+}
+
+#[test]
+fn should_return_the_flag_spelling_the_user_supplied() {
+    let cmd = || {
+        Cmd::new("test")
+            .with_flag(WhichAlias::new(Flag::store_true(
+                "verbose",
+                "v",
+                "enable verbose output.",
+            )))
+            .with_handler(|(alias, _)| alias)
+    };
+
+    assert_eq!(
+        Ok(Some("--verbose".to_string())),
+        cmd().run_to(&["test", "--verbose"][..], &mut Vec::new(), &mut Vec::new())
+    );
+    assert_eq!(
+        Ok(Some("-v".to_string())),
+        cmd().run_to(&["test", "-v"][..], &mut Vec::new(), &mut Vec::new())
+    );
+}
+
+#[test]
+fn should_classify_each_error_variant_by_kind() {
+    let usage = [
+        CliError::AmbiguousCommand,
+        CliError::FlagEvaluation("name".to_string()),
+        CliError::FlagEvaluationMessage {
+            flag: "name".to_string(),
+            message: "provide a name".to_string(),
+        },
+        CliError::MissingValue("name".to_string()),
+        CliError::MissingSubcommand { available: vec![] },
+        CliError::MissingRequiredFlag(vec!["name".to_string()]),
+        CliError::TooManyOccurrences {
+            flag: "verbose".to_string(),
+            max: 1,
+        },
+        CliError::MutuallyExclusive(vec!["a".to_string(), "b".to_string()]),
+        CliError::MissingDependency {
+            present: vec!["a".to_string()],
+            missing: vec!["b".to_string()],
+        },
+        CliError::WrongArgCount {
+            expected: "1..=3".to_string(),
+            got: 0,
+        },
+    ];
+    for e in usage.iter() {
+        assert_eq!(ErrorKind::Usage, e.kind(), "{:?}", e);
+    }
+
+    assert_eq!(ErrorKind::Value, CliError::ValueEvaluation.kind());
+    assert_eq!(
+        ErrorKind::Value,
+        CliError::ValueEvaluationMessage("invalid".to_string()).kind()
+    );
+    assert_eq!(
+        ErrorKind::Value,
+        CliError::InvalidChoice("foo".to_string()).kind()
+    );
+    assert_eq!(ErrorKind::Io, CliError::Io("denied".to_string()).kind());
+}
+
+#[test]
+fn should_only_print_usage_for_usage_errors() {
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    let res = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_validator(|_: &String| Err::<(), _>("bad".to_string()))
+        .with_handler(|name| name)
+        .run_to(&["test"][..], &mut out, &mut err);
+    assert!(res.is_err());
+    assert!(String::from_utf8(err).unwrap().contains("Usage:"));
+
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    let res = Cmd::new("test")
+        .with_flag(WithChoices::new(
+            ["a".to_string()],
+            Flag::expect_string("name", "n", "A name."),
+        ))
+        .with_handler(|name| name)
+        .run_to(&["test", "-n", "b"][..], &mut out, &mut err);
+    assert_eq!(Some(ErrorKind::Value), res.err().map(|e| e.kind()));
+    assert!(!String::from_utf8(err).unwrap().contains("Usage:"));
+}
+
+#[test]
+fn should_greedily_consume_values_until_next_flag() {
+    let input = ["test", "--tags", "a", "b", "c", "--name", "x"];
+    let flags = Cmd::new("test")
+        .with_flag(FlagWithValue::new(
+            "tags",
+            "t",
+            "tags to apply.",
+            Greedy::new(StringValue),
+        ))
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|(tags, name)| (tags, name))
+        .evaluate(&input[..]);
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..7),
+            (
+                vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                "x".to_string()
+            )
+        )),
+        flags
+    );
+}
+
+#[test]
+fn should_write_nothing_on_error_with_quiet_errors() {
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    let res = Cmd::new("test")
+        .quiet_errors()
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|name| name)
+        .run_to(&["test"][..], &mut out, &mut err);
+
+    assert_eq!(Err(CliError::FlagEvaluation("name".to_string())), res);
+    assert!(out.is_empty() && err.is_empty());
+}
+
+#[test]
+fn should_sort_spans_by_start_index() {
+    let mut spans = vec![
+        Span::from_range(5..6),
+        Span::new(vec![4, 0]),
+        Span::from_range(2..4),
+        Span::from_range(2..3),
+    ];
+    spans.sort();
+
+    assert_eq!(
+        vec![
+            Span::new(vec![4, 0]),
+            Span::from_range(2..3),
+            Span::from_range(2..4),
+            Span::from_range(5..6),
+        ],
+        spans
+    );
+}
+
+#[test]
+fn should_require_explicit_sign_for_signed_required_value() {
+    let value = SignedRequiredValue::new(I32Value);
+
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), 5)),
+        value.evaluate(&["+5"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), -5)),
+        value.evaluate(&["-5"][..])
+    );
+    assert_eq!(Err(CliError::ValueEvaluation), value.evaluate(&["5"][..]));
+}
+
+#[test]
+fn should_list_env_vars_in_environment_help_section() {
+    let help = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name.").with_env("MYAPP_NAME"))
+        .with_flag(Flag::expect_u8("count", "c", "A count."))
+        .help();
+
+    let environment = help.split("Environment:\n").nth(1).unwrap();
+    assert_eq!(
+        format!("    {:<16} {}", "MYAPP_NAME", "A name."),
+        environment
+    );
+
+    let help = Cmd::new("test")
+        .with_flag(Flag::expect_u8("count", "c", "A count."))
+        .help();
+    assert!(!help.contains("Environment:"));
+}
+
+#[test]
+fn should_cap_flag_occurrences() {
+    let flag = Flag::store_true("verbose", "v", "verbose output.").max_occurrences(3);
+
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..2), true)),
+        flag.evaluate(&["test", "-v", "-v", "--verbose"][..])
+    );
+    assert_eq!(
+        Err(CliError::TooManyOccurrences {
+            flag: "verbose".to_string(),
+            max: 3
+        }),
+        flag.evaluate(&["test", "-v", "-v", "--verbose", "-v"][..])
+    );
+}
+
+#[test]
+fn should_align_subcommand_descriptions_to_longest_name() {
+    let help = CmdGroup::new("test_group")
+        .with_command(Cmd::new("ls").description("list entries"))
+        .with_command(Cmd::new("a-very-long-subcommand-name").description("does something else"))
+        .help();
+
+    let rows = help
+        .split("Subcommands:\n")
+        .nth(1)
+        .unwrap()
+        .lines()
+        .collect::<Vec<&str>>();
+    assert_eq!(
+        vec![
+            "ls                          list entries",
+            "a-very-long-subcommand-name does something else",
+        ],
+        rows
+    );
+}
+
+#[test]
+fn should_never_match_a_standalone_dash_as_a_flag() {
+    let input = ["test", "-", "--verbose"];
+    let flag = Flag::store_true("verbose", "", "verbose output.");
+
+    assert_eq!(vec![2], flag.occurrences(&input[..]));
+    assert!(flag.occurrences(&["test", "-"][..]).is_empty());
+
+    let flags = Cmd::new("test")
+        .with_flag(flag)
+        .evaluate(&input[..])
+        .unwrap();
+    assert_eq!(
+        vec![Value::new(Span::from_range(1..2), "-".to_string())],
+        return_unused_args(&input[..], &flags.span)
+    );
+
+    assert_eq!(
+        Ok(Value::new(Span::empty(), None)),
+        OptionalValue::new("verbose", "", "verbose output.", StringValue)
+            .optional()
+            .evaluate(&["test", "-"][..])
+            .map(|v| v.map(|inner| inner.flatten()))
+    );
+}
+
+#[test]
+fn should_match_flags_identically_to_formatted_comparison() {
+    let input = [
+        "test",
+        "--name",
+        "foo",
+        "-n",
+        "-",
+        "--",
+        "---name",
+        "--n",
+        "-name",
+        "n",
+        "--name=x",
+        "-nn",
+        "--verbose",
+        "-v",
+    ];
+    let flags = [
+        Flag::expect_string("name", "n", "A name."),
+        Flag::expect_string("verbose", "v", "verbose output."),
+        Flag::expect_string("-name", "-n", "An odd name."),
+    ];
+    let reference = |name: &str, short_code: &str| {
+        input
+            .iter()
+            .enumerate()
+            .filter(|(_, &arg)| {
+                arg != "-"
+                    && (arg == format!("--{}", name)
+                        || arg == format!("-{}", short_code)
+                        || arg.starts_with(&format!("--{}=", name))
+                        || arg.starts_with(&format!("-{}=", short_code)))
+            })
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>()
+    };
+
+    assert_eq!(reference("name", "n"), flags[0].occurrences(&input[..]));
+    assert_eq!(reference("verbose", "v"), flags[1].occurrences(&input[..]));
+    assert_eq!(reference("-name", "-n"), flags[2].occurrences(&input[..]));
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), "foo".to_string())),
+        flags[0].evaluate(&input[..])
+    );
+}
+
+#[test]
+fn should_apply_hidden_default_without_advertising_it() {
+    let cmd = Cmd::new("test")
+        .with_flag(
+            Flag::expect_u8("workers", "w", "A worker count.")
+                .optional()
+                .hidden_default(4),
+        )
+        .with_handler(|workers| workers);
+
+    assert!(!cmd.help().contains("default"));
+    assert!(cmd.help().contains("(optional)"));
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), 4)),
+        cmd.evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_parse_rfc3339_timestamps() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let eval = |v: &'static str| TimestampValue.evaluate(&[v][..]).map(|v| v.unwrap());
+
+    assert_eq!(
+        Ok(UNIX_EPOCH + Duration::new(1_672_628_645, 500_000_000)),
+        eval("2023-01-02T03:04:05.5Z")
+    );
+    assert_eq!(
+        Ok(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+        eval("2000-02-29")
+    );
+    assert_eq!(
+        Ok(UNIX_EPOCH - Duration::from_secs(86_400)),
+        eval("1969-12-31")
+    );
+
+    for malformed in [
+        "2023-13-01",
+        "2023-1-02",
+        "2023-01-02T24:00:00Z",
+        "2023-01-02T03:04",
+        "2023-01-02T03:04:05+01:00",
+        "yesterday",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            Err(CliError::ValueEvaluation),
+            eval(malformed),
+            "{}",
+            malformed
+        );
+    }
+}
+
+#[test]
+fn should_reject_more_than_one_conflicting_flag() {
+    let cmd = || {
+        Cmd::new("test")
+            .with_flag(Flag::store_true("json", "j", "Output json.").optional())
+            .with_flag(Flag::store_true("yaml", "y", "Output yaml.").optional())
+            .with_flag(Flag::store_true("toml", "t", "Output toml.").optional())
+            .conflicts(&["json", "yaml", "toml"])
+            .with_handler(|_| {})
+    };
+
+    assert_eq!(
+        Err(CliError::MutuallyExclusive(vec![
+            "json".to_string(),
+            "toml".to_string()
+        ])),
+        cmd().evaluate(&["test", "--json", "-t"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..2),
+            ((None, Some(true)), None)
+        )),
+        cmd().evaluate(&["test", "-y"][..])
+    );
+}
+
+#[test]
+fn should_generate_help_from_a_cloned_structure() {
+    let cmd = Cmd::new("test")
+        .description("a test cmd")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_flag(Flag::store_true("verbose", "v", "Enable verbose output.").optional())
+        .with_handler(|(name, _)| name);
+
+    let help = cmd.help();
+    let structure = cmd.clone_structure();
+    let flags = cmd.evaluate(&["test", "-n", "foo"][..]).unwrap();
+    assert_eq!("foo".to_string(), cmd.dispatch(flags));
+
+    assert_eq!(help, structure.help());
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..3),
+            ("foo".to_string(), None)
+        )),
+        structure.evaluate(&["test", "-n", "foo"][..])
+    );
+}
+
+#[test]
+fn should_generate_help_from_a_cloned_group_structure() {
+    let remote = CmdGroup::new("remote")
+        .description("manage remotes")
+        .with_command(
+            Cmd::new("add")
+                .description("add a remote")
+                .with_flag(Flag::expect_string("url", "u", "A remote url."))
+                .with_handler(|url| url),
+        );
+    let status = Cmd::new("status")
+        .description("show status")
+        .with_handler(|_| "clean".to_string());
+    let group = CmdGroup::new("tool")
+        .description("a tool")
+        .subcommand_required()
+        .with_command(OneOf::new(remote, status));
+
+    let help = group.help();
+    let model = group.tree_model();
+    let structure = group.clone_structure();
+    let flags = group
+        .evaluate(&["tool", "remote", "add", "-u", "origin"][..])
+        .unwrap();
+    assert_eq!("origin".to_string(), group.dispatch(flags));
+
+    assert_eq!(help, structure.help());
+    assert_eq!(model, structure.tree_model());
+    assert_eq!(
+        Err(CliError::MissingSubcommand {
+            available: vec!["remote".to_string(), "status".to_string()]
+        }),
+        structure.evaluate(&["tool"][..])
+    );
+}
+
+#[test]
+fn should_map_integers_to_enum_variants() {
+    #[derive(Debug, Clone, PartialEq)]
+    enum Level {
+        Low,
+        Medium,
+        High,
+    }
+
+    let flag = FlagWithValue::new(
+        "level",
+        "l",
+        "A level.",
+        IntToEnum::new([(0, Level::Low), (1, Level::Medium), (2, Level::High)]),
+    );
+    let eval = |v: &'static str| flag.evaluate(&["test", "-l", v][..]).map(|v| v.unwrap());
+
+    assert_eq!(Ok(Level::Low), eval("0"));
+    assert_eq!(Ok(Level::Medium), eval("1"));
+    assert_eq!(Ok(Level::High), eval("2"));
+    assert_eq!(Err(CliError::InvalidChoice("9".to_string())), eval("9"));
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "level".to_string(),
+            value: "high".to_string(),
+            message: None,
+        }),
+        eval("high")
+    );
+}
+
+#[test]
+fn should_return_unused_args_at_their_original_positions() {
+    let input = ["test", "a", "-n", "foo", "b", "--unknown", "c"];
+    let flags = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|name| name)
+        .evaluate(&input[..])
+        .unwrap();
+
+    let unused = return_unused_args_with_positions(&input[..], &flags.span);
+    assert_eq!(
+        vec![
+            (1, "a".to_string()),
+            (4, "b".to_string()),
+            (5, "--unknown".to_string()),
+            (6, "c".to_string())
+        ],
+        unused
+    );
+    for (idx, arg) in unused {
+        assert_eq!(input[idx], arg);
+    }
+}
+
+#[test]
+fn should_prefer_integers_when_accepting_an_integer_or_string() {
+    let flag = Flag::expect_one_of_types("id", "i", "An id or name.");
+    let eval = |v: &'static str| flag.evaluate(&["test", "--id", v][..]).map(|v| v.unwrap());
+
+    assert_eq!(Ok(IntOrString::Int(42)), eval("42"));
+    assert_eq!(Ok(IntOrString::Int(-7)), eval("-7"));
+    assert_eq!(Ok(IntOrString::Str("web-01".to_string())), eval("web-01"));
+    assert_eq!(
+        Err(CliError::MissingValue("id".to_string())),
+        flag.evaluate(&["test", "--id"][..])
+    );
+}
+
+#[test]
+fn should_dispatch_matched_subcommand_help_from_a_group() {
+    let group = || {
+        CmdGroup::new("testgroup")
+            .description("a test group")
+            .with_command(
+                Cmd::new("test_one")
+                    .description("first test cmd")
+                    .with_flag(Flag::expect_string("name", "n", "A name."))
+                    .with_helpstring_handler(|help, _| help),
+            )
+            .with_command(
+                Cmd::new("test_two")
+                    .description("second test cmd")
+                    .with_flag(Flag::expect_u8("count", "c", "A count."))
+                    .with_helpstring_handler(|help, _| help),
+            )
+    };
+    let group_help = group().help();
+
+    let input = ["testgroup", "test_two", "-c", "5"];
+    let flag_values = group().evaluate(&input[..]).unwrap();
+    let help = group().dispatch_with_helpstring(flag_values);
+
+    assert_ne!(group_help, help);
+    assert!(help.contains("second test cmd"));
+    assert!(help.contains("--count"));
+    assert!(!help.contains("--name"));
+}
+
+#[test]
+fn should_report_flag_presence_without_evaluating_values() {
+    let path = std::env::temp_dir().join(format!("scrap-dry-run-{}", std::process::id()));
+    let path = path.to_str().unwrap().to_string();
+    let input = ["test", "--file", path.as_str()];
+
+    let cmd = Cmd::new("test")
+        .with_flag(WithOpen::new(FlagWithValue::new(
+            "file",
+            "f",
+            "A file to open.",
+            FileValue::new(true, false, true),
+        )))
+        .with_flag(Flag::store_true("verbose", "v", "Enable verbose output.").optional())
+        .with_handler(|_| {});
+
+    assert_eq!(
+        vec![("file".to_string(), true), ("verbose".to_string(), false)],
+        cmd.dry_run(&input[..])
+    );
+    // the file doesn't exist, so a full evaluation fails to open it.
+    assert!(cmd.evaluate(&input[..]).is_err());
+    assert!(!std::path::Path::new(&path).exists());
+}
+
+#[test]
+fn should_reject_flaglike_values_when_configured() {
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name.").no_flaglike_values())
+        .with_flag(Flag::store_true("debug", "d", "Enable debug output.").optional())
+        .with_handler(|_| {});
+
+    assert_eq!(
+        Err(CliError::MissingValue("name".to_string())),
+        cmd.evaluate(&["test", "--name", "--debug"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..4),
+            ("value".to_string(), Some(true))
+        )),
+        cmd.evaluate(&["test", "--name", "value", "--debug"][..])
+    );
+
+    // flags that don't consume a value are unaffected.
+    let flag = Flag::store_true("debug", "d", "Enable debug output.").no_flaglike_values();
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..2), true)),
+        flag.evaluate(&["test", "-d", "--name"][..])
+    );
+}
+
+#[test]
+fn should_toggle_plus_minus_flags() {
+    let flag = PlusMinusFlag::new("verbose", "v", "Toggle verbose output.");
+
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..2), true)),
+        flag.evaluate(&["test", "+v"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..2), false)),
+        flag.evaluate(&["test", "-v"][..])
+    );
+    assert_eq!(
+        Err(CliError::FlagEvaluation("verbose".to_string())),
+        flag.evaluate(&["test", "+x", "--verbose"][..])
+    );
+
+    let cmd = Cmd::new("test")
+        .with_flag(PlusMinusFlag::new("verbose", "v", "Toggle verbose output.").optional())
+        .with_handler(|verbose| verbose);
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), None)),
+        cmd.evaluate(&["test"][..])
+    );
+    assert!(cmd.help().contains("+v enables, -v disables"));
+}
+
+#[test]
+fn should_preserve_newlines_in_multi_paragraph_after_help() {
+    let after_long_help = "Examples:\n    test -n foo\n\nSee the manual for more.";
+    let help = Cmd::new("test")
+        .after_help("See the manual.")
+        .after_long_help(after_long_help)
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|name| name)
+        .help();
+
+    assert!(help.contains("Examples:\n    test -n foo"));
+    assert!(help.contains("\n\nSee the manual for more."));
+    assert!(help.ends_with(after_long_help));
+    assert!(!help.contains("See the manual."));
+}
+
+#[test]
+fn should_build_values_and_spans_from_raw_parts() {
+    assert_eq!(
+        Value::new(Span::from_range(1..3), "foo".to_string()),
+        Value::spanned(1..3, "foo".to_string())
+    );
+    assert_eq!(Span::from_range(0..3), span![0, 1, 2]);
+    assert_eq!(Span::empty(), span![]);
+
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::store_true("debug", "d", "Enable debug output."))
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|_| {});
+    assert_eq!(
+        Ok(Value::new(span![0, 1, 3, 4], (true, "foo".to_string()))),
+        cmd.evaluate(&["test", "-d", "ignored", "-n", "foo"][..])
+    );
+}
+
+#[test]
+fn should_require_flags_together_or_not_at_all() {
+    let cmd = Cmd::new("test")
+        .with_flag(AllOrNone::new(
+            Flag::expect_string("cert", "c", "A certificate path."),
+            Flag::expect_string("key", "k", "A key path."),
+        ))
+        .with_handler(|_| {});
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..5),
+            (Some("tls.crt".to_string()), Some("tls.key".to_string()))
+        )),
+        cmd.evaluate(&["test", "-c", "tls.crt", "-k", "tls.key"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), (None, None))),
+        cmd.evaluate(&["test"][..])
+    );
+    assert_eq!(
+        Err(CliError::MissingDependency {
+            present: vec!["key".to_string()],
+            missing: vec!["cert".to_string()],
+        }),
+        cmd.evaluate(&["test", "--key", "tls.key"][..])
+    );
+}
+
+#[test]
+fn should_pass_all_original_args_to_raw_handler() {
+    let input = ["test", "-n", "foo", "unused", "--debug"];
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_flag(Flag::store_true("debug", "d", "Enable debug output."))
+        .with_raw_handler(|(name, debug), raw_args: &[&str]| {
+            (
+                name,
+                debug,
+                raw_args
+                    .iter()
+                    .map(|arg| arg.to_string())
+                    .collect::<Vec<_>>(),
+            )
+        });
+
+    let flag_values = cmd.evaluate(&input[..]).unwrap();
+    assert_eq!(
+        (
+            "foo".to_string(),
+            true,
+            input.iter().map(|arg| arg.to_string()).collect::<Vec<_>>()
+        ),
+        cmd.dispatch_with_raw_args(&input[..], flag_values)
+    );
+}
+
+#[test]
+fn should_only_accept_strict_decimal_numbers() {
+    let eval = |v: &'static str| StrictF64Value.evaluate(&[v][..]).map(|v| v.unwrap());
+
+    assert_eq!(Ok(1.5), eval("1.5"));
+    assert_eq!(Ok(-1.5), eval("-1.5"));
+    assert_eq!(Ok(1000.0), eval("1e3"));
+    assert_eq!(Ok(0.025), eval("2.5E-2"));
+
+    for malformed in [
+        "inf", "-inf", "nan", "NaN", ".5", "1.", "1,5", "1e", "1e+", "+", "1e999", "",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            Err(CliError::ValueEvaluation),
+            eval(malformed),
+            "{}",
+            malformed
+        );
+    }
+}
+
+#[test]
+fn should_match_and_document_flattened_group_flags_by_prefix() {
+    let db = Cmd::new("db")
+        .with_flag(Flag::expect_string("host", "H", "A database host."))
+        .with_flag(
+            Flag::expect_u16("port", "P", "A database port.")
+                .optional()
+                .with_default(5432),
+        );
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("host", "h", "A listen host."))
+        .with_flag(db.flatten_group("db"))
+        .with_handler(|_| {});
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..7),
+            ("0.0.0.0".to_string(), ("db.local".to_string(), 6543))
+        )),
+        cmd.evaluate(
+            &[
+                "test",
+                "--host",
+                "0.0.0.0",
+                "--db-host",
+                "db.local",
+                "--db-port",
+                "6543"
+            ][..]
+        )
+    );
+    // the unprefixed flag is only matched by the parent.
+    assert_eq!(
+        Err(CliError::FlagEvaluation("db-host".to_string())),
+        cmd.evaluate(&["test", "--host", "0.0.0.0"][..])
+    );
+
+    let help = cmd.help();
+    assert!(help.contains("--db-host, -H"));
+    assert!(help.contains("--db-port, -P"));
+    assert!(help.contains("--host, -h"));
+}
+
+#[test]
+fn should_return_positions_of_a_span_not_in_another() {
+    // overlapping
+    assert_eq!(
+        Span::from_range(3..5),
+        Span::from_range(1..5).difference(&Span::from_range(0..3))
+    );
+    assert_eq!(
+        Span::new(vec![1, 4]),
+        Span::from_range(1..5).difference(&Span::from_range(2..4))
+    );
+
+    // disjoint
+    assert_eq!(
+        Span::from_range(0..2),
+        Span::from_range(0..2).difference(&Span::from_range(4..6))
+    );
+    assert_eq!(
+        Span::empty(),
+        Span::empty().difference(&Span::from_range(0..2))
+    );
+}
+
+#[test]
+fn should_return_handler_output_or_error_from_try_run() {
+    let cmd = || {
+        Cmd::new("test")
+            .with_flag(Flag::expect_u8("count", "c", "A count."))
+            .with_handler(|count| count * 2)
+    };
+
+    assert_eq!(Ok(10), cmd().try_run(&["test", "-c", "5"][..]));
+    assert_eq!(
+        Err(CliError::MissingValue("count".to_string())),
+        cmd().try_run(&["test", "-c"][..])
+    );
+    // help flags are evaluated like any other argument.
+    assert_eq!(
+        Err(CliError::FlagEvaluation("count".to_string())),
+        cmd().try_run(&["test", "--help"][..])
+    );
+}
+
+#[test]
+fn should_expand_unambiguous_choice_prefixes() {
+    let flag = WithChoices::new(
+        [
+            "debug".to_string(),
+            "info".to_string(),
+            "warn".to_string(),
+            "warning".to_string(),
+        ],
+        FlagWithValue::new("log-level", "l", "A log level.", StringValue),
+    )
+    .allow_prefix();
+    let eval = |v: &'static str| flag.evaluate(&["test", "-l", v][..]).map(|v| v.unwrap());
+
+    // unique prefix
+    assert_eq!(Ok("info".to_string()), eval("inf"));
+    assert_eq!(Ok("debug".to_string()), eval("d"));
+    // exact match, despite also prefixing another choice
+    assert_eq!(Ok("warn".to_string()), eval("warn"));
+    // ambiguous and unmatched prefixes
+    assert_eq!(Err(CliError::InvalidChoice("w".to_string())), eval("w"));
+    assert_eq!(
+        Err(CliError::InvalidChoice("error".to_string())),
+        eval("error")
+    );
+}
+
+#[test]
+fn should_evaluate_floating_point_flags() {
+    let flag = Flag::expect_f64("threshold", "t", "A threshold.");
+    let eval = |v: &'static str| flag.evaluate(&["test", "-t", v][..]).map(|v| v.unwrap());
+
+    assert_eq!(Ok(0.75), eval("0.75"));
+    assert_eq!(Ok(-0.5), eval("-0.5"));
+    assert_eq!(Ok(1e3), eval("1e3"));
+    assert_eq!(Ok(f64::INFINITY), eval("inf"));
+    assert_eq!(Ok(f64::NEG_INFINITY), eval("-inf"));
+    assert!(eval("NaN").unwrap().is_nan());
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "threshold".to_string(),
+            value: "1,5".to_string(),
+            message: None,
+        }),
+        eval("1,5")
+    );
+
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), -2.5f32)),
+        Flag::expect_f32("offset", "o", "An offset.").evaluate(&["test", "-o", "-2.5"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), 0.5)),
+        Flag::expect_f64("threshold", "t", "A threshold.")
+            .optional()
+            .with_default(0.5)
+            .evaluate(&["test"][..])
+    );
+    assert!(WithChoices::new(
+        [0.25, 0.5],
+        FlagWithValue::new("ratio", "r", "A ratio.", F64Value)
+    )
+    .evaluate(&["test", "-r", "0.75"][..])
+    .is_err());
+}
+
+#[test]
+fn should_fall_back_to_piped_stdin_only_when_not_a_terminal() {
+    let flag = |is_terminal| {
+        StdinFallback::with_reader(
+            Flag::expect_string("input", "i", "An input."),
+            &b"piped input\r\n"[..],
+            is_terminal,
+        )
+    };
+
+    // piped
+    let piped = flag(false);
+    assert_eq!(
+        Ok(Value::new(Span::empty(), "piped input".to_string())),
+        piped.evaluate(&["test"][..])
+    );
+    // stdin is only read once.
+    assert_eq!(
+        Ok(Value::new(Span::empty(), "piped input".to_string())),
+        piped.evaluate(&["test"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), "arg".to_string())),
+        piped.evaluate(&["test", "-i", "arg"][..])
+    );
+
+    // terminal
+    assert_eq!(
+        Err(CliError::FlagEvaluation("input".to_string())),
+        flag(true).evaluate(&["test"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), None)),
+        flag(true).optional().evaluate(&["test"][..])
+    );
+
+    // nothing piped
+    assert_eq!(
+        Err(CliError::FlagEvaluation("input".to_string())),
+        StdinFallback::with_reader(
+            Flag::expect_string("input", "i", "An input."),
+            &b""[..],
+            false
+        )
+        .evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_parse_any_from_str_type_behind_optional_and_default() {
+    let port = || FlagWithValue::new("port", "p", "A port.", FromStrValue::<u16>::new());
+
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), 8080u16)),
+        FromStrValue::<u16>::new().evaluate(&["8080"][..])
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        FromStrValue::<u16>::new().evaluate(&["-1"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), Some(22))),
+        port().optional().evaluate(&["test", "-p", "22"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), None)),
+        port().optional().evaluate(&["test"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), 80)),
+        port()
+            .optional()
+            .with_default(80u16)
+            .evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_render_argparse_style_help() {
+    let help = Cmd::new("test")
+        .description("a test cmd")
+        .help_style(HelpStyle::argparse())
+        .with_flag(
+            Flag::expect_string("name", "n", "A name.").join(Flag::store_true(
+                "verbose",
+                "v",
+                "enable verbose output.",
+            )),
+        )
+        .with_positional("path", 0, StringValue)
+        .with_handler(|_| {})
+        .help();
+
+    assert_eq!(
+        "usage: test [-n NAME] [-v] path
+
+a test cmd
+
+positional arguments:
+  path
+
+options:
+  -n NAME, --name NAME  A name.
+  -v, --verbose         enable verbose output.",
+        help
+    );
+}
+
+#[test]
+fn should_parse_bool_values_from_the_following_argument() {
+    for (arg, expected) in [
+        ("true", true),
+        ("TRUE", true),
+        ("1", true),
+        ("Yes", true),
+        ("false", false),
+        ("0", false),
+        ("no", false),
+    ] {
+        assert_eq!(
+            Ok(Value::new(Span::from_range(1..3), expected)),
+            Flag::expect_bool("enabled", "e", "Enable it.").evaluate(&["test", "-e", arg][..])
+        );
+    }
+
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        BoolValue.evaluate(&["maybe"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), true)),
+        Flag::expect_bool("enabled", "e", "Enable it.")
+            .optional()
+            .with_default(true)
+            .evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_report_matched_flags_and_classified_unused_args() {
+    let input = ["test", "--unknown", "-v", "file", "--name", "foo", "-"];
+    let cmd = Cmd::new("test")
+        .with_flag(
+            Flag::expect_string("name", "n", "A name.")
+                .join(Flag::store_true("verbose", "v", "Enable verbose output.").optional())
+                .join(Flag::expect_u8("count", "c", "A count.").optional()),
+        )
+        .with_handler(|_| {});
+
+    let report = cmd.evaluate_report(&input[..]).unwrap();
+
+    assert_eq!(
+        ParseReport {
+            value: (("foo".to_string(), Some(true)), None),
+            span: Span::new(vec![0, 4, 5, 2]),
+            matched_flags: vec!["name".to_string(), "verbose".to_string()],
+            unused_flags: vec![(1, "--unknown".to_string())],
+            unused_positionals: vec![(3, "file".to_string()), (6, "-".to_string())],
+        },
+        report
+    );
+}
+
+#[test]
+fn should_parse_integers_in_a_fixed_radix() {
+    let mode = || Flag::expect_u32_radix("mode", "m", "A file mode.", 8);
+
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), 0o755)),
+        mode().evaluate(&["test", "-m", "755"][..])
+    );
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "mode".to_string(),
+            value: "758".to_string(),
+            message: None,
+        }),
+        mode().evaluate(&["test", "-m", "758"][..])
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        RadixValue::<u32>::new(8).evaluate(&["0o755"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), -0xffi16)),
+        RadixValue::<i16>::new(16).evaluate(&["-FF"][..])
+    );
+}
+
+#[test]
+fn should_not_panic_on_adversarial_input() {
+    // deep enough to overflow the stack of an unbounded recursive parser.
+    let deep_arrays = "[".repeat(200_000);
+    let deep_objects = r#"{"a":"#.repeat(100_000);
+
+    let adversarial = [
+        "",
+        "-",
+        "--",
+        "---",
+        "--=",
+        "-=",
+        "0",
+        "00000000000000000000000000000001",
+        "-0",
+        "+0",
+        "99999999999999999999999999999999999999999999999999",
+        "-99999999999999999999999999999999999999999999999999",
+        "1e99999",
+        "-1e-99999",
+        "0x",
+        "0xffffffffffffffffffffffffffffffff",
+        "NaN",
+        "inf",
+        "\0",
+        "a\0b",
+        "--name\0",
+        "-n\0",
+        "\u{feff}",
+        "ü",
+        "1-",
+        "-1",
+        "1..",
+        "..1",
+        "5-1",
+        "1-99999999999999999999",
+        "0-100000000000000",
+        "0-18446744073709551615",
+        "0-1,0-100000000000000",
+        ",,,",
+        "=",
+        "k=",
+        "=v",
+        "[",
+        "{",
+        "\"",
+        "[[[[[[[[[[",
+        deep_arrays.as_str(),
+        deep_objects.as_str(),
+        "{\"a\":",
+        "9999-99-99T99:99:99Z",
+        "1970-01-01T00:00:00",
+    ];
+
+    for &arg in adversarial.iter() {
+        let inputs = [
+            vec!["test", arg],
+            vec!["test", "-v", arg],
+            vec!["test", arg, "-v"],
+            vec!["test", "-v", "--", arg],
+            vec!["test", arg, arg, arg],
+        ];
+
+        for input in inputs.iter() {
+            let _ = Flag::expect_string("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::store_true("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_i8("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_i64("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_u64("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_u32_radix("value", "v", "A value.", 16).evaluate(&input[..]);
+            let _ = Flag::expect_f32("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_f64("value", "v", "A value.").evaluate(&input[..]);
+            let _ =
+                FlagWithValue::new("value", "v", "A value.", StrictF64Value).evaluate(&input[..]);
+            let _ = Flag::expect_bool("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_u32_prefixed("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_duration("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_byte_size("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_range_list("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_map("value", "v", "A value.", I64Value).evaluate(&input[..]);
+            let _ = Flag::expect_json("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_timestamp("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_one_of_types("value", "v", "A value.").evaluate(&input[..]);
+            let _ = OptionalValue::new("value", "v", "A value.", I64Value).evaluate(&input[..]);
+            let _ = PlusMinusFlag::new("value", "v", "A value.").evaluate(&input[..]);
+            let _ = Flag::expect_string("value", "v", "A value.")
+                .optional()
+                .with_default("default".to_string())
+                .evaluate(&input[..]);
+
+            let cmd = Cmd::new("test")
+                .with_flag(Flag::expect_i64("value", "v", "A value.").optional())
+                .with_positional("first", 0, StringValue)
+                .with_handler(|_| {});
+            let _ = cmd.evaluate(&input[..]);
+            let _ = cmd.evaluate_report(&input[..]);
+            let _ = cmd.dry_run(&input[..]);
+        }
+    }
+}
+
+#[test]
+fn should_parse_128_bit_integers() {
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..3), i128::MAX)),
+        Flag::expect_i128("id", "i", "An id.")
+            .evaluate(&["test", "-i", "170141183460469231731687303715884105727"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), u128::MAX)),
+        U128Value.evaluate_at(
+            &["test", "-i", "340282366920938463463374607431768211455"][..],
+            2
+        )
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        U128Value.evaluate(&["340282366920938463463374607431768211456"][..])
+    );
+}
+
+#[test]
+fn should_evaluate_paths_without_touching_the_filesystem() {
+    use std::path::PathBuf;
+
+    let out = || Flag::expect_path("out", "o", "An output path.");
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..3),
+            PathBuf::from("/path/does/not/exist/out.txt")
+        )),
+        out().evaluate(&["test", "-o", "/path/does/not/exist/out.txt"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), PathBuf::from("a.out"))),
+        out()
+            .optional()
+            .with_default(PathBuf::from("a.out"))
+            .evaluate(&["test"][..])
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        PathBufValue.evaluate(&[""][..])
+    );
+}
+
+#[test]
+fn should_dispatch_a_handler_that_moves_captured_state() {
+    struct Sink(Vec<String>);
+
+    let sink = Sink(Vec::new());
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_once_handler(move |name| {
+            let Sink(mut lines) = sink;
+            lines.push(name);
+            lines
+        });
+
+    let flag_values = cmd.evaluate(&["test", "-n", "foo"][..]).unwrap();
+    assert_eq!(vec!["foo".to_string()], cmd.dispatch_once(flag_values));
+}
+
+#[test]
+fn should_dispatch_once_handlers_through_groups_and_one_of() {
+    let lines = vec!["init".to_string()];
+    let cmds = || {
+        let lines = lines.clone();
+        OneOf::new(
+            Cmd::new("add")
+                .with_flag(Flag::expect_string("name", "n", "A name."))
+                .with_once_handler(move |name| {
+                    let mut lines = lines;
+                    lines.push(name);
+                    lines
+                }),
+            Cmd::new("clear").with_once_handler(|_| Vec::new()),
+        )
+    };
+
+    let group = CmdGroup::new("tool").with_command(cmds());
+    let flag_values = group.evaluate(&["tool", "add", "-n", "foo"][..]).unwrap();
+    assert_eq!(
+        vec!["init".to_string(), "foo".to_string()],
+        group.dispatch_once(flag_values)
+    );
+
+    let one_of = cmds();
+    let flag_values = one_of.evaluate(&["clear"][..]).unwrap();
+    assert_eq!(Vec::<String>::new(), one_of.dispatch_once(flag_values));
+}
+
+#[test]
+fn should_parse_ipv4_and_ipv6_addresses() {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let bind = || Flag::expect_ip("bind", "b", "An address to bind.");
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..3),
+            IpAddr::V6(Ipv6Addr::LOCALHOST)
+        )),
+        bind().evaluate(&["test", "-b", "::1"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..3),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))
+        )),
+        bind().evaluate(&["test", "-b", "10.0.0.1"][..])
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        IpAddrValue.evaluate(&["not-an-ip"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), IpAddr::V4(Ipv4Addr::UNSPECIFIED))),
+        bind()
+            .optional()
+            .with_default(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+            .evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_fail_gracefully_on_empty_input() {
+    let empty: &[&str] = &[];
+
+    // value types
+    assert_eq!(Err(CliError::ValueEvaluation), StringValue.evaluate(empty));
+    assert_eq!(Err(CliError::ValueEvaluation), I64Value.evaluate(empty));
+    assert_eq!(Err(CliError::ValueEvaluation), U128Value.evaluate(empty));
+    assert_eq!(Err(CliError::ValueEvaluation), F64Value.evaluate(empty));
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        StrictF64Value.evaluate(empty)
+    );
+    assert_eq!(Err(CliError::ValueEvaluation), BoolValue.evaluate(empty));
+    assert_eq!(Err(CliError::ValueEvaluation), PathBufValue.evaluate(empty));
+    assert_eq!(Err(CliError::ValueEvaluation), IpAddrValue.evaluate(empty));
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        RangeListValue.evaluate(empty)
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        TimestampValue.evaluate(empty)
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        FromStrValue::<u16>::new().evaluate(empty)
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        RadixValue::<u32>::new(8).evaluate(empty)
+    );
+
+    // flags
+    let name = || Flag::expect_string("name", "n", "A name.");
+    assert_eq!(
+        Err(CliError::FlagEvaluation("name".to_string())),
+        name().evaluate(empty)
+    );
+    assert_eq!(
+        Err(CliError::FlagEvaluation("verbose".to_string())),
+        Flag::store_true("verbose", "v", "Enable verbose output.").evaluate(empty)
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), None)),
+        name().optional().evaluate(empty)
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), "foo".to_string())),
+        name()
+            .optional()
+            .with_default("foo".to_string())
+            .evaluate(empty)
+    );
+    assert!(name()
+        .join(Flag::store_true("verbose", "v", "Enable verbose output."))
+        .evaluate(empty)
+        .is_err());
+    assert_eq!(
+        Err(CliError::MissingValue("file".to_string())),
+        Positional::new("file", 0, StringValue).evaluate(empty)
+    );
+
+    // commands
+    let cmd = Cmd::new("test")
+        .with_flag(name().optional())
+        .with_handler(|_| {});
+    assert_eq!(Err(CliError::AmbiguousCommand), cmd.evaluate(empty));
+    assert_eq!(vec![("name".to_string(), false)], cmd.dry_run(empty));
+    assert!(!cmd.help_requested(empty));
+    assert!(CmdGroup::new("group")
+        .with_command(Cmd::new("test").with_handler(|_| {}))
+        .evaluate(empty)
+        .is_err());
+}
+
+#[test]
+fn should_parse_socket_addresses() {
+    use std::net::{Ipv6Addr, SocketAddr};
+
+    let listen = || Flag::expect_socket_addr("listen", "l", "An address to listen on.");
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..3),
+            SocketAddr::from(([127, 0, 0, 1], 8080))
+        )),
+        listen().evaluate(&["test", "-l", "127.0.0.1:8080"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..3),
+            SocketAddr::from((Ipv6Addr::LOCALHOST, 443))
+        )),
+        listen().evaluate(&["test", "-l", "[::1]:443"][..])
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        SocketAddrValue.evaluate(&["localhost:8080"][..])
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        SocketAddrValue.evaluate(&["localhost"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(
+            Span::empty(),
+            SocketAddr::from(([0, 0, 0, 0], 80))
+        )),
+        listen()
+            .optional()
+            .with_default(SocketAddr::from(([0, 0, 0, 0], 80)))
+            .evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_surface_a_flags_custom_error_message() {
+    let port = || {
+        Flag::expect_u16("port", "p", "A port.").error_message("provide a valid port with --port")
+    };
+
+    let err = port()
+        .evaluate(&["test", "--port", "http"][..])
+        .unwrap_err();
+    assert_eq!("provide a valid port with --port", err.to_string());
+
+    assert_eq!(
+        "provide a valid port with --port",
+        port().evaluate(&["test"][..]).unwrap_err().to_string()
+    );
+
+    // a missing value retains its own message.
+    assert_eq!(
+        Err(CliError::MissingValue("port".to_string())),
+        port().evaluate(&["test", "--port"][..])
+    );
+
+    assert_eq!(
+        "invalid value for flag port: http",
+        Flag::expect_u16("port", "p", "A port.")
+            .evaluate(&["test", "--port", "http"][..])
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn should_parse_suffixed_durations() {
+    use std::time::Duration;
+
+    for (arg, expected) in [
+        ("15ns", Duration::from_nanos(15)),
+        ("15us", Duration::from_micros(15)),
+        ("250ms", Duration::from_millis(250)),
+        ("30s", Duration::from_secs(30)),
+        ("5m", Duration::from_secs(300)),
+        ("2h", Duration::from_secs(7200)),
+    ] {
+        assert_eq!(
+            Ok(Value::new(Span::from_range(0..1), expected)),
+            DurationValue.evaluate(&[arg][..])
+        );
+    }
+
+    for arg in [
+        "30",
+        "s",
+        "30w",
+        "-5s",
+        "1.5s",
+        "30 s",
+        "99999999999999999999h",
+    ] {
+        assert_eq!(
+            Err(CliError::ValueEvaluation),
+            DurationValue.evaluate(&[arg][..]),
+            "{}",
+            arg
+        );
+    }
+
+    assert_eq!(
+        Ok(Value::new(Span::empty(), Duration::from_secs(10))),
+        Flag::expect_duration("timeout", "t", "A timeout.")
+            .optional()
+            .with_default(Duration::from_secs(10))
+            .evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_evaluate_a_group_invoked_under_an_alias() {
+    let group = CmdGroup::new("scrap")
+        .binary_aliases(&["scr", "sc"])
+        .with_command(
+            Cmd::new("greet")
+                .with_flag(Flag::expect_string("name", "n", "A name."))
+                .with_handler(|name| format!("hello {}", name)),
+        );
+
+    for bin in ["scrap", "scr", "/usr/local/bin/sc"] {
+        assert_eq!(
+            Ok(Value::new(Span::from_range(0..4), "foo".to_string())),
+            group.evaluate(&[bin, "greet", "-n", "foo"][..])
+        );
+    }
+    assert_eq!(
+        Err(CliError::AmbiguousCommand),
+        group.evaluate(&["other", "greet", "-n", "foo"][..])
+    );
+
+    let flag_values = group.evaluate(&["scr", "greet", "-n", "foo"][..]).unwrap();
+    assert_eq!("hello foo".to_string(), group.dispatch(flag_values));
+}
+
+#[test]
+fn should_dispatch_the_subcommand_named_by_a_multicall_binary() {
+    let group = || {
+        CmdGroup::new("toolbox")
+            .multicall()
+            .with_command(
+                Cmd::new("cat")
+                    .with_flag(Flag::store_true("number", "n", "Number lines.").optional())
+                    .with_positional("file", 0, StringValue)
+                    .with_handler(|(_, file)| format!("cat {}", file)),
+            )
+            .with_command(
+                Cmd::new("ls")
+                    .with_flag(Flag::store_true("all", "a", "Include hidden entries.").optional())
+                    .with_positional("dir", 0, StringValue)
+                    .with_handler(|(_, dir)| format!("ls {}", dir)),
+            )
+    };
+
+    let flag_values = group().evaluate(&["/usr/bin/cat", "file"][..]).unwrap();
+    assert_eq!(Span::from_range(0..2), flag_values.span);
+    assert_eq!("cat file".to_string(), group().dispatch(flag_values));
+
+    let flag_values = group().evaluate(&["toolbox", "ls", "dir"][..]).unwrap();
+    assert_eq!("ls dir".to_string(), group().dispatch(flag_values));
+
+    // multicall dispatch is opt-in.
+    assert_eq!(
+        Err(CliError::AmbiguousCommand),
+        CmdGroup::new("toolbox")
+            .with_command(
+                Cmd::new("cat")
+                    .with_flag(Flag::store_true("number", "n", "Number lines.").optional())
+                    .with_positional("file", 0, StringValue)
+                    .with_handler(|(_, file)| format!("cat {}", file)),
+            )
+            .evaluate(&["cat", "file"][..])
+    );
+}
+
+#[test]
+fn should_detect_the_radix_from_an_integer_prefix() {
+    for (arg, expected) in [
+        ("0xFF", 255u32),
+        ("0XfF", 255),
+        ("0o17", 15),
+        ("0b1010", 10),
+        ("42", 42),
+        ("+0x10", 16),
+    ] {
+        assert_eq!(
+            Ok(Value::new(Span::from_range(1..3), expected)),
+            Flag::expect_u32_prefixed("mask", "m", "A mask.").evaluate(&["test", "-m", arg][..]),
+            "{}",
+            arg
+        );
+    }
+
+    let signed = RadixValue::<i32>::prefixed();
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), -0xff)),
+        signed.evaluate(&["-0xFF"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(0..1), -12)),
+        signed.evaluate(&["-12"][..])
+    );
+
+    for arg in ["0x", "0b102", "0o8", "0x-5", "--5", "-0xFF_u32", "0z10"] {
+        assert_eq!(
+            Err(CliError::ValueEvaluation),
+            signed.evaluate(&[arg][..]),
+            "{}",
+            arg
+        );
+    }
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        RadixValue::<u32>::prefixed().evaluate(&["-0x1"][..])
+    );
+}
+
+#[test]
+fn should_reject_zero_for_nonzero_values() {
+    use std::num::{NonZeroI64, NonZeroU32};
+
+    let workers = || Flag::expect_nonzero_u32("workers", "w", "A worker count.");
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..3),
+            NonZeroU32::new(8).unwrap()
+        )),
+        workers().evaluate(&["test", "-w", "8"][..])
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        NonZeroU32Value.evaluate(&["0"][..])
+    );
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        NonZeroU32Value.evaluate(&["-1"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(0..1),
+            NonZeroI64::new(-3).unwrap()
+        )),
+        NonZeroI64Value.evaluate(&["-3"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), NonZeroU32::new(1).unwrap())),
+        workers()
+            .optional()
+            .with_default(NonZeroU32::new(1).unwrap())
+            .evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_filter_an_evaluated_value() {
+    let value = Value::new(Span::from_range(1..3), 8080u16);
+
+    assert_eq!(Ok(value.clone()), value.clone().filter(|&port| port > 1023));
+    assert_eq!(
+        Err(CliError::ValueEvaluation),
+        value.filter(|&port| port < 1024)
+    );
+}
+
+#[test]
+fn should_parse_suffixed_byte_sizes() {
+    for (arg, expected) in [
+        ("512", 512u64),
+        ("1k", 1_000),
+        ("3M", 3_000_000),
+        ("2G", 2_000_000_000),
+        ("1KiB", 1_024),
+        ("4MiB", 4_194_304),
+        ("1GiB", 1_073_741_824),
+    ] {
+        assert_eq!(
+            Ok(Value::new(Span::from_range(0..1), expected)),
+            ByteSizeValue.evaluate(&[arg][..]),
+            "{}",
+            arg
+        );
+    }
+
+    for arg in [
+        "k",
+        "1K",
+        "1kb",
+        "1.5M",
+        "-1k",
+        "1 MiB",
+        "18446744073709551615k",
+    ] {
+        assert_eq!(
+            Err(CliError::ValueEvaluation),
+            ByteSizeValue.evaluate(&[arg][..]),
+            "{}",
+            arg
+        );
+    }
+
+    assert_eq!(
+        Ok(Value::new(Span::empty(), 1024)),
+        Flag::expect_byte_size("max-size", "m", "A maximum size.")
+            .optional()
+            .with_default(1024u64)
+            .evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_model_a_multi_level_command_tree() {
+    let remote = CmdGroup::new("remote")
+        .description("manage remotes")
+        .with_command(
+            Cmd::new("add")
+                .description("add a remote")
+                .with_flag(Flag::expect_string("url", "u", "A remote url."))
+                .with_handler(|_| {}),
+        )
+        .with_command(Cmd::new("list").description("list remotes"));
+    let status = Cmd::new("status")
+        .description("show status")
+        .with_flag(Flag::store_true("short", "s", "Short output.").optional())
+        .with_handler(|_| {});
+    let group = CmdGroup::new("tool")
+        .description("a tool")
+        .with_command(OneOf::new(remote, status));
+
+    let flag = |name: &str, short_code, description, takes_value| FlagMetadata {
+        name: name.to_string(),
+        short_code,
+        description,
+        modifiers: if takes_value {
+            vec![]
+        } else {
+            vec!["optional".to_string()]
+        },
+        env_var: None,
+        takes_value,
+    };
+
+    assert_eq!(
+        CommandNode {
+            name: "tool",
+            description: "a tool",
+            flags: vec![],
+            subcommands: vec![
+                CommandNode {
+                    name: "remote",
+                    description: "manage remotes",
+                    flags: vec![],
+                    subcommands: vec![
+                        CommandNode {
+                            name: "add",
+                            description: "add a remote",
+                            flags: vec![flag("url", "u", "A remote url.", true)],
+                            subcommands: vec![],
+                        },
+                        CommandNode {
+                            name: "list",
+                            description: "list remotes",
+                            flags: vec![],
+                            subcommands: vec![],
+                        },
+                    ],
+                },
+                CommandNode {
+                    name: "status",
+                    description: "show status",
+                    flags: vec![flag("short", "s", "Short output.", false)],
+                    subcommands: vec![],
+                },
+            ],
+        },
+        group.tree_model()
+    );
+}
+
+#[test]
+fn should_round_trip_a_span_through_its_display() {
+    for span in [
+        Span::empty(),
+        Span::from_range(0..3),
+        Span::new(vec![0, 1, 2, 5]),
+        // order and repeated positions are preserved.
+        Span::new(vec![0, 4, 5, 2]),
+        Span::new(vec![3, 3]),
+    ] {
+        assert_eq!(Ok(span.clone()), span.to_string().parse::<Span>());
+    }
+
+    for malformed in ["0..", "..3", "3", "2..2", "0..1;2..3", "a..b", "0..1,"] {
+        assert_eq!(
+            Err(CliError::ValueEvaluation),
+            malformed.parse::<Span>(),
+            "{}",
+            malformed
+        );
+    }
+}
+
+#[test]
+fn should_count_repeated_flags() {
+    let verbose = || Flag::count("verbose", "v", "Raise the verbosity.");
+
+    assert_eq!(
+        Ok(Value::new(Span::new(vec![1, 2, 3]), 3)),
+        verbose().evaluate(&["test", "-v", "-v", "-v"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..2), 3)),
+        verbose().evaluate(&["test", "-vvv"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::new(vec![1, 3]), 3)),
+        verbose().evaluate(&["test", "--verbose", "file", "-vv"][..])
+    );
+    // only tokens repeating the short code are counted.
+    assert_eq!(
+        Ok(Value::new(Span::empty(), 0)),
+        verbose().evaluate(&["test", "-vx", "-", "--", "--verb"][..])
+    );
+
+    let cmd = Cmd::new("test")
+        .with_flag(verbose())
+        .with_handler(|level| level);
+    let flag_values = cmd.evaluate(&["test"][..]).unwrap();
+    assert_eq!(0, cmd.dispatch(flag_values));
+}
+
+#[test]
+fn should_collect_every_occurrence_of_a_repeated_flag() {
+    let include = || Flag::expect_string("include", "i", "A path to include.").repeated();
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::new(vec![1, 2, 4, 5]),
+            vec!["a".to_string(), "b".to_string()]
+        )),
+        include().evaluate(&["test", "--include", "a", "file", "-i", "b"][..])
+    );
+    assert_eq!(
+        Err(CliError::MissingValue("include".to_string())),
+        include().evaluate(&["test", "-i", "a", "-i"][..])
+    );
+
+    let cmd = Cmd::new("test")
+        .with_flag(include().join(Flag::store_true("verbose", "v", "Verbose output.").optional()))
+        .with_handler(|(includes, _)| includes);
+    assert!(cmd.help().contains("(repeatable)"));
+
+    let flag_values = cmd.evaluate(&["test", "-v"][..]).unwrap();
+    assert_eq!(Vec::<String>::new(), cmd.dispatch(flag_values));
+}
+
+#[test]
+fn should_enforce_the_number_of_unused_positionals() {
+    let cmd = Cmd::new("test")
+        .positionals(1..=3)
+        .with_flag(Flag::expect_string("name", "n", "A name.").optional())
+        .with_handler(|_| {});
+
+    // under
+    let err = cmd.evaluate(&["test", "-n", "foo"][..]).unwrap_err();
+    assert_eq!(
+        CliError::WrongArgCount {
+            expected: "1..=3".to_string(),
+            got: 0
+        },
+        err
+    );
+    assert_eq!(
+        "expected 1..=3 positional arguments, got 0",
+        err.to_string()
+    );
+
+    // within, where flag values and unknown flags aren't counted.
+    assert!(cmd.evaluate(&["test", "a"][..]).is_ok());
+    assert!(cmd
+        .evaluate(&["test", "a", "-n", "foo", "--unknown", "b", "-"][..])
+        .is_ok());
+
+    // over
+    assert_eq!(
+        Err(CliError::WrongArgCount {
+            expected: "1..=3".to_string(),
+            got: 4
+        }),
+        cmd.evaluate(&["test", "a", "b", "c", "d"][..])
+    );
+}
+
+#[test]
+fn should_sum_compound_durations() {
+    use std::time::Duration;
+
+    for (arg, expected) in [
+        ("1h30m", Duration::from_secs(5400)),
+        ("90m", Duration::from_secs(5400)),
+        ("1d", Duration::from_secs(86400)),
+        ("1d12h", Duration::from_secs(129600)),
+        ("1m30s500ms", Duration::from_millis(90500)),
+    ] {
+        assert_eq!(
+            Ok(Value::new(Span::from_range(0..1), expected)),
+            DurationValue.evaluate(&[arg][..]),
+            "{}",
+            arg
+        );
+    }
+
+    for arg in ["1h30x", "30m1h", "1h1h", "1h30", "h30m", "1h 30m", "1h-30m"] {
+        assert_eq!(
+            Err(CliError::ValueEvaluation),
+            DurationValue.evaluate(&[arg][..]),
+            "{}",
+            arg
+        );
+    }
+}
+
+#[test]
+fn should_evaluate_flag_values_passed_inline() {
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("kv", "k", "A key-value pair."))
+        .with_handler(|kv| kv);
+
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..2), "a=b".to_string())),
+        Flag::expect_string("kv", "k", "A key-value pair.").evaluate(&["test", "--kv=a=b"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(2..3), 8080)),
+        Flag::expect_u16("port", "p", "A port.").evaluate(&["test", "a", "-p=8080"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(1..2), "".to_string())),
+        Flag::expect_string("name", "n", "A name.").evaluate(&["test", "--name="][..])
+    );
+
+    // the inline token is consumed, leaving the remaining arguments unused.
+    let input = ["test", "--kv=a=b", "extra"];
+    let flags = cmd.evaluate(&input[..]).unwrap();
+    assert_eq!("a=b", flags.value);
+    assert_eq!(
+        vec![Value::new(Span::from_range(2..3), "extra".to_string())],
+        return_unused_args(&input[..], &flags.span)
+    );
+
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "port".to_string(),
+            value: "http".to_string(),
+            message: None,
+        }),
+        Flag::expect_u16("port", "p", "A port.").evaluate(&["test", "--port=http"][..])
+    );
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "verbose".to_string(),
+            value: "false".to_string(),
+            message: None,
+        }),
+        Flag::store_true("verbose", "v", "verbose output.")
+            .evaluate(&["test", "--verbose=false"][..])
+    );
+    assert_eq!(
+        Err(CliError::FlagEvaluation("name".to_string())),
+        Flag::expect_string("name", "n", "A name.").evaluate(&["test", "--names=a"][..])
+    );
+}
+
+#[test]
+fn should_index_positionals_over_arguments_unconsumed_by_flags() {
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_flag(
+            Flag::store_true("verbose", "v", "verbose output.")
+                .optional()
+                .with_default(false),
+        )
+        .with_positional("src", 0, StringValue)
+        .with_positional("dest", 1, StringValue)
+        .with_handler(|(((name, verbose), src), dest)| (name, verbose, src, dest));
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::new(vec![0, 1, 2, 4, 3, 5]),
+            (
+                (("foo".to_string(), true), "a.txt".to_string()),
+                "b.txt".to_string()
+            )
+        )),
+        cmd.evaluate(&["test", "-n", "foo", "a.txt", "-v", "b.txt"][..])
+    );
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::new(vec![0, 3, 4, 1, 2]),
+            (
+                (("foo".to_string(), false), "a.txt".to_string()),
+                "b.txt".to_string()
+            )
+        )),
+        cmd.evaluate(&["test", "a.txt", "b.txt", "--name", "foo"][..])
+    );
+
+    assert_eq!(
+        Err(CliError::MissingValue("dest".to_string())),
+        cmd.evaluate(&["test", "-n", "foo", "a.txt"][..])
+    );
+}
+
+#[test]
+fn should_start_trailing_args_after_values_of_required_flags() {
+    let input = ["time", "-n", "foo", "-c", "3", "ls", "-n"];
+    let flags = Cmd::new("time")
+        .trailing_var_arg()
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_flag(Flag::expect_u8("count", "c", "A count."))
+        .with_flag(
+            Flag::store_true("verbose", "v", "verbose output.")
+                .optional()
+                .with_default(false),
+        )
+        .with_handler(|_| {})
+        .evaluate(&input[..])
+        .unwrap();
+
+    assert_eq!((("foo".to_string(), 3), false), flags.value);
+    assert_eq!(
+        vec!["ls".to_string(), "-n".to_string()],
+        return_unused_args(&input[..], &flags.span)
+            .into_iter()
+            .map(|arg| arg.unwrap())
+            .collect::<Vec<String>>()
+    );
+
+    // a flag set by its presence alone doesn't consume the following token.
+    let input = ["time", "-v", "-n", "foo", "-c", "3", "ls", "-v"];
+    let flags = Cmd::new("time")
+        .trailing_var_arg()
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_flag(Flag::expect_u8("count", "c", "A count."))
+        .with_flag(Flag::store_true("verbose", "v", "verbose output."))
+        .with_handler(|_| {})
+        .evaluate(&input[..])
+        .unwrap();
+
+    assert_eq!(vec![0, 1, 2, 3, 4, 5], {
+        let mut span = flags.span.0.clone();
+        span.sort_unstable();
+        span
+    });
+}
+
+#[test]
+fn should_reject_range_lists_expanding_past_the_maximum_length() {
+    let max = RangeListValue::MAX_LEN;
+    let at_max = format!("1-{}", max);
+    let past_max = format!("0-{}", max);
+    let split_past_max = format!("1-{},0", max);
+
+    assert_eq!(
+        Ok(max),
+        RangeListValue
+            .evaluate(&[at_max.as_str()][..])
+            .map(|v| v.unwrap().len())
+    );
+
+    for arg in [
+        past_max.as_str(),
+        split_past_max.as_str(),
+        "0-100000000000000",
+        "0-18446744073709551615",
+    ] {
+        assert_eq!(
+            Err(CliError::ValueEvaluation),
+            RangeListValue.evaluate(&[arg][..]),
+            "{}",
+            arg
+        );
+    }
+}
+
+#[test]
+fn should_reject_json_nested_past_the_maximum_depth() {
+    let at_max = format!(
+        "{}{}",
+        "[".repeat(JsonValue::MAX_DEPTH),
+        "]".repeat(JsonValue::MAX_DEPTH)
+    );
+    assert!(JsonValue::parse(&at_max).is_ok());
+
+    let past_max = format!(
+        "{}{}",
+        "[".repeat(JsonValue::MAX_DEPTH + 1),
+        "]".repeat(JsonValue::MAX_DEPTH + 1)
+    );
+    assert_eq!(Err(JsonValue::MAX_DEPTH), JsonValue::parse(&past_max));
+
+    let objects = format!(
+        "{}1{}",
+        r#"{"a": "#.repeat(JsonValue::MAX_DEPTH + 1),
+        "}".repeat(JsonValue::MAX_DEPTH + 1)
+    );
+    assert!(JsonValue::parse(&objects).is_err());
+
+    // deep enough to overflow the stack without a limit.
+    let unterminated = "[".repeat(200_000);
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "meta".to_string(),
+            value: unterminated.clone(),
+            message: Some(
+                "invalid JSON at position 128: nested deeper than 128 levels".to_string()
+            ),
+        }),
+        Flag::expect_json("meta", "m", "Inline metadata.")
+            .evaluate(&["test", "-m", unterminated.as_str()][..])
+    );
+}
+
+#[test]
+fn should_detect_presence_of_flags_passed_inline() {
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("json", "j", "A json path.").optional())
+        .with_flag(Flag::expect_string("yaml", "y", "A yaml path.").optional())
+        .conflicts(&["json", "yaml"])
+        .with_handler(|_| {});
+
+    assert_eq!(
+        Err(CliError::MutuallyExclusive(vec![
+            "json".to_string(),
+            "yaml".to_string()
+        ])),
+        cmd.evaluate(&["test", "--json=a", "-y=b"][..])
+    );
+    assert_eq!(
+        vec![("json".to_string(), true), ("yaml".to_string(), false)],
+        cmd.dry_run(&["test", "--json=a"][..])
+    );
+
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|_| {});
+    let report = cmd.evaluate_report(&["test", "--name=a", "b"][..]).unwrap();
+    assert_eq!(vec!["name".to_string()], report.matched_flags);
+    assert_eq!(vec![(2, "b".to_string())], report.unused_positionals);
+
+    // an inline value leaves the following argument to start trailing args.
+    let input = ["time", "--name=foo", "ls", "-n"];
+    let flags = Cmd::new("time")
+        .trailing_var_arg()
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|name| name)
+        .evaluate(&input[..])
+        .unwrap();
+    assert_eq!("foo".to_string(), flags.value);
+    assert_eq!(Span::from_range(0..2), flags.span);
+}
+
+#[test]
+fn should_not_fall_back_to_piped_stdin_when_flag_is_passed() {
+    let flag = StdinFallback::with_reader(
+        Flag::expect_u16("port", "p", "A port."),
+        &b"80\n"[..],
+        false,
+    );
+
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "port".to_string(),
+            value: "abc".to_string(),
+            message: None,
+        }),
+        flag.evaluate(&["test", "-p", "abc"][..])
+    );
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "port".to_string(),
+            value: "abc".to_string(),
+            message: None,
+        }),
+        flag.evaluate(&["test", "--port=abc"][..])
+    );
+    assert_eq!(
+        Err(CliError::MissingValue("port".to_string())),
+        flag.evaluate(&["test", "-p"][..])
+    );
+
+    // the piped value remains available once the flag is absent.
+    assert_eq!(
+        Ok(Value::new(Span::empty(), 80)),
+        flag.evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_classify_invalid_values_of_real_flags_as_value_errors() {
+    let kind = |res: Result<Value<_>, CliError>| res.map(|_| ()).map_err(|e| e.kind());
+    let port = || Flag::expect_u16("port", "p", "A port.");
+
+    assert_eq!(
+        Err(ErrorKind::Value),
+        kind(port().evaluate(&["test", "-p", "abc"][..]))
+    );
+    assert_eq!(
+        Err(ErrorKind::Value),
+        kind(port().evaluate(&["test", "--port=abc"][..]))
+    );
+    assert_eq!(
+        Err(ErrorKind::Value),
+        kind(
+            port()
+                .error_message("provide a valid port")
+                .evaluate(&["test", "-p", "abc"][..])
+        )
+    );
+    assert_eq!(Err(ErrorKind::Usage), kind(port().evaluate(&["test"][..])));
+    assert_eq!(
+        Err(ErrorKind::Usage),
+        kind(port().evaluate(&["test", "-p"][..]))
+    );
+
+    let validated = Validated::new(port(), |port: &u16| {
+        if *port >= 1024 {
+            Ok(())
+        } else {
+            Err("port must be unprivileged".to_string())
+        }
+    });
+    assert_eq!(
+        Err(ErrorKind::Value),
+        kind(validated.evaluate(&["test", "-p", "80"][..]))
+    );
+
+    let cmd = Cmd::new("test")
+        .with_flag(port().optional())
+        .with_positional("count", 0, U8Value)
+        .with_handler(|_| {});
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "count".to_string(),
+            value: "many".to_string(),
+            message: None,
+        }),
+        cmd.evaluate(&["test", "many"][..])
+    );
+    assert_eq!(
+        ErrorKind::Value,
+        cmd.evaluate(&["test", "many"][..]).unwrap_err().kind()
+    );
+}
+
+#[test]
+fn should_report_prefixed_flags_by_their_prefixed_name() {
+    let db = || {
+        Cmd::new("db")
+            .with_flag(Flag::expect_string("host", "H", "A database host."))
+            .with_flag(Flag::expect_u16("port", "P", "A database port."))
+    };
+    let cmd = Cmd::new("test")
+        .with_flag(
+            Flag::store_true("verbose", "v", "verbose output.")
+                .optional()
+                .with_default(false),
+        )
+        .with_flag(db().flatten_group("db"))
+        .with_handler(|_| {});
+
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "db-port".to_string(),
+            value: "x".to_string(),
+            message: None,
+        }),
+        cmd.evaluate(&["test", "--db-host", "a", "--db-port", "x"][..])
+    );
+    // a foreign long flag is neither matched nor consumed as a value.
+    assert_eq!(
+        Err(CliError::MissingValue("db-host".to_string())),
+        cmd.evaluate(&["test", "--db-port", "1", "--db-host", "--verbose"][..])
+    );
+    assert_eq!(
+        Err(CliError::MissingValue("db-host".to_string())),
+        cmd.evaluate(&["test", "-P", "1", "-H", "--other"][..])
+    );
+
+    assert_eq!(
+        vec![
+            ("verbose".to_string(), false),
+            ("db-host".to_string(), true),
+            ("db-port".to_string(), false)
+        ],
+        cmd.dry_run(&["test", "--db-host", "a"][..])
+    );
+    let report = cmd
+        .evaluate_report(&["test", "--db-host=a", "--db-port", "1"][..])
+        .unwrap();
+    assert_eq!(
+        vec!["db-host".to_string(), "db-port".to_string()],
+        report.matched_flags
+    );
+
+    let conflicting = Cmd::new("test")
+        .with_flag(Flag::expect_string("host", "h", "A listen host.").optional())
+        .with_flag(db().flatten_group("db"))
+        .conflicts(&["host", "db-host"])
+        .with_handler(|_| {});
+    assert_eq!(
+        Err(CliError::MutuallyExclusive(vec![
+            "host".to_string(),
+            "db-host".to_string()
+        ])),
+        conflicting.evaluate(&["test", "--host", "a", "--db-host", "b", "-P", "1"][..])
+    );
+
+    let either = AtLeastOne::new(
+        Flag::expect_string("host", "h", "A listen host."),
+        Prefixed::new("db", Flag::expect_string("host", "H", "A database host.")),
+    );
+    assert_eq!(
+        Err(CliError::MissingRequiredFlag(vec![
+            "host".to_string(),
+            "db-host".to_string()
+        ])),
+        either.evaluate(&["test"][..])
+    );
+
+    let both = AllOrNone::new(
+        Prefixed::new("db", Flag::expect_string("user", "u", "A database user.")),
+        Prefixed::new(
+            "db",
+            Flag::expect_string("pass", "p", "A database password."),
+        ),
+    );
+    assert_eq!(
+        Err(CliError::MissingDependency {
+            present: vec!["db-user".to_string()],
+            missing: vec!["db-pass".to_string()],
+        }),
+        both.evaluate(&["test", "--db-user", "a"][..])
+    );
+}
+
+#[test]
+fn should_render_value_types_that_take_no_value_without_a_value() {
+    #[derive(Debug, Clone, Copy)]
+    struct Verbose;
+
+    impl<'a> Evaluatable<'a, &'a [&'a str], bool> for Verbose {
+        fn evaluate(&self, _: &'a [&'a str]) -> EvaluateResult<'a, bool> {
+            Ok(Value::new(Span::empty(), true))
+        }
+    }
+
+    impl<'a> PositionalArgumentValue<'a, &'a [&'a str], bool> for Verbose {
+        fn evaluate_at(&self, input: &'a [&'a str], pos: usize) -> EvaluateResult<'a, bool> {
+            self.evaluate(&input[pos..])
+        }
+    }
+
+    impl TakesValue for Verbose {
+        fn takes_value(&self) -> bool {
+            false
+        }
+    }
+
+    let flag = FlagWithValue::new("verbose", "v", "Verbose output.", Verbose);
+    let metadata = FlagMetadata::from(flag.short_help().contexts()[0]);
+    assert!(!metadata.takes_value);
+
+    let flag = FlagWithValue::new("name", "n", "A name.", StringValue);
+    let metadata = FlagMetadata::from(flag.short_help().contexts()[0]);
+    assert!(metadata.takes_value);
+}
+
+#[test]
+fn should_not_fall_back_to_env_when_flag_is_passed_with_an_invalid_value() {
+    std::env::set_var("SCRAP_TEST_INVALID_FLAG_PORT", "8080");
+    let flag = Flag::expect_u16("port", "p", "A port.").with_env("SCRAP_TEST_INVALID_FLAG_PORT");
+
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "port".to_string(),
+            value: "abc".to_string(),
+            message: None,
+        }),
+        flag.evaluate(&["test", "-p", "abc"][..])
+    );
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "port".to_string(),
+            value: "abc".to_string(),
+            message: None,
+        }),
+        flag.evaluate(&["test", "--port=abc"][..])
+    );
+    assert_eq!(
+        Err(CliError::MissingValue("port".to_string())),
+        flag.evaluate(&["test", "-p"][..])
+    );
+
+    // the environment value remains available once the flag is absent.
+    assert_eq!(
+        Ok(Value::new(Span::empty(), 8080)),
+        flag.evaluate(&["test"][..])
+    );
+    std::env::remove_var("SCRAP_TEST_INVALID_FLAG_PORT");
+}
+
+#[test]
+fn should_evaluate_closure_flags_with_and_without_trailing_var_arg() {
+    let name = |input: &[&str]| {
+        input
+            .iter()
+            .position(|&arg| arg == "-n")
+            .and_then(|idx| input.get(idx + 1).map(|&value| (idx, value.to_string())))
+            .map(|(idx, value)| Value::new(Span::from_range(idx..idx + 2), value))
+            .ok_or(CliError::FlagEvaluation("name".to_string()))
+    };
+
+    let flags = Cmd::new("test")
+        .with_flag(name)
+        .evaluate(&["test", "-n", "foo"][..])
+        .unwrap();
+    assert_eq!("foo".to_string(), flags.value);
+
+    let input = ["time", "-n", "foo", "ls", "-n", "bar"];
+    let flags = Cmd::new("time")
+        .trailing_var_arg()
+        .with_flag(name)
+        .evaluate(&input[..])
+        .unwrap();
+    assert_eq!("foo".to_string(), flags.value);
+    assert_eq!(
+        vec!["ls".to_string(), "-n".to_string(), "bar".to_string()],
+        return_unused_args(&input[..], &flags.span)
+            .into_iter()
+            .map(|arg| arg.unwrap())
+            .collect::<Vec<String>>()
+    );
+}
+
+#[test]
+fn should_treat_a_lone_dash_as_the_first_trailing_arg() {
+    let input = ["time", "-", "-n", "foo"];
+    let flags = Cmd::new("time")
+        .trailing_var_arg()
+        .with_flag(
+            Flag::expect_string("name", "n", "A name.")
+                .optional()
+                .with_default("none".to_string()),
+        )
+        .with_handler(|_| {})
+        .evaluate(&input[..])
+        .unwrap();
+
+    assert_eq!("none".to_string(), flags.value);
+    assert_eq!(
+        vec!["-".to_string(), "-n".to_string(), "foo".to_string()],
+        return_unused_args(&input[..], &flags.span)
+            .into_iter()
+            .map(|arg| arg.unwrap())
+            .collect::<Vec<String>>()
+    );
+}
+
+#[test]
+fn should_report_invalid_values_of_passed_flags_from_at_least_one() {
+    let flags = AtLeastOne::new(
+        Flag::expect_u16("port", "p", "A port."),
+        Flag::expect_u16("admin", "a", "An admin port."),
+    );
+
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "port".to_string(),
+            value: "abc".to_string(),
+            message: None,
+        }),
+        flags.evaluate(&["test", "--port", "abc", "--admin", "8"][..])
+    );
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "admin".to_string(),
+            value: "xyz".to_string(),
+            message: None,
+        }),
+        flags.evaluate(&["test", "--port", "80", "--admin=xyz"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::from_range(3..5), (None, Some(8)))),
+        flags.evaluate(&["test", "a", "b", "--admin", "8"][..])
+    );
+}
+
+#[test]
+fn should_report_invalid_values_of_passed_flags_from_all_or_none() {
+    let flags = AllOrNone::new(
+        Flag::expect_u16("port", "p", "A port."),
+        Flag::expect_u16("admin", "a", "An admin port."),
+    );
+
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "port".to_string(),
+            value: "abc".to_string(),
+            message: None,
+        }),
+        flags.evaluate(&["test", "--port", "abc", "--admin", "xyz"][..])
+    );
+    assert_eq!(
+        Err(CliError::InvalidValue {
+            flag: "admin".to_string(),
+            value: "xyz".to_string(),
+            message: None,
+        }),
+        flags.evaluate(&["test", "--port", "80", "--admin", "xyz"][..])
+    );
+    assert_eq!(
+        Err(CliError::MissingDependency {
+            present: vec!["port".to_string()],
+            missing: vec!["admin".to_string()],
+        }),
+        flags.evaluate(&["test", "--port", "80"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(Span::empty(), (None, None))),
+        flags.evaluate(&["test"][..])
+    );
+}
+
+#[test]
+fn should_run_a_command_over_layered_arg_sources() {
+    let path = std::env::temp_dir().join(format!("scrap-run-layered-{}", std::process::id()));
+    std::fs::write(&path, "-n\nfoo\n").unwrap();
+
+    let cmd = || {
+        Cmd::new("test")
+            .with_flag(Flag::store_true("debug", "d", "Run command in debug mode."))
+            .with_flag(Flag::expect_string("name", "n", "A name."))
+            .with_handler(|(debug, name)| (debug, name))
+    };
+    let source = || LayeredArgs::new(&["test", "-d"][..], ResponseFile::open(&path).unwrap());
+
+    assert_eq!(Ok((true, "foo".to_string())), cmd().try_run(source()));
+
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    assert_eq!(
+        Ok(Some((true, "foo".to_string()))),
+        cmd().run_to(source(), &mut out, &mut err)
+    );
+    assert!(out.is_empty() && err.is_empty());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn should_return_the_flag_spelling_without_an_inline_value() {
+    let flag = WhichAlias::new(Flag::expect_string("name", "n", "A name."));
+
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..2),
+            ("--name".to_string(), "foo".to_string())
+        )),
+        flag.evaluate(&["test", "--name=foo"][..])
+    );
+    assert_eq!(
+        Ok(Value::new(
+            Span::from_range(1..2),
+            ("-n".to_string(), "a=b".to_string())
+        )),
+        flag.evaluate(&["test", "-n=a=b"][..])
+    );
+}
+
+#[test]
+fn should_detect_presence_of_plus_minus_flags_in_plus_form() {
+    let cmd = || {
+        Cmd::new("t")
+            .with_flag(
+                PlusMinusFlag::new("verbose", "v", "Toggle verbose output.")
+                    .optional()
+                    .with_default(false),
+            )
+            .with_handler(|verbose| verbose)
+    };
+
+    assert_eq!(
+        vec![("verbose".to_string(), true)],
+        cmd().dry_run(&["t", "+v", "ls"][..])
+    );
+    assert_eq!(
+        vec!["verbose".to_string()],
+        cmd()
+            .evaluate_report(&["t", "+v", "ls"][..])
+            .unwrap()
+            .matched_flags
+    );
+
+    let input = ["t", "+v", "ls", "-v"];
+    let flags = cmd().trailing_var_arg().evaluate(&input[..]).unwrap();
+    assert!(flags.value);
+    assert_eq!(
+        vec!["ls".to_string(), "-v".to_string()],
+        return_unused_args(&input[..], &flags.span)
+            .into_iter()
+            .map(|arg| arg.unwrap())
+            .collect::<Vec<String>>()
+    );
+}
+
+#[test]
+fn should_keep_unknown_flags_as_positional_values() {
+    let cmd = || {
+        Cmd::new("wrap")
+            .keep_unknown_flags()
+            .with_flag(Flag::expect_string("name", "n", "A name."))
+            .with_handler(|name| name)
+    };
+
+    let input = ["wrap", "--unknown", "x", "-n", "foo", "y"];
+    let report = cmd().evaluate_report(&input[..]).unwrap();
+    assert_eq!("foo".to_string(), report.value);
+    assert!(report.unused_flags.is_empty());
+    assert_eq!(
+        vec![
+            (1, "--unknown".to_string()),
+            (2, "x".to_string()),
+            (5, "y".to_string())
+        ],
+        report.unused_positionals
+    );
+
+    // without the mode, unknown flags are reported apart from positionals.
+    let report = Cmd::new("wrap")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_handler(|name| name)
+        .evaluate_report(&input[..])
+        .unwrap();
+    assert_eq!(vec![(1, "--unknown".to_string())], report.unused_flags);
+
+    assert_eq!(
+        Err(CliError::WrongArgCount {
+            expected: "0..=2".to_string(),
+            got: 3
+        }),
+        cmd().positionals(0..=2).evaluate(&input[..])
+    );
+
+    let input = ["wrap", "-n", "foo", "--unknown", "x", "-n", "bar"];
+    let flags = cmd().trailing_var_arg().evaluate(&input[..]).unwrap();
+    assert_eq!("foo".to_string(), flags.value);
+    assert_eq!(
+        vec!["--unknown", "x", "-n", "bar"],
+        return_unused_args(&input[..], &flags.span)
+            .into_iter()
+            .map(|arg| arg.unwrap())
+            .collect::<Vec<String>>()
+    );
+}
+
+#[test]
+fn should_return_configured_metadata_from_group_getters() {
+    let group = CmdGroup::new("remote")
+        .about("manage remotes")
+        .author("John Doe <jdoe@example.com>")
+        .version("1.2.3");
+
+    assert_eq!("remote", group.get_name());
+    assert_eq!("manage remotes", group.get_description());
+    assert_eq!("John Doe <jdoe@example.com>", group.get_author());
+    assert_eq!("1.2.3", group.get_version());
+}
+
+#[test]
+fn should_list_env_vars_in_argparse_style_help() {
+    let help = Cmd::new("test")
+        .help_style(HelpStyle::argparse())
+        .with_flag(Flag::expect_string("name", "n", "A name.").with_env("MYAPP_NAME"))
+        .with_flag(Flag::expect_u8("count", "c", "A count."))
+        .help();
+
+    let environment = help.split("environment variables:\n").nth(1).unwrap();
+    assert_eq!(format!("  {:<22}{}", "MYAPP_NAME", "A name."), environment);
+
+    let help = Cmd::new("test")
+        .help_style(HelpStyle::argparse())
+        .with_flag(Flag::expect_u8("count", "c", "A count."))
+        .help();
+    assert!(!help.contains("environment variables:"));
+}
+
+#[test]
+fn should_not_report_flag_like_values_as_matched_flags() {
+    let cmd = Cmd::new("test")
+        .with_flag(Flag::expect_string("name", "n", "A name."))
+        .with_flag(
+            Flag::store_true("verbose", "v", "verbose output.")
+                .optional()
+                .with_default(false),
+        )
+        .with_handler(|_| {});
+
+    let report = cmd.evaluate_report(&["test", "--name", "-v"][..]).unwrap();
+    assert_eq!(vec!["name".to_string()], report.matched_flags);
+
+    let report = cmd
+        .evaluate_report(&["test", "-v", "--name", "-v"][..])
+        .unwrap();
+    assert_eq!(("-v".to_string(), true), report.value);
+    assert_eq!(
+        vec!["name".to_string(), "verbose".to_string()],
+        report.matched_flags
+    );
+}